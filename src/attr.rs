@@ -1,23 +1,49 @@
 //! [`Attribute`](syn::Attribute) parsing for items, variants and fields.
 
+mod const_default;
 mod crate_;
 mod default;
+#[cfg(feature = "serde")]
+mod expose;
 mod field;
 mod incomparable;
 mod item;
+#[cfg(feature = "proptest")]
+mod proptest_strategy;
+#[cfg(feature = "proptest")]
+mod proptest_weight;
+mod secret;
 mod skip;
 mod variant;
 #[cfg(feature = "zeroize")]
-mod zeroize_fqs;
+mod zeroize;
+#[cfg(feature = "zeroize")]
+mod zeroize_to;
 
+#[cfg(any(feature = "rkyv", feature = "subtle", feature = "zeroize"))]
+pub use self::crate_::crate_root;
 #[cfg(feature = "zeroize")]
-pub use self::zeroize_fqs::ZeroizeFqs;
+pub use self::crate_::parse_crate_option;
+#[cfg(any(feature = "rkyv", feature = "subtle"))]
+pub use self::crate_::parse_crate_options;
+#[cfg(feature = "serde")]
+pub use self::crate_::parse_crate_path;
+#[cfg(any(feature = "rkyv", feature = "serde"))]
+pub use self::crate_::parse_path;
+#[cfg(feature = "serde")]
+pub use self::expose::Expose;
 pub use self::{
+	const_default::ConstDefaultValue,
 	crate_::parse_crate,
 	default::Default,
 	field::FieldAttr,
 	incomparable::Incomparable,
 	item::{DeriveWhere, ItemAttr},
+	secret::Secret,
 	skip::{Skip, SkipGroup},
 	variant::VariantAttr,
 };
+#[cfg(feature = "proptest")]
+pub use self::{proptest_strategy::ProptestStrategy, proptest_weight::ProptestWeight};
+#[cfg(feature = "zeroize")]
+pub use self::{zeroize::ZeroizeField, zeroize_to::ZeroizeTo};
@@ -0,0 +1,59 @@
+//! Attribute parsing for the `ConstDefault(value = ..)` option.
+
+use syn::{spanned::Spanned, Expr, Meta, Result};
+
+use crate::{util::MetaListExt, DeriveWhere, Error, Trait};
+
+/// Stores the value of this field for the associated `const DEFAULT`.
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct ConstDefaultValue(pub Option<Expr>);
+
+impl ConstDefaultValue {
+	/// Token used for the `ConstDefault(value = ..)` option.
+	const VALUE: &'static str = "value";
+
+	/// Adds a [`Meta`] to this [`ConstDefaultValue`].
+	pub fn add_attribute(&mut self, meta: &Meta, derive_wheres: &[DeriveWhere]) -> Result<()> {
+		debug_assert!(meta.path().is_ident(Trait::ConstDefault.as_str()));
+
+		if !derive_wheres
+			.iter()
+			.any(|derive_where| derive_where.contains(Trait::ConstDefault))
+		{
+			return Err(Error::const_default(meta.span()));
+		}
+
+		match meta {
+			Meta::List(list) => {
+				let nested = list.parse_non_empty_nested_metas()?;
+
+				for meta in &nested {
+					if let Meta::NameValue(name_value) = meta {
+						if name_value.path.is_ident(Self::VALUE) {
+							if self.0.is_some() {
+								return Err(Error::option_duplicate(
+									name_value.span(),
+									Self::VALUE,
+								));
+							} else {
+								self.0 = Some(name_value.value.clone())
+							}
+						} else {
+							return Err(Error::option(name_value.path.span()));
+						}
+					} else {
+						return Err(Error::option_syntax(meta.span()));
+					}
+				}
+
+				Ok(())
+			}
+			Meta::Path(path) => Err(Error::option_required(
+				path.span(),
+				Trait::ConstDefault.as_str(),
+			)),
+			_ => Err(Error::option_syntax(meta.span())),
+		}
+	}
+}
@@ -1,4 +1,5 @@
-//! Parsing implementation for `#[derive_where(crate = ...)]`.
+//! Parsing implementation for `#[derive_where(crate = ...)]` and the `crate`
+//! option of traits.
 
 use proc_macro2::Span;
 use syn::{spanned::Spanned, Expr, ExprLit, ExprPath, Lit, Meta, Path, Result};
@@ -8,27 +9,84 @@ use crate::{util, Error, DERIVE_WHERE};
 /// Parses `#[derive_where(crate = ...)]`.
 pub fn parse_crate(meta: Meta) -> Result<(Path, Span)> {
 	if let Meta::NameValue(name_value) = meta {
-		let path = match &name_value.value {
-			Expr::Lit(ExprLit {
-				lit: Lit::Str(lit_str),
-				..
-			}) => match lit_str.parse::<Path>() {
-				Ok(path) => path,
-				Err(error) => return Err(Error::path(lit_str.span(), error)),
-			},
-			Expr::Path(ExprPath { path, .. }) => path.clone(),
-			_ => return Err(Error::option_syntax(name_value.value.span())),
-		};
-
-		if path == util::path_from_strs(&[DERIVE_WHERE]) {
-			Err(Error::path_unnecessary(
-				path.span(),
-				&format!("::{}", DERIVE_WHERE),
-			))
-		} else {
-			Ok((path, name_value.span()))
-		}
+		let path = parse_crate_path(&name_value.value, DERIVE_WHERE)?;
+		Ok((path, name_value.span()))
 	} else {
 		Err(Error::option_syntax(meta.span()))
 	}
 }
+
+/// Parses the value of a `crate` option, returning an error if it is the
+/// default path of the crate `name`.
+pub fn parse_crate_path(value: &Expr, name: &str) -> Result<Path> {
+	let path = parse_path(value)?;
+
+	if path == util::path_from_strs(&[name]) {
+		Err(Error::path_unnecessary(path.span(), &format!("::{}", name)))
+	} else {
+		Ok(path)
+	}
+}
+
+/// Parses the `crate` option of a trait into `crate_`, returning an error if
+/// it is a duplicate or the default path of the crate `name`.
+#[cfg(any(feature = "rkyv", feature = "subtle", feature = "zeroize"))]
+pub fn parse_crate_option(
+	crate_: &mut Option<Path>,
+	name_value: &syn::MetaNameValue,
+	name: &str,
+) -> Result<()> {
+	// Check for duplicate `crate` option.
+	if crate_.is_none() {
+		*crate_ = Some(parse_crate_path(&name_value.value, name)?);
+		Ok(())
+	} else {
+		Err(Error::option_duplicate(name_value.span(), "crate"))
+	}
+}
+
+/// Parses the options of a trait only supporting the `crate` option.
+#[cfg(any(feature = "rkyv", feature = "subtle"))]
+pub fn parse_crate_options(
+	list: Option<syn::punctuated::Punctuated<Meta, syn::Token![,]>>,
+	trait_: &str,
+	name: &str,
+) -> Result<Option<Path>> {
+	let mut crate_ = None;
+
+	for meta in list.into_iter().flatten() {
+		match &meta {
+			Meta::NameValue(name_value) if name_value.path.is_ident("crate") => {
+				parse_crate_option(&mut crate_, name_value, name)?
+			}
+			Meta::List(_) => return Err(Error::option_syntax(meta.span())),
+			_ => return Err(Error::option_trait(meta.path().span(), trait_)),
+		}
+	}
+
+	Ok(crate_)
+}
+
+/// Returns the path to the root crate of a trait, the crate `name` if no
+/// `crate` option was given.
+#[cfg(any(feature = "rkyv", feature = "subtle", feature = "zeroize"))]
+pub fn crate_root(crate_: Option<&Path>, name: &str) -> Path {
+	crate_
+		.cloned()
+		.unwrap_or_else(|| util::path_from_strs(&[name]))
+}
+
+/// Parses a [`Path`] or a string literal containing a [`Path`].
+pub fn parse_path(value: &Expr) -> Result<Path> {
+	match value {
+		Expr::Lit(ExprLit {
+			lit: Lit::Str(lit_str),
+			..
+		}) => match lit_str.parse::<Path>() {
+			Ok(path) => Ok(path),
+			Err(error) => Err(Error::path(lit_str.span(), error)),
+		},
+		Expr::Path(ExprPath { path, .. }) => Ok(path.clone()),
+		_ => Err(Error::option_syntax(value.span())),
+	}
+}
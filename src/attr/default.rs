@@ -6,7 +6,7 @@ use syn::{spanned::Spanned, Meta, Result};
 use crate::{DeriveWhere, Error, Trait};
 
 /// Stores if this variant should be the default when implementing
-/// [`Default`](trait@std::default::Default).
+/// [`Default`](trait@std::default::Default) or `ConstDefault`.
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(test, derive(Debug))]
 pub struct Default(pub Option<Span>);
@@ -26,7 +26,9 @@ impl Default {
 				let mut impl_default = false;
 
 				for derive_where in derive_wheres {
-					if derive_where.contains(Trait::Default) {
+					if derive_where.contains(Trait::Default)
+						|| derive_where.contains(Trait::ConstDefault)
+					{
 						impl_default = true;
 						break;
 					}
@@ -0,0 +1,56 @@
+//! Attribute parsing for the `Serialize(expose)` option.
+
+use proc_macro2::Span;
+use syn::{spanned::Spanned, Meta, Result};
+
+use crate::{util::MetaListExt, DeriveWhere, Error, Trait};
+
+/// Stores if this field of a `secret` item is allowed to be serialized when
+/// implementing [`Serialize`](https://docs.rs/serde/latest/serde/derive.Serialize.html).
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct Expose(pub Option<Span>);
+
+impl Expose {
+	/// Token used for the `Serialize(expose)` option.
+	const EXPOSE: &'static str = "expose";
+
+	/// Adds a [`Meta`] to this [`Expose`].
+	pub fn add_attribute(&mut self, meta: &Meta, derive_wheres: &[DeriveWhere]) -> Result<()> {
+		debug_assert!(meta.path().is_ident(Trait::Serialize.as_str()));
+
+		if !derive_wheres
+			.iter()
+			.any(|derive_where| derive_where.contains(Trait::Serialize))
+		{
+			return Err(Error::serialize(meta.span()));
+		}
+
+		match meta {
+			Meta::List(list) => {
+				let nested = list.parse_non_empty_nested_metas()?;
+
+				for meta in &nested {
+					match meta {
+						Meta::Path(path) if path.is_ident(Self::EXPOSE) => {
+							if self.0.is_some() {
+								return Err(Error::option_duplicate(path.span(), Self::EXPOSE));
+							} else {
+								self.0 = Some(path.span())
+							}
+						}
+						Meta::Path(path) => return Err(Error::option(path.span())),
+						_ => return Err(Error::option_syntax(meta.span())),
+					}
+				}
+
+				Ok(())
+			}
+			Meta::Path(path) => Err(Error::option_required(
+				path.span(),
+				Trait::Serialize.as_str(),
+			)),
+			_ => Err(Error::option_syntax(meta.span())),
+		}
+	}
+}
@@ -2,20 +2,32 @@
 
 use syn::{spanned::Spanned, Attribute, Meta, Result};
 
-use crate::{util::MetaListExt, DeriveWhere, Error, Skip, DERIVE_WHERE};
+#[cfg(feature = "serde")]
+use crate::Expose;
+#[cfg(feature = "proptest")]
+use crate::ProptestStrategy;
 #[cfg(feature = "zeroize")]
-use crate::{Trait, ZeroizeFqs};
+use crate::ZeroizeField;
+use crate::{util::MetaListExt, ConstDefaultValue, DeriveWhere, Error, Skip, Trait, DERIVE_WHERE};
 
 /// Attributes on field.
 #[derive(Default)]
 #[cfg_attr(test, derive(Debug))]
 pub struct FieldAttr {
-	/// [`Trait`](crate::Trait)s to skip this field for.
-	#[cfg_attr(feature = "zeroize", allow(rustdoc::redundant_explicit_links))]
+	/// [`Trait`]s to skip this field for.
 	pub skip: Skip,
-	/// Use fully-qualified-syntax for the [`Zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html) implementation on this field.
+	/// Value of this field for the associated `const DEFAULT`.
+	pub const_default: ConstDefaultValue,
+	/// [`Strategy`](https://docs.rs/proptest/latest/proptest/strategy/trait.Strategy.html)
+	/// used to generate this field for `ProptestArbitrary`.
+	#[cfg(feature = "proptest")]
+	pub proptest_strategy: ProptestStrategy,
+	/// Allow serializing this field of a `secret` item.
+	#[cfg(feature = "serde")]
+	pub expose: Expose,
+	/// How [`Zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html) is called on this field.
 	#[cfg(feature = "zeroize")]
-	pub zeroize_fqs: ZeroizeFqs,
+	pub zeroize: ZeroizeField,
 }
 
 impl FieldAttr {
@@ -55,10 +67,31 @@ impl FieldAttr {
 					continue;
 				}
 
+				if meta.path().is_ident(Trait::ConstDefault.as_str()) {
+					self.const_default.add_attribute(meta, derive_wheres)?;
+					continue;
+				}
+
+				#[cfg(feature = "proptest")]
+				{
+					if meta.path().is_ident(Trait::ProptestArbitrary.as_str()) {
+						self.proptest_strategy.add_attribute(meta, derive_wheres)?;
+						continue;
+					}
+				}
+
+				#[cfg(feature = "serde")]
+				{
+					if meta.path().is_ident(Trait::Serialize.as_str()) {
+						self.expose.add_attribute(meta, derive_wheres)?;
+						continue;
+					}
+				}
+
 				#[cfg(feature = "zeroize")]
 				{
 					if meta.path().is_ident(Trait::Zeroize.as_str()) {
-						self.zeroize_fqs.add_attribute(meta, derive_wheres)?;
+						self.zeroize.add_attribute(meta, derive_wheres)?;
 						continue;
 					}
 				}
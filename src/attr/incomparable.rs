@@ -33,7 +33,7 @@ impl Incomparable {
 						DeriveTrait::Eq | DeriveTrait::Ord => {
 							return Err(Error::non_partial_incomparable(path.span()));
 						}
-						DeriveTrait::PartialEq | DeriveTrait::PartialOrd => impl_cmp = true,
+						DeriveTrait::PartialEq(_) | DeriveTrait::PartialOrd => impl_cmp = true,
 						_ => {}
 					}
 				}
@@ -3,15 +3,19 @@
 use std::borrow::Cow;
 
 use proc_macro2::Span;
+use quote::ToTokens;
 use syn::{
 	parse::{discouraged::Speculative, Parse, ParseStream},
 	punctuated::Punctuated,
 	spanned::Spanned,
-	Attribute, BoundLifetimes, Data, Ident, Meta, Path, PredicateType, Result, Token, Type,
-	TypePath, WhereClause, WherePredicate,
+	Attribute, BoundLifetimes, Data, Ident, Lifetime, Meta, Path, PredicateType, Result, Token,
+	Type, TypePath, WhereClause, WherePredicate,
 };
 
-use crate::{trait_::DeriveTrait, Error, Incomparable, Item, Skip, SkipGroup, Trait, DERIVE_WHERE};
+use crate::{
+	trait_::DeriveTrait, util, Error, Incomparable, Item, Secret, Skip, SkipGroup, Trait,
+	DERIVE_WHERE,
+};
 
 /// Attributes on item.
 #[derive(Default)]
@@ -23,6 +27,9 @@ pub struct ItemAttr {
 	/// Comparing item will yield `false` for [`PartialEq`] and [`None`] for
 	/// [`PartialOrd`].
 	pub incomparable: Incomparable,
+	/// Redact, compare in constant time and don't serialize fields of this
+	/// item.
+	pub secret: Secret,
 	/// [`DeriveWhere`]s on this item.
 	pub derive_wheres: Vec<DeriveWhere>,
 }
@@ -33,6 +40,7 @@ impl ItemAttr {
 		let mut self_ = ItemAttr::default();
 		let mut skip_inners = Vec::new();
 		let mut incomparables = Vec::new();
+		let mut secrets = Vec::new();
 
 		for attr in attrs {
 			if attr.path().is_ident(DERIVE_WHERE) {
@@ -60,6 +68,9 @@ impl ItemAttr {
 								} else if meta.path().is_ident(Incomparable::INCOMPARABLE) {
 									// Needs to be parsed after all traits are known.
 									incomparables.push(meta)
+								} else if meta.path().is_ident(Secret::SECRET) {
+									// Needs to be parsed after all traits are known.
+									secrets.push(meta)
 								} else if meta.path().is_ident("crate") {
 									let (path, _) = super::parse_crate(meta)
 										.expect("failed to parse previously parsed attribute");
@@ -118,15 +129,48 @@ impl ItemAttr {
 					.iter()
 					.zip(&derive_where.traits)
 					.skip(skip)
-					.find(|(_, other_trait)| *other_trait == trait_)
+					.find(|(_, other_trait)| ****other_trait == ***trait_)
 				{
 					return Err(Error::trait_duplicate(*span));
 				}
 			}
 		}
 
-		// Delayed parsing of `skip_inner` and `incomparable` to get access to all
-		// traits to be implemented.
+		// `ConstDefault` has no trait to bind generics to, so generics without a
+		// custom bound would be silently ignored.
+		for derive_where in &self_.derive_wheres {
+			if derive_where.any_no_bound() {
+				if let Some((span, _)) = derive_where
+					.spans
+					.iter()
+					.zip(&derive_where.traits)
+					.find(|(_, trait_)| *trait_ == Trait::ConstDefault)
+				{
+					return Err(Error::const_default_no_bound(*span));
+				}
+			}
+		}
+
+		// `FromBytes` implements `FromZeros` as well, so they can't both be
+		// implemented.
+		#[cfg(feature = "zerocopy")]
+		if self_
+			.derive_wheres
+			.iter()
+			.any(|derive_where| derive_where.contains(Trait::FromBytes))
+		{
+			if let Some((span, _)) = self_
+				.derive_wheres
+				.iter()
+				.flat_map(|derive_where| derive_where.spans.iter().zip(&derive_where.traits))
+				.find(|(_, trait_)| *trait_ == Trait::FromZeros)
+			{
+				return Err(Error::zerocopy_from_zeros(*span));
+			}
+		}
+
+		// Delayed parsing of `skip_inner`, `incomparable` and `secret` to get access to
+		// all traits to be implemented.
 		for meta in skip_inners {
 			self_
 				.skip_inner
@@ -139,6 +183,10 @@ impl ItemAttr {
 				.add_attribute(&meta, &self_.derive_wheres)?;
 		}
 
+		for meta in secrets {
+			self_.secret.add_attribute(&meta, &self_.derive_wheres)?;
+		}
+
 		Ok(self_)
 	}
 }
@@ -169,12 +217,13 @@ impl DeriveWhere {
 			assert!(!input.is_empty());
 
 			while !input.is_empty() {
-				// Start with parsing a trait.
+				// Start with parsing a trait or an `unsafe(..)` list of traits.
 				// Not checking for duplicates here, we do that after merging `derive_where`s
 				// with the same bounds.
-				let (span, trait_) = DeriveTrait::from_stream(attrs, span, data, input)?;
-				spans.push(span);
-				traits.push(trait_);
+				for (span, trait_) in DeriveTrait::from_stream(attrs, span, data, input)? {
+					spans.push(span);
+					traits.push(trait_);
+				}
 
 				if !input.is_empty() {
 					let mut fork = input.fork();
@@ -238,6 +287,28 @@ impl DeriveWhere {
 		})
 	}
 
+	/// Returns `true` if any [`NoBound`](Generic::NoBound) is present.
+	pub fn any_no_bound(&self) -> bool {
+		self.generics.iter().any(|generic| match generic {
+			Generic::CustomBound(_) => false,
+			Generic::NoBound(_) => true,
+		})
+	}
+
+	/// Returns all lifetimes used in [`CustomBound`](Generic::CustomBound)s,
+	/// except lifetimes introduced by `for<..>`.
+	pub fn custom_bound_lifetimes(&self) -> Vec<Lifetime> {
+		self.generics
+			.iter()
+			.flat_map(|generic| match generic {
+				Generic::CustomBound(predicate) => {
+					util::free_lifetimes(predicate.to_token_stream())
+				}
+				Generic::NoBound(_) => Vec::new(),
+			})
+			.collect()
+	}
+
 	/// Returns `true` if the given generic type parameter if present.
 	pub fn has_type_param(&self, type_param: &Ident) -> bool {
 		self.generics.iter().any(|generic| match generic {
@@ -269,32 +340,55 @@ impl DeriveWhere {
 		trait_: &DeriveTrait,
 		item: &Item,
 	) {
-		// Only create a where clause if required
-		if !self.generics.is_empty() {
-			// We use the existing where clause or create a new one if required.
-			let where_clause = where_clause.get_or_insert(Cow::Owned(WhereClause {
-				where_token: <Token![where]>::default(),
-				predicates: Punctuated::default(),
-			}));
-
-			// Insert bounds into the `where` clause.
-			for generic in &self.generics {
-				where_clause
-					.to_mut()
-					.predicates
-					.push(WherePredicate::Type(match generic {
-						Generic::CustomBound(type_bound) => type_bound.clone(),
-						Generic::NoBound(GenericNoBound {
-							lifetimes: bound_lifetimes,
-							ty,
-						}) => PredicateType {
-							lifetimes: bound_lifetimes.clone(),
-							bounded_ty: ty.clone(),
-							colon_token: <Token![:]>::default(),
-							bounds: trait_.where_bounds(item),
-						},
-					}));
-			}
+		// Insert bounds into the `where` clause.
+		for generic in &self.generics {
+			let predicate = match generic {
+				Generic::CustomBound(predicate) => predicate.clone(),
+				Generic::NoBound(GenericNoBound {
+					lifetimes: bound_lifetimes,
+					ty,
+				}) => {
+					let bounds = trait_.where_bounds(self, item);
+
+					// Skip generics if there is no trait to bind them to.
+					if bounds.is_empty() {
+						continue;
+					}
+
+					WherePredicate::Type(PredicateType {
+						lifetimes: bound_lifetimes.clone(),
+						bounded_ty: ty.clone(),
+						colon_token: <Token![:]>::default(),
+						bounds,
+					})
+				}
+			};
+
+			// Only create a where clause if required. We use the existing where clause or
+			// create a new one if required.
+			where_clause
+				.get_or_insert(Cow::Owned(WhereClause {
+					where_token: <Token![where]>::default(),
+					predicates: Punctuated::default(),
+				}))
+				.to_mut()
+				.predicates
+				.push(predicate);
+		}
+
+		// Add user-provided predicates.
+		if let Some(predicates) = trait_
+			.where_predicates()
+			.filter(|predicates| !predicates.is_empty())
+		{
+			where_clause
+				.get_or_insert(Cow::Owned(WhereClause {
+					where_token: <Token![where]>::default(),
+					predicates: Punctuated::default(),
+				}))
+				.to_mut()
+				.predicates
+				.extend(predicates.iter().cloned());
 		}
 	}
 }
@@ -319,11 +413,11 @@ impl Parse for GenericNoBound {
 }
 
 /// Holds a single generic [type](GenericNoBound) with optional lifetime bounds
-/// or [type with bound](PredicateType).
+/// or [type or lifetime with bound](WherePredicate).
 #[derive(Eq, PartialEq)]
 pub enum Generic {
-	/// Generic type with custom [specified bounds](PredicateType).
-	CustomBound(PredicateType),
+	/// Generic type or lifetime with custom [specified bounds](WherePredicate).
+	CustomBound(WherePredicate),
 	/// Generic [type](GenericNoBound) which will be bound to the
 	/// [`DeriveTrait`].
 	NoBound(GenericNoBound),
@@ -339,11 +433,11 @@ impl Parse for Generic {
 		if let Ok(where_predicate) = WherePredicate::parse(&fork) {
 			input.advance_to(&fork);
 
-			// Don't allow lifetimes, as it doesn't make sense in the context.
-			if let WherePredicate::Type(path) = where_predicate {
-				Ok(Generic::CustomBound(path))
-			} else {
-				Err(Error::generic(where_predicate.span()))
+			match where_predicate {
+				WherePredicate::Type(_) | WherePredicate::Lifetime(_) => {
+					Ok(Generic::CustomBound(where_predicate))
+				}
+				_ => Err(Error::generic(where_predicate.span())),
 			}
 		} else {
 			match GenericNoBound::parse(input) {
@@ -0,0 +1,60 @@
+//! Attribute parsing for the `ProptestArbitrary(strategy = ..)` option.
+
+use syn::{spanned::Spanned, Expr, Meta, Result};
+
+use crate::{util::MetaListExt, DeriveWhere, Error, Trait};
+
+/// Stores the [`Strategy`](https://docs.rs/proptest/latest/proptest/strategy/trait.Strategy.html)
+/// used to generate this field instead of [`any`](https://docs.rs/proptest/latest/proptest/arbitrary/fn.any.html).
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct ProptestStrategy(pub Option<Expr>);
+
+impl ProptestStrategy {
+	/// Token used for the `ProptestArbitrary(strategy = ..)` option.
+	const STRATEGY: &'static str = "strategy";
+
+	/// Adds a [`Meta`] to this [`ProptestStrategy`].
+	pub fn add_attribute(&mut self, meta: &Meta, derive_wheres: &[DeriveWhere]) -> Result<()> {
+		debug_assert!(meta.path().is_ident(Trait::ProptestArbitrary.as_str()));
+
+		if !derive_wheres
+			.iter()
+			.any(|derive_where| derive_where.contains(Trait::ProptestArbitrary))
+		{
+			return Err(Error::proptest_arbitrary(meta.span()));
+		}
+
+		match meta {
+			Meta::List(list) => {
+				let nested = list.parse_non_empty_nested_metas()?;
+
+				for meta in &nested {
+					if let Meta::NameValue(name_value) = meta {
+						if name_value.path.is_ident(Self::STRATEGY) {
+							if self.0.is_some() {
+								return Err(Error::option_duplicate(
+									name_value.span(),
+									Self::STRATEGY,
+								));
+							} else {
+								self.0 = Some(name_value.value.clone())
+							}
+						} else {
+							return Err(Error::option(name_value.path.span()));
+						}
+					} else {
+						return Err(Error::option_syntax(meta.span()));
+					}
+				}
+
+				Ok(())
+			}
+			Meta::Path(path) => Err(Error::option_required(
+				path.span(),
+				Trait::ProptestArbitrary.as_str(),
+			)),
+			_ => Err(Error::option_syntax(meta.span())),
+		}
+	}
+}
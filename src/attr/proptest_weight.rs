@@ -0,0 +1,70 @@
+//! Attribute parsing for the `ProptestArbitrary(weight = ..)` option.
+
+use proc_macro2::Span;
+use syn::{spanned::Spanned, Expr, ExprLit, Lit, Meta, Result};
+
+use crate::{util::MetaListExt, DeriveWhere, Error, Trait};
+
+/// Stores the relative weight of this variant when generating it with
+/// [`Arbitrary`](https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html)
+/// and the [`Span`] of the weight.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct ProptestWeight(pub Option<(Span, u32)>);
+
+impl ProptestWeight {
+	/// Token used for the `ProptestArbitrary(weight = ..)` option.
+	const WEIGHT: &'static str = "weight";
+
+	/// Adds a [`Meta`] to this [`ProptestWeight`].
+	pub fn add_attribute(&mut self, meta: &Meta, derive_wheres: &[DeriveWhere]) -> Result<()> {
+		debug_assert!(meta.path().is_ident(Trait::ProptestArbitrary.as_str()));
+
+		if !derive_wheres
+			.iter()
+			.any(|derive_where| derive_where.contains(Trait::ProptestArbitrary))
+		{
+			return Err(Error::proptest_arbitrary(meta.span()));
+		}
+
+		match meta {
+			Meta::List(list) => {
+				let nested = list.parse_non_empty_nested_metas()?;
+
+				for meta in &nested {
+					if let Meta::NameValue(name_value) = meta {
+						if name_value.path.is_ident(Self::WEIGHT) {
+							if self.0.is_some() {
+								return Err(Error::option_duplicate(
+									name_value.span(),
+									Self::WEIGHT,
+								));
+							}
+
+							match &name_value.value {
+								Expr::Lit(ExprLit {
+									lit: Lit::Int(lit), ..
+								}) => match lit.base10_parse::<u32>()? {
+									0 => return Err(Error::proptest_weight(lit.span())),
+									weight => self.0 = Some((lit.span(), weight)),
+								},
+								value => return Err(Error::proptest_weight(value.span())),
+							}
+						} else {
+							return Err(Error::option(name_value.path.span()));
+						}
+					} else {
+						return Err(Error::option_syntax(meta.span()));
+					}
+				}
+
+				Ok(())
+			}
+			Meta::Path(path) => Err(Error::option_required(
+				path.span(),
+				Trait::ProptestArbitrary.as_str(),
+			)),
+			_ => Err(Error::option_syntax(meta.span())),
+		}
+	}
+}
@@ -0,0 +1,68 @@
+//! Attribute parsing for the `secret` option.
+
+use proc_macro2::Span;
+use syn::{spanned::Spanned, Meta, Result};
+
+use crate::{trait_::DeriveTrait, DeriveWhere, Error};
+
+/// Stores if this item holds secrets, which redacts all fields when
+/// implementing [`Debug`](trait@std::fmt::Debug) or `Format`, compares them in
+/// constant time when implementing [`PartialEq`] and requires fields to be
+/// exposed explicitly when implementing `Serialize`.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct Secret(pub Option<Span>);
+
+impl Secret {
+	/// Token used for the `secret` option.
+	pub const SECRET: &'static str = "secret";
+
+	/// Adds a [`Meta`] to this [`Secret`].
+	pub fn add_attribute(&mut self, meta: &Meta, derive_wheres: &[DeriveWhere]) -> Result<()> {
+		debug_assert!(meta.path().is_ident(Self::SECRET));
+
+		if let Meta::Path(path) = meta {
+			if self.0.is_some() {
+				Err(Error::option_duplicate(path.span(), Self::SECRET))
+			} else {
+				let mut impl_secret = false;
+
+				for trait_ in derive_wheres
+					.iter()
+					.flat_map(|derive_where| derive_where.traits.iter())
+				{
+					match trait_ {
+						DeriveTrait::Debug => impl_secret = true,
+						// Constant-time comparison can't be done in `const` contexts.
+						DeriveTrait::PartialEq(partial_eq) if partial_eq.const_ => {
+							return Err(Error::secret_const(path.span()))
+						}
+						#[cfg(feature = "subtle")]
+						DeriveTrait::PartialEq(_) => impl_secret = true,
+						#[cfg(not(feature = "subtle"))]
+						DeriveTrait::PartialEq(_) => return Err(Error::secret_subtle_feature(path.span())),
+						#[cfg(feature = "defmt")]
+						DeriveTrait::Format => impl_secret = true,
+						#[cfg(feature = "serde")]
+						DeriveTrait::Serialize(_) => impl_secret = true,
+						_ => {}
+					}
+				}
+
+				if impl_secret {
+					self.0 = Some(path.span());
+					Ok(())
+				} else {
+					Err(Error::secret(path.span()))
+				}
+			}
+		} else {
+			Err(Error::option_syntax(meta.span()))
+		}
+	}
+
+	/// Returns `true` if this item holds secrets.
+	pub fn is_secret(&self) -> bool {
+		self.0.is_some()
+	}
+}
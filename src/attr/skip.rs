@@ -168,7 +168,7 @@ impl Skip {
 	pub fn group_skipped(&self, group: SkipGroup) -> bool {
 		match self {
 			Skip::None => false,
-			Skip::All => true,
+			Skip::All => group.traits().all(SkipGroup::trait_supported_by_skip_all),
 			Skip::Traits(groups) => groups.contains(&group),
 		}
 	}
@@ -178,14 +178,46 @@ impl Skip {
 #[derive(Clone, Copy, Eq, PartialEq)]
 #[cfg_attr(test, derive(Debug))]
 pub enum SkipGroup {
+	/// [`Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html).
+	#[cfg(feature = "arbitrary")]
+	Arbitrary,
+	/// [`Archive`](https://docs.rs/rkyv/latest/rkyv/trait.Archive.html),
+	/// [`Deserialize`](https://docs.rs/rkyv/latest/rkyv/trait.Deserialize.html) and
+	/// [`Serialize`](https://docs.rs/rkyv/latest/rkyv/trait.Serialize.html) from
+	/// `rkyv`.
+	#[cfg(feature = "rkyv")]
+	Archive,
 	/// [`Clone`].
 	Clone,
+	/// [`ConstantTimeEq`](https://docs.rs/subtle/latest/subtle/trait.ConstantTimeEq.html).
+	#[cfg(feature = "subtle")]
+	ConstantTimeEq,
 	/// [`Debug`].
 	Debug,
+	/// [`Deserialize`](https://docs.rs/serde/latest/serde/derive.Deserialize.html).
+	#[cfg(feature = "serde")]
+	Deserialize,
 	/// [`Eq`], [`Hash`], [`Ord`], [`PartialEq`] and [`PartialOrd`].
 	EqHashOrd,
+	/// [`Format`](https://docs.rs/defmt/latest/defmt/trait.Format.html).
+	#[cfg(feature = "defmt")]
+	Format,
 	/// [`Hash`].
 	Hash,
+	/// [`JsonSchema`](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html).
+	#[cfg(feature = "schemars")]
+	JsonSchema,
+	/// [`Arbitrary`](https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html)
+	/// from `proptest`.
+	#[cfg(feature = "proptest")]
+	ProptestArbitrary,
+	/// [`Deserialize`](https://docs.rs/serde/latest/serde/derive.Deserialize.html) and
+	/// [`Serialize`](https://docs.rs/serde/latest/serde/derive.Serialize.html).
+	#[cfg(feature = "serde")]
+	Serde,
+	/// [`Serialize`](https://docs.rs/serde/latest/serde/derive.Serialize.html).
+	#[cfg(feature = "serde")]
+	Serialize,
 	/// [`Zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html) and
 	/// [`ZeroizeOnDrop`](https://docs.rs/zeroize/latest/zeroize/trait.ZeroizeOnDrop.html).
 	#[cfg(feature = "zeroize")]
@@ -199,10 +231,28 @@ impl SkipGroup {
 			use SkipGroup::*;
 
 			match ident.to_string().as_str() {
+				#[cfg(feature = "arbitrary")]
+				"Arbitrary" => Ok(Arbitrary),
+				#[cfg(feature = "rkyv")]
+				"Archive" => Ok(Archive),
 				"Clone" => Ok(Clone),
+				#[cfg(feature = "subtle")]
+				"ConstantTimeEq" => Ok(ConstantTimeEq),
 				"Debug" => Ok(Debug),
+				#[cfg(feature = "serde")]
+				"Deserialize" => Ok(Deserialize),
 				"EqHashOrd" => Ok(EqHashOrd),
+				#[cfg(feature = "defmt")]
+				"Format" => Ok(Format),
 				"Hash" => Ok(Hash),
+				#[cfg(feature = "schemars")]
+				"JsonSchema" => Ok(JsonSchema),
+				#[cfg(feature = "proptest")]
+				"ProptestArbitrary" => Ok(ProptestArbitrary),
+				#[cfg(feature = "serde")]
+				"Serde" => Ok(Serde),
+				#[cfg(feature = "serde")]
+				"Serialize" => Ok(Serialize),
 				#[cfg(feature = "zeroize")]
 				"Zeroize" => Ok(Zeroize),
 				_ => Err(Error::skip_group(path.span())),
@@ -217,10 +267,28 @@ impl SkipGroup {
 	/// messages.
 	const fn as_str(self) -> &'static str {
 		match self {
+			#[cfg(feature = "arbitrary")]
+			Self::Arbitrary => "Arbitrary",
+			#[cfg(feature = "rkyv")]
+			Self::Archive => "Archive",
 			Self::Clone => "Clone",
+			#[cfg(feature = "subtle")]
+			Self::ConstantTimeEq => "ConstantTimeEq",
 			Self::Debug => "Debug",
+			#[cfg(feature = "serde")]
+			Self::Deserialize => "Deserialize",
 			Self::EqHashOrd => "EqHashOrd",
+			#[cfg(feature = "defmt")]
+			Self::Format => "Format",
 			Self::Hash => "Hash",
+			#[cfg(feature = "schemars")]
+			Self::JsonSchema => "JsonSchema",
+			#[cfg(feature = "proptest")]
+			Self::ProptestArbitrary => "ProptestArbitrary",
+			#[cfg(feature = "serde")]
+			Self::Serde => "Serde",
+			#[cfg(feature = "serde")]
+			Self::Serialize => "Serialize",
 			#[cfg(feature = "zeroize")]
 			Self::Zeroize => "Zeroize",
 		}
@@ -229,12 +297,34 @@ impl SkipGroup {
 	/// [`Trait`]s supported by this group.
 	fn traits(self) -> impl Iterator<Item = Trait> {
 		match self {
+			#[cfg(feature = "arbitrary")]
+			Self::Arbitrary => [Some(Trait::Arbitrary), None, None, None, None]
+				.into_iter()
+				.flatten(),
+			#[cfg(feature = "rkyv")]
+			Self::Archive => [
+				Some(Trait::Archive),
+				Some(Trait::RkyvDeserialize),
+				Some(Trait::RkyvSerialize),
+				None,
+				None,
+			]
+			.into_iter()
+			.flatten(),
 			Self::Clone => [Some(Trait::Clone), None, None, None, None]
 				.into_iter()
 				.flatten(),
+			#[cfg(feature = "subtle")]
+			Self::ConstantTimeEq => [Some(Trait::ConstantTimeEq), None, None, None, None]
+				.into_iter()
+				.flatten(),
 			Self::Debug => [Some(Trait::Debug), None, None, None, None]
 				.into_iter()
 				.flatten(),
+			#[cfg(feature = "serde")]
+			Self::Deserialize => [Some(Trait::Deserialize), None, None, None, None]
+				.into_iter()
+				.flatten(),
 			Self::EqHashOrd => [
 				Some(Trait::Eq),
 				Some(Trait::Hash),
@@ -244,9 +334,35 @@ impl SkipGroup {
 			]
 			.into_iter()
 			.flatten(),
+			#[cfg(feature = "defmt")]
+			Self::Format => [Some(Trait::Format), None, None, None, None]
+				.into_iter()
+				.flatten(),
 			Self::Hash => [Some(Trait::Hash), None, None, None, None]
 				.into_iter()
 				.flatten(),
+			#[cfg(feature = "schemars")]
+			Self::JsonSchema => [Some(Trait::JsonSchema), None, None, None, None]
+				.into_iter()
+				.flatten(),
+			#[cfg(feature = "proptest")]
+			Self::ProptestArbitrary => [Some(Trait::ProptestArbitrary), None, None, None, None]
+				.into_iter()
+				.flatten(),
+			#[cfg(feature = "serde")]
+			Self::Serde => [
+				Some(Trait::Deserialize),
+				Some(Trait::Serialize),
+				None,
+				None,
+				None,
+			]
+			.into_iter()
+			.flatten(),
+			#[cfg(feature = "serde")]
+			Self::Serialize => [Some(Trait::Serialize), None, None, None, None]
+				.into_iter()
+				.flatten(),
 			#[cfg(feature = "zeroize")]
 			Self::Zeroize => [
 				Some(Trait::Zeroize),
@@ -263,15 +379,40 @@ impl SkipGroup {
 	/// Returns `true` if [`Trait`] is supported by any group.
 	pub fn trait_supported_by_skip_all(trait_: Trait) -> bool {
 		match trait_ {
-			Trait::Clone | Trait::Copy | Trait::Default => false,
+			#[cfg(feature = "arbitrary")]
+			Trait::Arbitrary => false,
+			Trait::Clone | Trait::ConstDefault | Trait::Copy | Trait::Default => false,
 			Trait::Debug
 			| Trait::Eq
 			| Trait::Hash
 			| Trait::Ord
 			| Trait::PartialEq
 			| Trait::PartialOrd => true,
+			Trait::Assert | Trait::Send | Trait::Sync => false,
+			#[cfg(feature = "bytemuck")]
+			Trait::Pod | Trait::Zeroable => false,
+			#[cfg(feature = "subtle")]
+			Trait::ConditionallySelectable => false,
+			#[cfg(feature = "subtle")]
+			Trait::ConstantTimeEq => true,
+			#[cfg(feature = "defmt")]
+			Trait::Format => true,
+			#[cfg(feature = "borsh")]
+			Trait::BorshDeserialize | Trait::BorshSerialize => false,
+			#[cfg(feature = "schemars")]
+			Trait::JsonSchema => false,
+			#[cfg(feature = "proptest")]
+			Trait::ProptestArbitrary => false,
+			#[cfg(feature = "rkyv")]
+			Trait::Archive | Trait::RkyvDeserialize | Trait::RkyvSerialize => false,
 			#[cfg(feature = "serde")]
 			Trait::Deserialize | Trait::Serialize => false,
+			#[cfg(feature = "zerocopy")]
+			Trait::FromBytes
+			| Trait::FromZeros
+			| Trait::Immutable
+			| Trait::IntoBytes
+			| Trait::KnownLayout => false,
 			#[cfg(feature = "zeroize")]
 			Trait::Zeroize | Trait::ZeroizeOnDrop => true,
 		}
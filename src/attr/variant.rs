@@ -2,7 +2,11 @@
 
 use syn::{spanned::Spanned, Attribute, Fields, Meta, Result, Variant};
 
+#[cfg(feature = "zeroize")]
+use crate::ZeroizeTo;
 use crate::{util::MetaListExt, Default, DeriveWhere, Error, Incomparable, Skip, DERIVE_WHERE};
+#[cfg(feature = "proptest")]
+use crate::{ProptestWeight, Trait};
 
 /// Attributes on variant.
 #[derive(Default)]
@@ -10,10 +14,17 @@ pub struct VariantAttr {
 	/// Default variant.
 	pub default: Default,
 	/// [`Trait`](crate::Trait)s to skip all fields for.
+	#[cfg_attr(feature = "proptest", allow(rustdoc::redundant_explicit_links))]
 	pub skip_inner: Skip,
 	/// Comparing variant will yield `false` for [`PartialEq`] and [`None`] for
 	/// [`PartialOrd`].
 	pub incomparable: Incomparable,
+	/// Relative weight of this variant for `ProptestArbitrary`.
+	#[cfg(feature = "proptest")]
+	pub proptest_weight: ProptestWeight,
+	/// Overwrite the enum with this variant after zeroizing it on [`Drop`].
+	#[cfg(feature = "zeroize")]
+	pub zeroize_to: ZeroizeTo,
 }
 
 impl VariantAttr {
@@ -68,6 +79,32 @@ impl VariantAttr {
 				} else if meta.path().is_ident(Incomparable::INCOMPARABLE) {
 					self.incomparable.add_attribute(meta, derive_wheres)?;
 				} else {
+					#[cfg(feature = "zeroize")]
+					{
+						if meta.path().is_ident(ZeroizeTo::ZEROIZE_TO) {
+							// Only allow `zeroize_to` on empty variants.
+							match &variant.fields {
+								Fields::Named(fields) if !fields.named.is_empty() => {
+									return Err(Error::zeroize_to_unit(variant.span()))
+								}
+								Fields::Unnamed(fields) if !fields.unnamed.is_empty() => {
+									return Err(Error::zeroize_to_unit(variant.span()))
+								}
+								_ => self.zeroize_to.add_attribute(meta, derive_wheres)?,
+							}
+
+							continue;
+						}
+					}
+
+					#[cfg(feature = "proptest")]
+					{
+						if meta.path().is_ident(Trait::ProptestArbitrary.as_str()) {
+							self.proptest_weight.add_attribute(meta, derive_wheres)?;
+							continue;
+						}
+					}
+
 					return Err(Error::option(meta.path().span()));
 				}
 			}
@@ -0,0 +1,177 @@
+//! Attribute parsing for the `Zeroize(fqs)`, `Zeroize(order = ..)` and
+//! `Zeroize(with = ..)` options.
+
+use syn::{
+	spanned::Spanned, Expr, ExprLit, ExprPath, ExprUnary, Lit, Meta, MetaNameValue, Path, Result,
+	UnOp,
+};
+
+use crate::{util::MetaListExt, DeriveTrait, DeriveWhere, Error, Trait};
+
+/// Stores how [`Zeroize::zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html#tymethod.zeroize)
+/// is called on this field.
+#[derive(Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct ZeroizeField {
+	/// Use fully-qualified-syntax.
+	pub fqs: bool,
+	/// Position of this field when zeroizing, lower is zeroized first.
+	pub order: Option<i32>,
+	/// Function to call with `&mut` to this field instead.
+	pub with: Option<Path>,
+}
+
+impl ZeroizeField {
+	/// Token used for the `Zeroize(fqs)` option.
+	const FQS: &'static str = "fqs";
+	/// Token used for the `Zeroize(order = ..)` option.
+	const ORDER: &'static str = "order";
+	/// Token used for the `Zeroize(with = ..)` option.
+	const WITH: &'static str = "with";
+
+	/// Adds a [`Meta`] to this [`ZeroizeField`].
+	pub fn add_attribute(&mut self, meta: &Meta, derive_wheres: &[DeriveWhere]) -> Result<()> {
+		debug_assert!(meta.path().is_ident(Trait::Zeroize.as_str()));
+
+		if !derive_wheres.iter().any(|derive_where| {
+			derive_where.contains(Trait::Zeroize) || derive_where.contains(Trait::ZeroizeOnDrop)
+		}) {
+			return Err(Error::zeroize(meta.span()));
+		}
+
+		let mut impl_zeroize = false;
+		let mut impl_drop = false;
+
+		for trait_ in derive_wheres
+			.iter()
+			.flat_map(|derive_where| derive_where.traits.iter())
+		{
+			match trait_ {
+				DeriveTrait::Zeroize(_) => impl_zeroize = true,
+				// Without a `Drop` implementation no field is zeroized.
+				DeriveTrait::ZeroizeOnDrop(zeroize_on_drop) => {
+					impl_drop |= !zeroize_on_drop.no_drop
+				}
+				_ => (),
+			}
+		}
+
+		match meta {
+			Meta::List(list) => {
+				let nested = list.parse_non_empty_nested_metas()?;
+
+				for meta in &nested {
+					match meta {
+						Meta::Path(path) if path.is_ident(Self::FQS) => {
+							if self.fqs {
+								return Err(Error::option_duplicate(path.span(), Self::FQS));
+							} else if !impl_zeroize {
+								return Err(Error::zeroize_fqs(path.span()));
+							} else if self.with.is_some() {
+								return Err(Error::zeroize_option_combination(
+									path.span(),
+									Self::FQS,
+									Self::WITH,
+								));
+							} else {
+								self.fqs = true
+							}
+						}
+						Meta::NameValue(name_value) if name_value.path.is_ident(Self::ORDER) => {
+							if self.order.is_some() {
+								return Err(Error::option_duplicate(
+									name_value.span(),
+									Self::ORDER,
+								));
+							}
+
+							Self::check_drop(name_value, Self::ORDER, impl_zeroize, impl_drop)?;
+
+							// Negative numbers are parsed as a unary expression.
+							let (negative, expr) = match &name_value.value {
+								Expr::Unary(ExprUnary {
+									op: UnOp::Neg(_),
+									expr,
+									..
+								}) => (true, &**expr),
+								expr => (false, expr),
+							};
+
+							match expr {
+								Expr::Lit(ExprLit {
+									lit: Lit::Int(lit), ..
+								}) => {
+									let order = lit.base10_parse::<i32>()?;
+									self.order = Some(if negative { -order } else { order });
+								}
+								_ => return Err(Error::zeroize_order(name_value.value.span())),
+							}
+						}
+						Meta::NameValue(name_value) if name_value.path.is_ident(Self::WITH) => {
+							if self.with.is_some() {
+								return Err(Error::option_duplicate(name_value.span(), Self::WITH));
+							} else if self.fqs {
+								return Err(Error::zeroize_option_combination(
+									name_value.span(),
+									Self::FQS,
+									Self::WITH,
+								));
+							}
+
+							Self::check_drop(name_value, Self::WITH, impl_zeroize, impl_drop)?;
+
+							if let Expr::Path(ExprPath { path, .. }) = &name_value.value {
+								self.with = Some(path.clone())
+							} else {
+								return Err(Error::option_syntax(name_value.value.span()));
+							}
+						}
+						Meta::Path(path) => return Err(Error::option(path.span())),
+						_ => return Err(Error::option_syntax(meta.span())),
+					}
+				}
+
+				Ok(())
+			}
+			Meta::Path(path) => Err(Error::option_required(path.span(), Trait::Zeroize.as_str())),
+			_ => Err(Error::option_syntax(meta.span())),
+		}
+	}
+
+	/// Checks that an implementation applying `option` is derived. Without
+	/// the `zeroize-on-drop` feature, [`Drop`] only calls
+	/// [`Zeroize::zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html#tymethod.zeroize),
+	/// which only applies `option` if [`Zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html)
+	/// is derived too.
+	fn check_drop(
+		name_value: &MetaNameValue,
+		option: &str,
+		impl_zeroize: bool,
+		impl_drop: bool,
+	) -> Result<()> {
+		if impl_zeroize {
+			Ok(())
+		} else if !impl_drop {
+			Err(Error::zeroize_option_combination(
+				name_value.span(),
+				option,
+				"no_drop",
+			))
+		} else {
+			#[cfg(feature = "zeroize-on-drop")]
+			return Ok(());
+			#[cfg(not(feature = "zeroize-on-drop"))]
+			Err(Error::zeroize_on_drop_option(name_value.span(), option))
+		}
+	}
+
+	/// Returns `true` if any option is set.
+	pub fn is_some(&self) -> bool {
+		self.fqs || self.order.is_some() || self.with.is_some()
+	}
+
+	/// Returns the position of this field when zeroizing, defaults to `0`.
+	pub fn order(&self) -> i32 {
+		self.order.unwrap_or(0)
+	}
+}
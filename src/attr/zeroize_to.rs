@@ -0,0 +1,58 @@
+//! Attribute parsing for the `zeroize_to` option.
+
+use proc_macro2::Span;
+use syn::{spanned::Spanned, Meta, Result};
+
+use crate::{DeriveTrait, DeriveWhere, Error};
+
+/// Stores if this variant should overwrite the enum after zeroizing it on
+/// [`Drop`] when implementing
+/// [`ZeroizeOnDrop`](https://docs.rs/zeroize/latest/zeroize/trait.ZeroizeOnDrop.html).
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(test, derive(Debug))]
+pub struct ZeroizeTo(pub Option<Span>);
+
+impl ZeroizeTo {
+	/// Token used for the `zeroize_to` option.
+	pub const ZEROIZE_TO: &'static str = "zeroize_to";
+
+	/// Adds a [`Meta`] to this [`ZeroizeTo`].
+	pub fn add_attribute(&mut self, meta: &Meta, derive_wheres: &[DeriveWhere]) -> Result<()> {
+		debug_assert!(meta.path().is_ident(Self::ZEROIZE_TO));
+
+		if let Meta::Path(path) = meta {
+			if self.0.is_some() {
+				Err(Error::option_duplicate(path.span(), Self::ZEROIZE_TO))
+			} else {
+				let mut impl_zeroize_on_drop = false;
+
+				for trait_ in derive_wheres
+					.iter()
+					.flat_map(|derive_where| derive_where.traits.iter())
+				{
+					if let DeriveTrait::ZeroizeOnDrop(zeroize_on_drop) = trait_ {
+						// Without a `Drop` implementation there is nothing to overwrite.
+						if zeroize_on_drop.no_drop {
+							return Err(Error::zeroize_option_combination(
+								path.span(),
+								Self::ZEROIZE_TO,
+								"no_drop",
+							));
+						}
+
+						impl_zeroize_on_drop = true;
+					}
+				}
+
+				if impl_zeroize_on_drop {
+					self.0 = Some(path.span());
+					Ok(())
+				} else {
+					Err(Error::zeroize_to(path.span()))
+				}
+			}
+		} else {
+			Err(Error::option_syntax(meta.span()))
+		}
+	}
+}
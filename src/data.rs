@@ -4,15 +4,21 @@ mod field;
 mod fields;
 
 use proc_macro2::Span;
-#[cfg(not(feature = "nightly"))]
+#[cfg(any(feature = "borsh", not(feature = "nightly")))]
 use syn::Expr;
-use syn::{FieldsNamed, Ident, Pat, PatPath, Path, Result, Variant};
+use syn::{Attribute, FieldsNamed, Ident, Pat, PatPath, Path, Result, Variant};
 
 pub use self::{
 	field::{Field, Member},
 	fields::Fields,
 };
-use crate::{util, Default, DeriveWhere, Either, Error, Incomparable, Skip, Trait, VariantAttr};
+#[cfg(feature = "proptest")]
+use crate::ProptestWeight;
+#[cfg(feature = "zeroize")]
+use crate::ZeroizeTo;
+use crate::{
+	util, Default, DeriveWhere, Either, Error, Incomparable, Secret, Skip, Trait, VariantAttr,
+};
 
 /// Holds all relevant data of a struct, union or variant.
 #[cfg_attr(test, derive(Debug))]
@@ -21,6 +27,8 @@ pub struct Data<'a> {
 	skip_inner: Skip,
 	/// [`Incomparable`] attribute of this struct, union or variant.
 	pub incomparable: Incomparable,
+	/// [`Secret`] attribute of this struct, union or variant.
+	pub secret: Secret,
 	/// [`struct@Ident`] of this struct, union or variant, used for implementing
 	/// [`Debug`](std::fmt::Debug).
 	pub ident: &'a Ident,
@@ -29,9 +37,13 @@ pub struct Data<'a> {
 	pub path: Path,
 	/// [Type](DataType) of this struct, union or variant.
 	pub type_: DataType<'a>,
-	#[cfg(not(feature = "nightly"))]
+	#[cfg(any(feature = "borsh", not(feature = "nightly")))]
 	/// Discriminant of this variant.
 	pub discriminant: Option<&'a Expr>,
+	/// [`Attribute`]s of this struct, union or variant, used for `serde`
+	/// attributes.
+	#[cfg(feature = "serde")]
+	pub attrs: &'a [Attribute],
 }
 
 /// Type of this data.
@@ -47,6 +59,12 @@ pub enum DataType<'a> {
 	Variant {
 		/// [`struct@Default`] attribute of this variant.
 		default: Default,
+		/// [`ProptestWeight`] attribute of this variant.
+		#[cfg(feature = "proptest")]
+		proptest_weight: ProptestWeight,
+		/// [`ZeroizeTo`] attribute of this variant.
+		#[cfg(feature = "zeroize")]
+		zeroize_to: ZeroizeTo,
 		/// [Type](VariantType) of this variant.
 		type_: VariantType<'a>,
 	},
@@ -79,11 +97,14 @@ pub enum SimpleType<'a> {
 
 impl<'a> Data<'a> {
 	/// Create [`Data`]s from [`syn::Fields`] of a struct.
+	#[allow(clippy::too_many_arguments)]
 	pub fn from_struct(
 		span: Span,
+		#[cfg_attr(not(feature = "serde"), allow(unused_variables))] attrs: &'a [Attribute],
 		derive_wheres: &[DeriveWhere],
 		skip_inner: Skip,
 		incomparable: Incomparable,
+		secret: Secret,
 		ident: &'a Ident,
 		fields: &'a syn::Fields,
 	) -> Result<Self> {
@@ -100,11 +121,14 @@ impl<'a> Data<'a> {
 					Ok(Self {
 						skip_inner,
 						incomparable,
+						secret,
 						ident,
 						path,
 						type_: DataType::Struct(fields),
-						#[cfg(not(feature = "nightly"))]
+						#[cfg(any(feature = "borsh", not(feature = "nightly")))]
 						discriminant: None,
+						#[cfg(feature = "serde")]
+						attrs,
 					})
 				}
 			}
@@ -118,17 +142,21 @@ impl<'a> Data<'a> {
 					Ok(Self {
 						skip_inner,
 						incomparable,
+						secret,
 						ident,
 						path,
 						type_: DataType::Tuple(fields),
-						#[cfg(not(feature = "nightly"))]
+						#[cfg(any(feature = "borsh", not(feature = "nightly")))]
 						discriminant: None,
+						#[cfg(feature = "serde")]
+						attrs,
 					})
 				}
 			}
 			syn::Fields::Unit if incomparable.0.is_some() => Ok(Self {
 				skip_inner,
 				incomparable,
+				secret,
 				ident,
 				path: path.clone(),
 				type_: DataType::Unit(Pat::Path(PatPath {
@@ -136,8 +164,10 @@ impl<'a> Data<'a> {
 					qself: None,
 					path,
 				})),
-				#[cfg(not(feature = "nightly"))]
+				#[cfg(any(feature = "borsh", not(feature = "nightly")))]
 				discriminant: None,
+				#[cfg(feature = "serde")]
+				attrs,
 			}),
 			syn::Fields::Unit => Err(Error::item_empty(span)),
 		}
@@ -146,6 +176,7 @@ impl<'a> Data<'a> {
 	/// Create [`Data`]s from [`FieldsNamed`] of an union.
 	pub fn from_union(
 		span: Span,
+		#[cfg_attr(not(feature = "serde"), allow(unused_variables))] attrs: &'a [Attribute],
 		derive_wheres: &[DeriveWhere],
 		skip_inner: Skip,
 		incomparable: Incomparable,
@@ -161,11 +192,15 @@ impl<'a> Data<'a> {
 			Ok(Self {
 				skip_inner,
 				incomparable,
+				// Unions don't support any trait affected by `secret`.
+				secret: Secret::default(),
 				ident,
 				path,
 				type_: DataType::Union(fields),
-				#[cfg(not(feature = "nightly"))]
+				#[cfg(any(feature = "borsh", not(feature = "nightly")))]
 				discriminant: None,
+				#[cfg(feature = "serde")]
+				attrs,
 			})
 		}
 	}
@@ -174,6 +209,7 @@ impl<'a> Data<'a> {
 	pub fn from_variant(
 		item_ident: &'a Ident,
 		derive_wheres: &[DeriveWhere],
+		secret: Secret,
 		variant: &'a Variant,
 	) -> Result<Self> {
 		// Parse `Attribute`s on variant.
@@ -181,6 +217,10 @@ impl<'a> Data<'a> {
 			default,
 			skip_inner,
 			incomparable,
+			#[cfg(feature = "proptest")]
+			proptest_weight,
+			#[cfg(feature = "zeroize")]
+			zeroize_to,
 		} = VariantAttr::from_attrs(&variant.attrs, derive_wheres, variant)?;
 
 		let path = util::path_from_idents(&[item_ident, &variant.ident]);
@@ -192,14 +232,21 @@ impl<'a> Data<'a> {
 				Ok(Self {
 					skip_inner,
 					incomparable,
+					secret,
 					ident: &variant.ident,
 					path,
 					type_: DataType::Variant {
 						default,
+						#[cfg(feature = "proptest")]
+						proptest_weight,
+						#[cfg(feature = "zeroize")]
+						zeroize_to,
 						type_: VariantType::Struct(fields),
 					},
-					#[cfg(not(feature = "nightly"))]
+					#[cfg(any(feature = "borsh", not(feature = "nightly")))]
 					discriminant: variant.discriminant.as_ref().map(|(_, expr)| expr),
+					#[cfg(feature = "serde")]
+					attrs: &variant.attrs,
 				})
 			}
 			syn::Fields::Unnamed(fields) => {
@@ -209,14 +256,21 @@ impl<'a> Data<'a> {
 				Ok(Self {
 					skip_inner,
 					incomparable,
+					secret,
 					ident: &variant.ident,
 					path,
 					type_: DataType::Variant {
 						default,
+						#[cfg(feature = "proptest")]
+						proptest_weight,
+						#[cfg(feature = "zeroize")]
+						zeroize_to,
 						type_: VariantType::Tuple(fields),
 					},
-					#[cfg(not(feature = "nightly"))]
+					#[cfg(any(feature = "borsh", not(feature = "nightly")))]
 					discriminant: variant.discriminant.as_ref().map(|(_, expr)| expr),
+					#[cfg(feature = "serde")]
+					attrs: &variant.attrs,
 				})
 			}
 			syn::Fields::Unit => {
@@ -229,14 +283,21 @@ impl<'a> Data<'a> {
 				Ok(Self {
 					skip_inner,
 					incomparable,
+					secret,
 					ident: &variant.ident,
 					path,
 					type_: DataType::Variant {
 						default,
+						#[cfg(feature = "proptest")]
+						proptest_weight,
+						#[cfg(feature = "zeroize")]
+						zeroize_to,
 						type_: VariantType::Unit(pattern),
 					},
-					#[cfg(not(feature = "nightly"))]
+					#[cfg(any(feature = "borsh", not(feature = "nightly")))]
 					discriminant: variant.discriminant.as_ref().map(|(_, expr)| expr),
+					#[cfg(feature = "serde")]
+					attrs: &variant.attrs,
 				})
 			}
 		}
@@ -282,6 +343,41 @@ impl<'a> Data<'a> {
 		}
 	}
 
+	/// Returns the relative weight of this variant for `ProptestArbitrary`. If
+	/// not a variant or no weight was specified, returns `1`.
+	#[cfg(feature = "proptest")]
+	pub fn proptest_weight(&self) -> u32 {
+		match self.type_ {
+			DataType::Variant {
+				proptest_weight, ..
+			} => proptest_weight.0.map_or(1, |(_, weight)| weight),
+			_ => 1,
+		}
+	}
+
+	/// Returns the [`Span`] of the `ProptestArbitrary(weight = ..)` option if
+	/// specified, otherwise of the [`struct@Ident`] of this variant.
+	#[cfg(feature = "proptest")]
+	pub fn proptest_weight_span(&self) -> Span {
+		match self.type_ {
+			DataType::Variant {
+				proptest_weight: ProptestWeight(Some((span, _))),
+				..
+			} => span,
+			_ => self.ident.span(),
+		}
+	}
+
+	/// Returns [`Some`] if this variant is marked as [`ZeroizeTo`]. If not a
+	/// variant, always returns [`None`].
+	#[cfg(feature = "zeroize")]
+	pub fn zeroize_to_span(&self) -> Option<Span> {
+		match &self.type_ {
+			DataType::Variant { zeroize_to, .. } => zeroize_to.0,
+			_ => None,
+		}
+	}
+
 	/// Returns `true` if this item or variant is marked as [`Incomparable`].
 	pub fn is_incomparable(&self) -> bool {
 		self.incomparable.0.is_some()
@@ -21,6 +21,9 @@ pub struct Field<'a> {
 	pub other_ident: Ident,
 	/// [`Type`] used for asserting traits on fields for [`Eq`].
 	pub type_: &'a Type,
+	/// [`Attribute`]s of this field, used for `serde` attributes.
+	#[cfg(feature = "serde")]
+	pub attrs: &'a [Attribute],
 }
 
 /// Borrowed version of [`syn::Member`], to avoid unnecessary allocations.
@@ -105,7 +108,7 @@ impl<'a> Field<'a> {
 	fn from_field(
 		derive_wheres: &[DeriveWhere],
 		skip_inner: &Skip,
-		attrs: &[Attribute],
+		attrs: &'a [Attribute],
 		member: Member<'a>,
 		type_: &'a Type,
 	) -> Result<Self> {
@@ -119,6 +122,8 @@ impl<'a> Field<'a> {
 			self_ident,
 			other_ident,
 			type_,
+			#[cfg(feature = "serde")]
+			attrs,
 		})
 	}
 
@@ -67,14 +67,25 @@ impl Error {
 
 	/// Unsupported trait for union.
 	pub fn union(span: Span) -> syn::Error {
-		syn::Error::new(
+		#[cfg(not(feature = "zeroize"))]
+		return syn::Error::new(
 			span,
 			"traits other then `Clone` and `Copy` aren't supported by unions",
+		);
+		#[cfg(feature = "zeroize")]
+		syn::Error::new(
+			span,
+			"traits other then `Clone`, `Copy`, `Zeroize(union)` and `ZeroizeOnDrop(union)` \
+			 aren't supported by unions",
 		)
 	}
 
+	/// Unsupported trait for enum.
+	pub fn enum_(span: Span, trait_: &str) -> syn::Error {
+		syn::Error::new(span, format!("`{}` isn't supported by enums", trait_))
+	}
+
 	/// Unsupported option in attribute.
-	#[cfg(feature = "zeroize")]
 	pub fn option_trait(span: Span, attribute: &str) -> syn::Error {
 		syn::Error::new(span, format!("`{}` doesn't support this option", attribute))
 	}
@@ -100,7 +111,6 @@ impl Error {
 	}
 
 	/// Missing sub-option for an option.
-	#[cfg(feature = "zeroize")]
 	pub fn option_required(span: Span, option: &str) -> syn::Error {
 		syn::Error::new(span, format!("`{}` requires an option", option))
 	}
@@ -167,7 +177,8 @@ impl Error {
 		)
 	}
 
-	/// Invalid value for the `derive_where`, `serde` or `Zeroize` `crate`
+	/// Invalid value for the `derive_where`, `serde`,
+	/// `ConditionallySelectable`, `ConstantTimeEq` or `Zeroize` `crate`
 	/// option.
 	pub fn path(span: Span, parse_error: syn::Error) -> syn::Error {
 		syn::Error::new(span, format!("expected path, {}", parse_error))
@@ -192,12 +203,72 @@ impl Error {
 		)
 	}
 
+	/// Requires crate feature `arbitrary`.
+	#[cfg(not(feature = "arbitrary"))]
+	pub fn arbitrary_feature(span: Span) -> syn::Error {
+		syn::Error::new(span, "requires crate feature `arbitrary`")
+	}
+
+	/// Requires crate feature `borsh`.
+	#[cfg(not(feature = "borsh"))]
+	pub fn borsh_feature(span: Span) -> syn::Error {
+		syn::Error::new(span, "requires crate feature `borsh`")
+	}
+
+	/// Requires crate feature `bytemuck`.
+	#[cfg(not(feature = "bytemuck"))]
+	pub fn bytemuck_feature(span: Span) -> syn::Error {
+		syn::Error::new(span, "requires crate feature `bytemuck`")
+	}
+
+	/// Requires crate feature `defmt`.
+	#[cfg(not(feature = "defmt"))]
+	pub fn defmt_feature(span: Span) -> syn::Error {
+		syn::Error::new(span, "requires crate feature `defmt`")
+	}
+
+	/// Requires crate feature `nightly`.
+	#[cfg(not(feature = "nightly"))]
+	pub fn nightly_feature(span: Span) -> syn::Error {
+		syn::Error::new(span, "requires crate feature `nightly`")
+	}
+
+	/// Requires crate feature `proptest`.
+	#[cfg(not(feature = "proptest"))]
+	pub fn proptest_feature(span: Span) -> syn::Error {
+		syn::Error::new(span, "requires crate feature `proptest`")
+	}
+
+	/// Requires crate feature `rkyv`.
+	#[cfg(not(feature = "rkyv"))]
+	pub fn rkyv_feature(span: Span) -> syn::Error {
+		syn::Error::new(span, "requires crate feature `rkyv`")
+	}
+
+	/// Requires crate feature `schemars`.
+	#[cfg(not(feature = "schemars"))]
+	pub fn schemars_feature(span: Span) -> syn::Error {
+		syn::Error::new(span, "requires crate feature `schemars`")
+	}
+
 	/// Requires crate feature `serde`.
 	#[cfg(not(feature = "serde"))]
 	pub fn serde_feature(span: Span) -> syn::Error {
 		syn::Error::new(span, "requires crate feature `serde`")
 	}
 
+	/// Requires crate feature `subtle`.
+	#[cfg(not(feature = "subtle"))]
+	pub fn subtle_feature(span: Span) -> syn::Error {
+		syn::Error::new(span, "requires crate feature `subtle`")
+	}
+
+	/// Requires crate feature `zerocopy`.
+	#[cfg(not(feature = "zerocopy"))]
+	pub fn zerocopy_feature(span: Span) -> syn::Error {
+		syn::Error::new(span, "requires crate feature `zerocopy`")
+	}
+
 	/// Requires crate feature `zeroize`.
 	#[cfg(not(feature = "zeroize"))]
 	pub fn zeroize_feature(span: Span) -> syn::Error {
@@ -210,6 +281,20 @@ impl Error {
 		syn::Error::new(span, "requires crate feature `zeroize-on-drop`")
 	}
 
+	/// Unsupported `Zeroize` option if only [`ZeroizeOnDrop`](https://docs.rs/zeroize/latest/zeroize/trait.ZeroizeOnDrop.html)
+	/// is implemented without the `zeroize-on-drop` crate feature.
+	#[cfg(all(feature = "zeroize", not(feature = "zeroize-on-drop")))]
+	pub fn zeroize_on_drop_option(span: Span, option: &str) -> syn::Error {
+		syn::Error::new(
+			span,
+			format!(
+				"`{}` requires crate feature `zeroize-on-drop` unless `Zeroize` is being \
+				 implemented",
+				option
+			),
+		)
+	}
+
 	/// Invalid delimiter in `derive_where` attribute for
 	/// [`Trait`](crate::Trait)s.
 	pub fn derive_where_delimiter(span: Span) -> syn::Error {
@@ -218,7 +303,19 @@ impl Error {
 
 	/// Unsupported predicate type in `derive_where` attribute for where clause.
 	pub fn generic(span: Span) -> syn::Error {
-		syn::Error::new(span, "only type predicates are supported")
+		syn::Error::new(span, "only type and lifetime predicates are supported")
+	}
+
+	/// Lifetime in a custom bound that isn't declared on the item or by the
+	/// trait.
+	pub fn lifetime_trait(span: Span, lifetime: &syn::Lifetime, trait_: &str) -> syn::Error {
+		syn::Error::new(
+			span,
+			format!(
+				"undeclared lifetime `{}`, `{}` has no such lifetime parameter",
+				lifetime, trait_
+			),
+		)
 	}
 
 	/// Invalid syntax in `derive_where` attribute for generics.
@@ -226,6 +323,38 @@ impl Error {
 		syn::Error::new(span, format!("expected type to bind to, {}", parse_error))
 	}
 
+	/// Unsafe [`Trait`](crate::Trait) used without `unsafe(..)`.
+	pub fn unsafe_required(span: Span, trait_: &str) -> syn::Error {
+		syn::Error::new(
+			span,
+			format!(
+				"`{}` is an unsafe trait and has to be specified with `unsafe({})`",
+				trait_, trait_
+			),
+		)
+	}
+
+	/// Safe [`Trait`](crate::Trait) used inside `unsafe(..)`.
+	pub fn unsafe_unexpected(span: Span, trait_: &str) -> syn::Error {
+		syn::Error::new(
+			span,
+			format!(
+				"`{}` is not an unsafe trait and can't be specified in `unsafe(..)`",
+				trait_
+			),
+		)
+	}
+
+	/// Unsafe [`Trait`](crate::Trait)s are not supported with the `safe`
+	/// crate feature.
+	#[cfg(feature = "safe")]
+	pub fn unsafe_safe(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"unsafe traits aren't supported with the crate feature `safe`",
+		)
+	}
+
 	/// Duplicate trait with the same bound.
 	pub fn trait_duplicate(span: Span) -> syn::Error {
 		syn::Error::new(span, "duplicate trait with the same bound")
@@ -248,20 +377,22 @@ impl Error {
 		)
 	}
 
-	/// Unsupported default option if [`Default`] isn't implemented.
+	/// Unsupported default option if [`Default`] or `ConstDefault` isn't
+	/// implemented.
 	pub fn default(span: Span) -> syn::Error {
 		syn::Error::new(
 			span,
-			"`default` is only supported if `Default` is being implemented",
+			"`default` is only supported if `Default` or `ConstDefault` is being implemented",
 		)
 	}
 
-	/// Missing `default` option on a variant when [`Default`] is implemented
-	/// for an enum.
+	/// Missing `default` option on a variant when [`Default`] or `ConstDefault`
+	/// is implemented for an enum.
 	pub fn default_missing(span: Span) -> syn::Error {
 		syn::Error::new(
 			span,
-			"required `default` option on a variant if `Default` is being implemented",
+			"required `default` option on a variant if `Default` or `ConstDefault` is being \
+			 implemented",
 		)
 	}
 
@@ -296,31 +427,115 @@ impl Error {
 		)
 	}
 
+	/// Unsupported `secret` option if [`Debug`](trait@std::fmt::Debug),
+	/// `Format`, [`PartialEq`] or `Serialize` isn't implemented.
+	pub fn secret(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`secret` is only supported if `Debug`, `Format`, `PartialEq` or `Serialize` is being \
+			 implemented",
+		)
+	}
+
+	/// Unsupported `secret` option with a `const` [`PartialEq`]
+	/// implementation.
+	pub fn secret_const(span: Span) -> syn::Error {
+		syn::Error::new(span, "`secret` can't be combined with `PartialEq(const)`")
+	}
+
+	/// Unsupported `secret` option with [`PartialEq`] without the `subtle`
+	/// crate feature.
+	#[cfg(not(feature = "subtle"))]
+	pub fn secret_subtle_feature(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`secret` with `PartialEq` requires the `subtle` crate feature",
+		)
+	}
+
+	/// Serialized field in a `secret` item without `Serialize(expose)`.
+	#[cfg(feature = "serde")]
+	pub fn secret_serialize(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"fields of `secret` items have to be skipped or use `Serialize(expose)` if \
+			 `Serialize` is being implemented",
+		)
+	}
+
+	/// Unsupported `Serialize(expose)` option on items that aren't `secret`.
+	#[cfg(feature = "serde")]
+	pub fn expose(span: Span) -> syn::Error {
+		syn::Error::new(span, "`expose` is only supported on `secret` items")
+	}
+
 	/// Unsupported `skip(Clone)` while deriving copy.
 	pub fn unable_to_skip_clone_while_deriving_copy(skip_clone: Span) -> syn::Error {
 		syn::Error::new(skip_clone, "Cannot skip `Clone` while deriving `Copy`")
 	}
 
-	/// Conflicting `serde(bound ...)` when deriving `De/Serialize`.
+	/// Unparsable `bound` predicates in `serde` or `schemars` attributes.
+	#[cfg(any(feature = "schemars", feature = "serde"))]
+	pub fn bound_predicates(span: Span, parse_error: syn::Error) -> syn::Error {
+		syn::Error::new(
+			span,
+			format!("expected `where` predicates, {}", parse_error),
+		)
+	}
+
+	/// `#[serde(crate = "...")]` used together with the `crate` option.
 	#[cfg(feature = "serde")]
-	pub fn serde_bound(serde: Span) -> syn::Error {
-		syn::Error::new(serde, "Found conflicting `#[serde(bound ...)]`")
+	pub fn serde_crate(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`#[serde(crate = \"...\")]` conflicts with the `crate` option, use only one of them",
+		)
+	}
+
+	/// `#[rkyv(crate = ..)]` used together with the `crate` option.
+	#[cfg(feature = "rkyv")]
+	pub fn rkyv_crate(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`#[rkyv(crate = ..)]` conflicts with the `crate` option, use only one of them",
+		)
 	}
 
 	/// List of available [`Trait`](crate::Trait)s.
 	fn trait_list() -> String {
 		[
+			"Arbitrary",
+			"Archive",
+			"BorshDeserialize",
+			"BorshSerialize",
 			"Clone",
+			"ConditionallySelectable",
+			"ConstDefault",
+			"ConstantTimeEq",
 			"Copy",
 			"Debug",
 			"Default",
 			"Deserialize",
 			"Eq",
+			"Format",
+			"FromBytes",
+			"FromZeros",
 			"Hash",
+			"Immutable",
+			"IntoBytes",
+			"JsonSchema",
+			"KnownLayout",
 			"Ord",
 			"PartialEq",
 			"PartialOrd",
+			"Pod",
+			"ProptestArbitrary",
+			"RkyvDeserialize",
+			"RkyvSerialize",
+			"Send",
 			"Serialize",
+			"Sync",
+			"Zeroable",
 			"Zeroize",
 			"ZeroizeOnDrop",
 		]
@@ -329,18 +544,157 @@ impl Error {
 
 	/// List of available [`SkipGroup`](crate::SkipGroup)s.
 	fn skip_group_list() -> String {
-		["Clone", "Debug", "EqHashOrd", "Hash", "Zeroize"].join(", ")
+		[
+			"Arbitrary",
+			"Archive",
+			"Clone",
+			"ConstantTimeEq",
+			"Debug",
+			"Deserialize",
+			"EqHashOrd",
+			"Format",
+			"Hash",
+			"JsonSchema",
+			"ProptestArbitrary",
+			"Serde",
+			"Serialize",
+			"Zeroize",
+		]
+		.join(", ")
 	}
 
-	/// Unsupported `Zeroize` option if [`Zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html) isn't implemented.
+	/// Unsupported `ConstDefault` option if `ConstDefault` isn't implemented.
+	pub fn const_default(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`ConstDefault` option is only supported if `ConstDefault` is being implemented",
+		)
+	}
+
+	/// Generic without a custom bound on `ConstDefault`.
+	pub fn const_default_no_bound(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`ConstDefault` has no trait to bind generics to, use a custom bound or a separate \
+			 `derive_where` without generics",
+		)
+	}
+
+	/// Unsupported `ProptestArbitrary` option if `ProptestArbitrary` isn't
+	/// implemented.
+	#[cfg(feature = "proptest")]
+	pub fn proptest_arbitrary(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`ProptestArbitrary` option is only supported if `ProptestArbitrary` is being \
+			 implemented",
+		)
+	}
+
+	/// Unsupported `Serialize` option if `Serialize` isn't implemented.
+	#[cfg(feature = "serde")]
+	pub fn serialize(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`Serialize` option is only supported if `Serialize` is being implemented",
+		)
+	}
+
+	/// Invalid `weight` for `ProptestArbitrary`.
+	#[cfg(feature = "proptest")]
+	pub fn proptest_weight(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`weight` has to be an integer greater than zero and the sum of all weights has to \
+			 fit in a `u32`",
+		)
+	}
+
+	/// Unsupported `Zeroize` option if [`Zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html)
+	/// or [`ZeroizeOnDrop`](https://docs.rs/zeroize/latest/zeroize/trait.ZeroizeOnDrop.html)
+	/// isn't implemented.
 	#[cfg(feature = "zeroize")]
 	pub fn zeroize(span: Span) -> syn::Error {
 		syn::Error::new(
 			span,
-			"`Zeroize` option is only supported if `Zeroize` is being implemented",
+			"`Zeroize` option is only supported if `Zeroize` or `ZeroizeOnDrop` is being \
+			 implemented",
+		)
+	}
+
+	/// Unsupported `Zeroize(fqs)` option if [`Zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html)
+	/// isn't implemented.
+	#[cfg(feature = "zeroize")]
+	pub fn zeroize_fqs(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`fqs` is only supported if `Zeroize` is being implemented",
+		)
+	}
+
+	/// Missing `union` option on a union for [`Zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html)
+	/// or [`ZeroizeOnDrop`](https://docs.rs/zeroize/latest/zeroize/trait.ZeroizeOnDrop.html).
+	#[cfg(feature = "zeroize")]
+	pub fn zeroize_union_required(span: Span, trait_: &str) -> syn::Error {
+		syn::Error::new(
+			span,
+			format!("`{}` requires the `union` option on unions", trait_),
+		)
+	}
+
+	/// Unsupported combination of two options.
+	#[cfg(feature = "zeroize")]
+	pub fn zeroize_option_combination(span: Span, option: &str, other: &str) -> syn::Error {
+		syn::Error::new(
+			span,
+			format!("`{}` can't be combined with `{}`", option, other),
 		)
 	}
 
+	/// Unsupported `union` option on items other than unions.
+	#[cfg(feature = "zeroize")]
+	pub fn zeroize_union(span: Span) -> syn::Error {
+		syn::Error::new(span, "the `union` option is only supported on unions")
+	}
+
+	/// Unsupported `zeroize_to` option if [`ZeroizeOnDrop`](https://docs.rs/zeroize/latest/zeroize/trait.ZeroizeOnDrop.html)
+	/// isn't implemented.
+	#[cfg(feature = "zeroize")]
+	pub fn zeroize_to(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`zeroize_to` is only supported if `ZeroizeOnDrop` is being implemented",
+		)
+	}
+
+	/// `zeroize_to` option isn't supported with the `safe` crate feature, as
+	/// it requires `unsafe` code.
+	#[cfg(all(feature = "safe", feature = "zeroize"))]
+	pub fn zeroize_to_safe(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`zeroize_to` isn't supported with the crate feature `safe`",
+		)
+	}
+
+	/// Unsupported `zeroize_to` option on a variant with fields.
+	#[cfg(feature = "zeroize")]
+	pub fn zeroize_to_unit(span: Span) -> syn::Error {
+		syn::Error::new(span, "`zeroize_to` is only supported on unit variants")
+	}
+
+	/// Duplicate `zeroize_to` option on a variant.
+	#[cfg(feature = "zeroize")]
+	pub fn zeroize_to_duplicate(span: Span) -> syn::Error {
+		syn::Error::new(span, "multiple `zeroize_to` options in enum")
+	}
+
+	/// Invalid `order` for `Zeroize`.
+	#[cfg(feature = "zeroize")]
+	pub fn zeroize_order(span: Span) -> syn::Error {
+		syn::Error::new(span, "`order` has to be an integer")
+	}
+
 	/// Deprecated use of `Zeroize(drop)`.
 	#[cfg(feature = "zeroize")]
 	pub fn deprecated_zeroize_drop(span: Span) -> syn::Error {
@@ -349,4 +703,44 @@ impl Error {
 			"`Zeroize(drop)` is deprecated, use `ZeroizeOnDrop` instead",
 		)
 	}
+
+	/// Missing `repr` required for a defined layout on structs.
+	#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+	pub fn layout_repr(span: Span, trait_: &str) -> syn::Error {
+		syn::Error::new(
+			span,
+			format!(
+				"`{}` requires `#[repr(C)]`, `#[repr(transparent)]` or `#[repr(packed)]`",
+				trait_
+			),
+		)
+	}
+
+	/// Missing `repr` required by `zerocopy` on enums.
+	#[cfg(feature = "zerocopy")]
+	pub fn zerocopy_repr_enum(span: Span, trait_: &str) -> syn::Error {
+		syn::Error::new(
+			span,
+			format!(
+				"`{}` requires `#[repr(C)]` or a primitive representation on enums",
+				trait_
+			),
+		)
+	}
+
+	/// Unsupported generics on `Pod` items whose padding can't be checked.
+	#[cfg(feature = "bytemuck")]
+	pub fn pod_generics(span: Span) -> syn::Error {
+		syn::Error::new(
+			span,
+			"`Pod` can't check items with generics for padding, use `#[repr(transparent)]` or \
+			 `#[repr(packed)]` without `align(..)`",
+		)
+	}
+
+	/// `FromZeros` is already implemented by `FromBytes`.
+	#[cfg(feature = "zerocopy")]
+	pub fn zerocopy_from_zeros(span: Span) -> syn::Error {
+		syn::Error::new(span, "`FromZeros` is already implemented by `FromBytes`")
+	}
 }
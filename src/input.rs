@@ -1,10 +1,14 @@
 //! Parses [`DeriveInput`] into something more useful.
 
 use proc_macro2::Span;
+#[cfg(feature = "serde")]
+use syn::spanned::Spanned;
 use syn::{
 	DeriveInput, GenericParam, Generics, ImplGenerics, Path, Result, TypeGenerics, WhereClause,
 };
 
+#[cfg(feature = "bytemuck")]
+use crate::trait_::pod::Pod;
 #[cfg(not(feature = "nightly"))]
 use crate::Discriminant;
 #[cfg(feature = "zeroize")]
@@ -44,6 +48,7 @@ impl<'a> Input<'a> {
 			skip_inner,
 			derive_wheres,
 			incomparable,
+			secret,
 		} = ItemAttr::from_attrs(span, data, attrs)?;
 
 		// Find if `incomparable` is specified on any item/variant.
@@ -53,9 +58,11 @@ impl<'a> Input<'a> {
 		let item = match &data {
 			syn::Data::Struct(data) => Data::from_struct(
 				span,
+				attrs,
 				&derive_wheres,
 				skip_inner,
 				incomparable,
+				secret,
 				ident,
 				&data.fields,
 			)
@@ -67,11 +74,17 @@ impl<'a> Input<'a> {
 				let variants = data
 					.variants
 					.iter()
-					.map(|variant| Data::from_variant(ident, &derive_wheres, variant))
+					.map(|variant| Data::from_variant(ident, &derive_wheres, secret, variant))
 					.collect::<Result<Vec<Data>>>()?;
 
 				// Find if a default option is specified on a variant.
 				let mut found_default = false;
+				// Find if a `zeroize_to` option is specified on a variant.
+				#[cfg(feature = "zeroize")]
+				let mut found_zeroize_to = false;
+				// Sum of all `weight` options, which has to fit in a `u32`.
+				#[cfg(feature = "proptest")]
+				let mut proptest_weight = 0_u32;
 
 				// While searching for a default option, check for duplicates.
 				for variant in &variants {
@@ -82,19 +95,41 @@ impl<'a> Input<'a> {
 							found_default = true;
 						}
 					}
+					#[cfg(feature = "zeroize")]
+					if let Some(span) = variant.zeroize_to_span() {
+						if found_zeroize_to {
+							return Err(Error::zeroize_to_duplicate(span));
+						} else {
+							found_zeroize_to = true;
+						}
+					}
+					#[cfg(feature = "proptest")]
+					{
+						proptest_weight = proptest_weight
+							.checked_add(variant.proptest_weight())
+							.ok_or_else(|| {
+								Error::proptest_weight(variant.proptest_weight_span())
+							})?;
+					}
 					if let (Some(item), Some(variant)) = (incomparable.0, variant.incomparable.0) {
 						return Err(Error::incomparable_on_item_and_variant(item, variant));
 					}
 					found_incomparable |= variant.is_incomparable();
 				}
 
+				// `zeroize_to` overwrites the enum with `unsafe` code.
+				#[cfg(all(feature = "safe", feature = "zeroize"))]
+				if let Some(span) = variants.iter().find_map(Data::zeroize_to_span) {
+					return Err(Error::zeroize_to_safe(span));
+				}
+
 				// Make sure a variant has the `option` attribute if `Default` is being
 				// implemented.
 				if !found_default
-					&& derive_wheres
-						.iter()
-						.any(|derive_where| derive_where.contains(Trait::Default))
-				{
+					&& derive_wheres.iter().any(|derive_where| {
+						derive_where.contains(Trait::Default)
+							|| derive_where.contains(Trait::ConstDefault)
+					}) {
 					return Err(Error::default_missing(span));
 				}
 
@@ -119,6 +154,7 @@ impl<'a> Input<'a> {
 			}
 			syn::Data::Union(data) => Data::from_union(
 				span,
+				attrs,
 				&derive_wheres,
 				skip_inner,
 				incomparable,
@@ -128,6 +164,68 @@ impl<'a> Input<'a> {
 			.map(Item::Item)?,
 		};
 
+		// Fields of `secret` items have to be exposed explicitly to be serialized.
+		#[cfg(feature = "serde")]
+		{
+			let serialize = derive_wheres
+				.iter()
+				.any(|derive_where| derive_where.contains(Trait::Serialize));
+
+			let datas = match &item {
+				Item::Enum { variants, .. } => variants.as_slice(),
+				Item::Item(data) => std::slice::from_ref(data),
+			};
+
+			for data in datas {
+				if let Either::Left(fields) = data.fields() {
+					for field in &fields.fields {
+						if let Some(span) = field.attr.expose.0 {
+							if !secret.is_secret() {
+								return Err(Error::expose(span));
+							}
+						} else if secret.is_secret()
+							&& serialize && !data.skip(Trait::Serialize)
+							&& !field.skip(Trait::Serialize)
+						{
+							return Err(Error::secret_serialize(field.type_.span()));
+						}
+					}
+				}
+			}
+		}
+
+		// `Pod` requires a defined layout without padding.
+		#[cfg(feature = "bytemuck")]
+		for derive_where in &derive_wheres {
+			for (span, trait_) in derive_where.spans.iter().zip(&derive_where.traits) {
+				if trait_ == Trait::Pod {
+					Pod::check_layout(*span, attrs, generics)?;
+				}
+			}
+		}
+
+		// Lifetimes in custom bounds that aren't declared on the item have to be
+		// provided by every trait, e.g. `'de` for `Deserialize`.
+		for derive_where in &derive_wheres {
+			for lifetime in derive_where.custom_bound_lifetimes() {
+				if generics.lifetimes().any(|param| param.lifetime == lifetime) {
+					continue;
+				}
+
+				if let Some(trait_) = derive_where.traits.iter().find(|trait_| {
+					trait_
+						.lifetime()
+						.map_or(true, |name| lifetime.ident != name)
+				}) {
+					return Err(Error::lifetime_trait(
+						lifetime.span(),
+						&lifetime,
+						trait_.as_str(),
+					));
+				}
+			}
+		}
+
 		// Don't allow generic constraints be the same as generics on item unless there
 		// is a use-case for it.
 		// Count number of generic type parameters.
@@ -177,6 +275,29 @@ impl<'a> Input<'a> {
 					continue;
 				}
 
+				// Assertions and `ConstDefault` can't be derived by std.
+				if trait_ == Trait::Assert || trait_ == Trait::ConstDefault {
+					continue;
+				}
+
+				// `subtle` provides no derives.
+				#[cfg(feature = "subtle")]
+				if trait_ == Trait::ConditionallySelectable || trait_ == Trait::ConstantTimeEq {
+					continue;
+				}
+
+				// Unsafe traits can't be derived by std.
+				if trait_.is_unsafe() {
+					continue;
+				}
+
+				// `bytemuck`'s traits are unsafe as well, but checked by derive-where
+				// instead of requiring `unsafe(..)`.
+				#[cfg(feature = "bytemuck")]
+				if trait_ == Trait::Pod || trait_ == Trait::Zeroable {
+					continue;
+				}
+
 				// Any field is skipped with a corresponding `Trait`.
 				if item.any_skip_trait(***trait_) {
 					continue;
@@ -187,21 +308,54 @@ impl<'a> Input<'a> {
 					continue;
 				}
 
+				// `secret` changes the output of `Debug` and `PartialEq`.
+				if secret.is_secret() && (trait_ == Trait::Debug || trait_ == Trait::PartialEq) {
+					continue;
+				}
+
+				// `secret` changes the output of `Format`.
+				#[cfg(feature = "defmt")]
+				if secret.is_secret() && trait_ == Trait::Format {
+					continue;
+				}
+
+				// `secret` checks which fields are serialized.
+				#[cfg(feature = "serde")]
+				if secret.is_secret() && trait_ == Trait::Serialize {
+					continue;
+				}
+
 				#[cfg(feature = "zeroize")]
 				{
-					// `Zeroize(crate = ..)`, `ZeroizeOnDrop(crate = ..)` or
-					// `ZeroizeOnDrop(no_drop)` is used.
-					if let DeriveTrait::Zeroize(Zeroize { crate_: Some(_) })
+					// `Zeroize(crate = ..)`, `ZeroizeOnDrop(crate = ..)`,
+					// `ZeroizeOnDrop(no_drop)`, `ZeroizeOnDrop(on_drop = ..)` or `union` is
+					// used.
+					if let DeriveTrait::Zeroize(Zeroize {
+						crate_: Some(_), ..
+					})
+					| DeriveTrait::Zeroize(Zeroize { union_: true, .. })
 					| DeriveTrait::ZeroizeOnDrop(ZeroizeOnDrop {
 						crate_: Some(_), ..
 					})
-					| DeriveTrait::ZeroizeOnDrop(ZeroizeOnDrop { no_drop: true, .. }) = *trait_
+					| DeriveTrait::ZeroizeOnDrop(ZeroizeOnDrop { no_drop: true, .. })
+					| DeriveTrait::ZeroizeOnDrop(ZeroizeOnDrop {
+						on_drop: Some(_), ..
+					})
+					| DeriveTrait::ZeroizeOnDrop(ZeroizeOnDrop { union_: true, .. }) = *trait_
 					{
 						continue;
 					}
 
-					// `Zeroize(fqs)` is used on any field.
-					if trait_ == Trait::Zeroize && item.any_fqs() {
+					// `zeroize_to` is used on any variant.
+					if trait_ == Trait::ZeroizeOnDrop && item.zeroize_to().is_some() {
+						continue;
+					}
+
+					// `Zeroize(fqs)`, `Zeroize(order = ..)` or `Zeroize(with = ..)` is used on
+					// any field.
+					if (trait_ == Trait::Zeroize || trait_ == Trait::ZeroizeOnDrop)
+						&& item.any_zeroize_field()
+					{
 						continue;
 					}
 				}
@@ -223,6 +377,9 @@ impl<'a> Input<'a> {
 
 /// Stores output of [`Generics::split_for_impl()`].
 pub struct SplitGenerics<'a> {
+	/// Original [`Generics`], used to add the `'de` lifetime for `serde`.
+	#[cfg(feature = "serde")]
+	pub generics: &'a Generics,
 	/// Necessary generic definitions.
 	pub imp: ImplGenerics<'a>,
 	/// Generics on the type itself.
@@ -237,6 +394,8 @@ impl<'a> SplitGenerics<'a> {
 		let (imp, ty, where_clause) = generics.split_for_impl();
 
 		SplitGenerics {
+			#[cfg(feature = "serde")]
+			generics,
 			imp,
 			ty,
 			where_clause,
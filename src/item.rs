@@ -1,11 +1,21 @@
 //! Intermediate representation of item data.
 
+#[cfg(any(feature = "borsh", not(feature = "nightly")))]
+use std::borrow::Cow;
+
 use proc_macro2::Ident;
+#[cfg(any(feature = "zerocopy", not(feature = "nightly")))]
+use syn::{punctuated::Punctuated, Attribute, Meta, Result, Token};
 #[cfg(not(feature = "nightly"))]
 use {
-	proc_macro2::{Span, TokenStream},
+	proc_macro2::TokenStream,
 	quote::{quote, ToTokens},
-	syn::{punctuated::Punctuated, spanned::Spanned, Attribute, Meta, Result, Token, Variant},
+	syn::{spanned::Spanned, Variant},
+};
+#[cfg(any(feature = "borsh", not(feature = "nightly")))]
+use {
+	proc_macro2::{Literal, Span},
+	syn::{parse_quote, Expr, ExprLit, LitInt},
 };
 
 #[cfg(not(feature = "nightly"))]
@@ -57,23 +67,49 @@ impl Item<'_> {
 		}
 	}
 
-	/// Returns `true` if any field uses `Zeroize(fqs)`.
+	/// Returns the variant marked with `zeroize_to`, if any.
+	#[cfg(feature = "zeroize")]
+	pub fn zeroize_to(&self) -> Option<&Data<'_>> {
+		match self {
+			Item::Enum { variants, .. } => variants
+				.iter()
+				.find(|data| data.zeroize_to_span().is_some()),
+			Item::Item(_) => None,
+		}
+	}
+
+	/// Returns `true` if any field uses `Zeroize(fqs)`, `Zeroize(order = ..)`
+	/// or `Zeroize(with = ..)`.
 	#[cfg(feature = "zeroize")]
-	pub fn any_fqs(&self) -> bool {
+	pub fn any_zeroize_field(&self) -> bool {
 		use crate::Either;
 
 		match self {
 			Item::Item(data) => match data.fields() {
-				Either::Left(fields) => fields.fields.iter().any(|field| field.attr.zeroize_fqs.0),
+				Either::Left(fields) => fields
+					.fields
+					.iter()
+					.any(|field| field.attr.zeroize.is_some()),
 				Either::Right(_) => false,
 			},
 			Item::Enum { variants, .. } => variants.iter().any(|data| match data.fields() {
-				Either::Left(fields) => fields.fields.iter().any(|field| field.attr.zeroize_fqs.0),
+				Either::Left(fields) => fields
+					.fields
+					.iter()
+					.any(|field| field.attr.zeroize.is_some()),
 				Either::Right(_) => false,
 			}),
 		}
 	}
 
+	/// Returns `true` if this [`Item`] is marked as [`Secret`](crate::Secret).
+	pub fn is_secret(&self) -> bool {
+		match self {
+			Item::Item(data) => data.secret.is_secret(),
+			Item::Enum { variants, .. } => variants.iter().any(|data| data.secret.is_secret()),
+		}
+	}
+
 	/// Returns `true` if all [`Fields`](crate::data::Fields) are empty for this
 	/// [`Trait`].
 	pub fn is_empty(&self, trait_: Trait) -> bool {
@@ -100,6 +136,75 @@ impl Item<'_> {
 	}
 }
 
+/// Returns the discriminant of each variant. Variants without an explicit
+/// discriminant count up from the previous one, like Rust does.
+#[cfg(any(feature = "borsh", not(feature = "nightly")))]
+pub fn discriminants<'a>(variants: &[Data<'a>]) -> Vec<Cow<'a, Expr>> {
+	let mut discriminants = Vec::<Cow<Expr>>::with_capacity(variants.len());
+	let mut last_expression: Option<(Option<usize>, usize)> = None;
+
+	for variant in variants {
+		let discriminant = if let Some(discriminant) = variant.discriminant {
+			last_expression = Some((Some(discriminants.len()), 0));
+			Cow::Borrowed(discriminant)
+		} else {
+			let discriminant = match &mut last_expression {
+				Some((Some(expr_index), counter)) => {
+					let expr = &discriminants[*expr_index];
+					*counter += 1;
+					let counter = Literal::usize_unsuffixed(*counter);
+					parse_quote! { (#expr) + #counter }
+				}
+				Some((None, counter)) => {
+					*counter += 1;
+
+					ExprLit {
+						attrs: Vec::new(),
+						lit: LitInt::new(&counter.to_string(), Span::call_site()).into(),
+					}
+					.into()
+				}
+				None => {
+					last_expression = Some((None, 0));
+					ExprLit {
+						attrs: Vec::new(),
+						lit: LitInt::new("0", Span::call_site()).into(),
+					}
+					.into()
+				}
+			};
+
+			Cow::Owned(discriminant)
+		};
+
+		discriminants.push(discriminant);
+	}
+
+	discriminants
+}
+
+/// Returns the representations found in `repr` attributes.
+#[cfg(any(feature = "zerocopy", not(feature = "nightly")))]
+pub fn repr(attrs: &[Attribute]) -> Result<Vec<Ident>> {
+	let mut reprs = Vec::new();
+
+	for attr in attrs {
+		if attr.path().is_ident("repr") {
+			if let Meta::List(list) = &attr.meta {
+				let list = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+				reprs.extend(
+					list.iter()
+						.filter_map(|meta| meta.path().get_ident().cloned()),
+				);
+			} else {
+				unreachable!("found invalid `repr` attribute")
+			}
+		}
+	}
+
+	Ok(reprs)
+}
+
 /// Type of discriminant used.
 #[derive(Clone, Copy)]
 #[cfg_attr(test, derive(Debug))]
@@ -127,23 +232,12 @@ impl Discriminant {
 
 		let mut has_repr = None;
 
-		for attr in attrs {
-			if attr.path().is_ident("repr") {
-				if let Meta::List(list) = &attr.meta {
-					let list =
-						list.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
-
-					for ident in list {
-						if let Some(repr) = Representation::parse(&ident) {
-							has_repr = Some(repr);
-							break;
-						} else if ident != "C" && ident != "Rust" && ident != "align" {
-							return Err(Error::repr_unknown(ident.span()));
-						}
-					}
-				} else {
-					unreachable!("found invalid `repr` attribute")
-				}
+		for ident in repr(attrs)? {
+			if let Some(repr) = Representation::parse(&ident) {
+				has_repr = Some(repr);
+				break;
+			} else if ident != "C" && ident != "Rust" && ident != "align" {
+				return Err(Error::repr_unknown(ident.span()));
 			}
 		}
 
@@ -174,7 +268,7 @@ impl Discriminant {
 /// The type used to represent an enum.
 #[derive(Clone, Copy)]
 #[cfg_attr(test, derive(Debug))]
-#[cfg(not(feature = "nightly"))]
+#[cfg(any(feature = "zerocopy", not(feature = "nightly")))]
 pub enum Representation {
 	/// [`u8`].
 	U8,
@@ -202,10 +296,10 @@ pub enum Representation {
 	ISize,
 }
 
-#[cfg(not(feature = "nightly"))]
+#[cfg(any(feature = "zerocopy", not(feature = "nightly")))]
 impl Representation {
 	/// Parse an [`struct@Ident`] to a valid representation if it is.
-	fn parse(ident: &Ident) -> Option<Self> {
+	pub fn parse(ident: &Ident) -> Option<Self> {
 		Some(if ident == "u8" {
 			Self::U8
 		} else if ident == "u16" {
@@ -236,6 +330,7 @@ impl Representation {
 	}
 
 	/// Convert this [`Representation`] to a [`TokenStream`].
+	#[cfg(not(feature = "nightly"))]
 	pub fn to_token(self) -> TokenStream {
 		let ident = match self {
 			Representation::U8 => "u8",
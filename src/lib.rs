@@ -112,6 +112,16 @@
 //! struct Example<T, U>(PhantomData<T>, PhantomData<U>);
 //! ```
 //!
+//! Lifetime predicates and higher-ranked trait bounds are supported as well:
+//!
+//! ```
+//! # use std::fmt::Debug;
+//! # use derive_where::derive_where;
+//! #[derive_where(Clone; 'a: 'b)]
+//! #[derive_where(Debug; for<'c> &'c T: Debug)]
+//! struct Example<'a, 'b, T>(&'a T, &'b T);
+//! ```
+//!
 //! ## Enum default
 //!
 //! Since Rust 1.62 deriving [`Default`] on an enum is possible with the
@@ -170,11 +180,25 @@
 //! `skip` and `skip_inner`. To prevent breaking invariants defined for these
 //! traits, some of them can only be skipped in groups. The following groups are
 //! available:
+//! - [`Arbitrary`]: Skipped fields are filled with [`Default`]. Not skipped by
+//!   an unconstrained `skip`, it has to be skipped explicitly.
+//! - [`Archive`]: Skips [`Archive`], `RkyvDeserialize` and `RkyvSerialize`.
+//!   Skipped fields are filled with [`Default`]. Not skipped by an
+//!   unconstrained `skip`, they have to be skipped explicitly.
 //! - [`Clone`]: Uses [`Default`] instead of [`Clone`].
+//! - [`ConstantTimeEq`]
 //! - [`Debug`]
+//! - [`Deserialize`]: Skipped fields are filled with [`Default`].
 //! - `EqHashOrd`: Skips [`Eq`], [`Hash`], [`Ord`], [`PartialOrd`] and
 //!   [`PartialEq`].
+//! - [`Format`]
 //! - [`Hash`]
+//! - [`JsonSchema`]: Not skipped by an unconstrained `skip`, like `Serde`.
+//! - `ProptestArbitrary`: Skipped fields are filled with [`Default`]. Not
+//!   skipped by an unconstrained `skip`, it has to be skipped explicitly.
+//! - `Serde`: Skips [`Deserialize`] and [`Serialize`]. Not skipped by an
+//!   unconstrained `skip`, they have to be skipped explicitly.
+//! - [`Serialize`]
 //! - `Zeroize`: Skips [`Zeroize`] and [`ZeroizeOnDrop`].
 //!
 //! ```
@@ -228,21 +252,245 @@
 //! Note that it is not possible to use `incomparable` with [`Eq`] or [`Ord`] as
 //! that would break their invariants.
 //!
+//! ## Secret items
+//!
+//! The item-level `secret` option applies safe defaults to items holding
+//! secrets, e.g. items implementing [`ZeroizeOnDrop`]:
+//! - [`Debug`] and [`Format`] print every field as `[REDACTED]`.
+//! - [`PartialEq`] compares every field with [`ConstantTimeEq`] without
+//!   short-circuiting. This requires the `subtle` crate feature and can't be
+//!   combined with `PartialEq(const)`. The path to `subtle` is taken from the
+//!   `crate` option of [`ConstantTimeEq`] in the same `derive_where` attribute.
+//! - [`Serialize`] requires every field to be either skipped or explicitly
+//!   exposed with the field-level `Serialize(expose)` option.
+//!
+//! ```
+//! # use derive_where::derive_where;
+//! #[derive_where(Debug)]
+//! #[derive_where(secret)]
+//! struct Key {
+//! 	id: u32,
+//! 	key: u64,
+//! }
+//!
+//! assert_eq!(
+//! 	format!("{:?}", Key { id: 1, key: 42 }),
+//! 	"Key { id: [REDACTED], key: [REDACTED] }"
+//! );
+//! ```
+//!
+//! ## Borsh `BorshDeserialize` and `BorshSerialize`
+//!
+//! Deriving [`BorshDeserialize`] and [`BorshSerialize`] reads and writes fields
+//! in declaration order, like `borsh`'s own derive macros. Enum variants are
+//! prefixed with a `u8` tag, which is the discriminant of the variant, so
+//! explicit discriminants are respected, like with `#[borsh(use_discriminant =
+//! true)]`. Without explicit discriminants, the tag is the index of the
+//! variant. Discriminants have to fit into a `u8`.
+//!
+//! ```
+//! # #[cfg(feature = "borsh")]
+//! # extern crate borsh_ as borsh;
+//! # #[cfg(feature = "borsh")]
+//! # {
+//! # use std::marker::PhantomData;
+//! # use derive_where::derive_where;
+//! #[derive_where(BorshDeserialize, BorshSerialize; T)]
+//! #[repr(u8)]
+//! enum Example<T, U> {
+//! 	A(T, PhantomData<U>) = 1,
+//! 	B = 4,
+//! }
+//! # }
+//! ```
+//!
+//! ## `JsonSchema`
+//!
+//! Deriving [`JsonSchema`] forwards the item to `schemars`' own derive macro,
+//! replacing its bounds with the ones generated by derive-where. Predicates
+//! from `#[schemars(bound = "...")]` are combined with them. Regular `schemars`
+//! and `serde` attributes, like `rename`, can be used. Fields skipped for
+//! [`JsonSchema`], or for both [`Deserialize`] and [`Serialize`], are skipped
+//! in the schema, fields skipped for only one of them are marked accordingly,
+//! like `serde` would. Requires `schemars` v1.
+//!
+//! ```
+//! # #[cfg(feature = "schemars")]
+//! # extern crate schemars_ as schemars;
+//! # #[cfg(feature = "schemars")]
+//! # {
+//! # use std::marker::PhantomData;
+//! # use derive_where::derive_where;
+//! #[derive_where(JsonSchema; T)]
+//! struct Example<T, U> {
+//! 	value: T,
+//! 	#[derive_where(skip(JsonSchema))]
+//! 	marker: PhantomData<U>,
+//! }
+//! # }
+//! ```
+//!
+//! ## `bytemuck` traits
+//!
+//! [`Zeroable`] and [`Pod`] are implemented directly by derive-where, together
+//! with hidden assertions that every field implements them as well, like [`Eq`]
+//! does. This allows custom bounds, e.g. `#[derive_where(Zeroable, Pod; T:
+//! Pod)]`, while generics only used in [`PhantomData`] stay unbounded. Enums
+//! and unions aren't supported. [`Pod`] requires `#[repr(C)]`,
+//! `#[repr(transparent)]` or `#[repr(packed)]`. Because padding can only be
+//! checked for items without generics, generic items have to be
+//! `#[repr(transparent)]` or `#[repr(packed)]`, without `packed(N)` above `1`
+//! or `align(..)`. [`Copy`] has to be implemented separately.
+//!
+//! ```
+//! # #[cfg(feature = "bytemuck")]
+//! # extern crate bytemuck_ as bytemuck;
+//! # #[cfg(feature = "bytemuck")]
+//! # {
+//! # use derive_where::derive_where;
+//! #[derive_where(Clone, Copy, Zeroable, Pod; T: bytemuck::Pod)]
+//! #[repr(transparent)]
+//! struct Example<T>(T);
+//!
+//! let example: Example<u32> = bytemuck::cast(42_u32);
+//! assert_eq!(example.0, 42);
+//! # }
+//! ```
+//!
+//! ## `zerocopy` traits
+//!
+//! Deriving [`FromBytes`], [`FromZeros`], [`Immutable`], [`IntoBytes`] and
+//! [`KnownLayout`] forwards the item to `zerocopy`'s own derive macros, adding
+//! the bounds generated by derive-where to its `where` clause. `zerocopy`
+//! itself only bounds field types, so generics only used in [`PhantomData`],
+//! like markers, stay unbounded. [`IntoBytes`] requires `#[repr(C)]`,
+//! `#[repr(transparent)]` or `#[repr(packed)]` on structs. Enums require
+//! `#[repr(C)]` or a primitive representation for [`FromBytes`], [`FromZeros`]
+//! and [`IntoBytes`]. Padding is rejected by `zerocopy`. [`FromBytes`] already
+//! implements [`FromZeros`], so they can't be derived together. Requires
+//! `zerocopy` v0.8.
+//!
+//! ```
+//! # #[cfg(feature = "zerocopy")]
+//! # extern crate zerocopy_ as zerocopy;
+//! # #[cfg(feature = "zerocopy")]
+//! # {
+//! # use std::marker::PhantomData;
+//! # use derive_where::derive_where;
+//! # use zerocopy::{FromBytes, IntoBytes};
+//! struct BigEndian;
+//!
+//! #[derive_where(FromBytes, IntoBytes, KnownLayout, Immutable)]
+//! #[repr(C)]
+//! struct Packet<E> {
+//! 	kind: u8,
+//! 	data: [u8; 3],
+//! 	endian: PhantomData<E>,
+//! }
+//!
+//! let packet = Packet::<BigEndian>::read_from_bytes(&[1, 2, 3, 4]).unwrap();
+//! assert_eq!(packet.as_bytes(), [1, 2, 3, 4]);
+//! # }
+//! ```
+//!
+//! ## rkyv `Archive`, `RkyvDeserialize` and `RkyvSerialize`
+//!
+//! Deriving [`Archive`], `RkyvDeserialize` and `RkyvSerialize` forwards the
+//! item to `rkyv`'s own derive macros, adding the bounds generated by
+//! derive-where to its `where` clause, which the archived item shares. `rkyv`
+//! itself only bounds field types, so generics only used in [`PhantomData`],
+//! like markers, stay unbounded. Generics are bound to [`Archive`] for
+//! `RkyvDeserialize`, as it is implemented for the archived item. Custom bounds
+//! can refer to `rkyv`'s `__S` serializer and `__D` deserializer, e.g.
+//! `RkyvSerialize; T: rkyv::Serialize<__S>`. Fields skipped with the `Archive`
+//! skip group aren't archived and are filled with [`Default`] on
+//! deserialization. The path to the `rkyv` crate can be specified with the
+//! `crate` option, e.g. `Archive(crate = rkyv_)`, or with
+//! `#[rkyv(crate = ..)]`, but not both. Requires `rkyv` v0.8.
+//!
+//! ```
+//! # #[cfg(feature = "rkyv")]
+//! # extern crate rkyv_ as rkyv;
+//! # #[cfg(feature = "rkyv")]
+//! # {
+//! # use std::marker::PhantomData;
+//! # use derive_where::derive_where;
+//! # use rkyv::rancor::Error;
+//! struct Marker;
+//!
+//! #[derive_where(Archive, RkyvDeserialize, RkyvSerialize; K)]
+//! struct Index<K, M> {
+//! 	keys: Vec<K>,
+//! 	#[derive_where(skip(Archive))]
+//! 	cache: Option<usize>,
+//! 	marker: PhantomData<M>,
+//! }
+//!
+//! let index = Index::<u32, Marker> {
+//! 	keys: vec![1, 2, 3],
+//! 	cache: Some(1),
+//! 	marker: PhantomData,
+//! };
+//! let bytes = rkyv::to_bytes::<Error>(&index).unwrap();
+//! let archived = rkyv::access::<ArchivedIndex<u32, Marker>, Error>(&bytes).unwrap();
+//! assert_eq!(archived.keys.as_slice(), [1, 2, 3]);
+//!
+//! let index = rkyv::from_bytes::<Index<u32, Marker>, Error>(&bytes).unwrap();
+//! assert_eq!(index.cache, None);
+//! # }
+//! ```
+//!
 //! ## Serde `Deserialize` and `Serialize`
 //!
-//! Deriving [`Deserialize`] and [`Serialize`] works as expected. While
-//! derive-where does not offer any attribute options, regular `serde`
-//! attributes can be used. Derive-where will respect
-//! [`#[serde(crate = "...")]`](https://serde.rs/container-attrs.html#crate).
+//! Deriving [`Deserialize`] and [`Serialize`] works as expected. Regular
+//! `serde` attributes can be used. The path to the `serde` crate can be
+//! specified with the `crate` option, e.g. `Serialize(crate = serde_)`, or with
+//! [`#[serde(crate = "...")]`](https://serde.rs/container-attrs.html#crate),
+//! but not both.
+//! Predicates from [`#[serde(bound = "...")]`](https://serde.rs/container-attrs.html#bound)
+//! are combined with the bounds generated by derive-where.
+//! Custom bounds for [`Deserialize`] can refer to its `'de` lifetime, e.g.
+//! `#[derive_where(Deserialize; T: Deserialize<'de>)]`.
+//!
+//! Implementations are generated directly by derive-where if only the
+//! following `serde` attributes are used:
+//! - Container: `bound`, `crate`, `rename`, `rename_all`, `tag`, `untagged`,
+//!   `default` and `deny_unknown_fields`.
+//! - Variant: `rename`, `rename_all`, `skip`, `skip_serializing` and
+//!   `skip_deserializing`.
+//! - Field: `rename`, `skip`, `skip_serializing`, `skip_deserializing` and
+//!   `default`.
+//!
+//! Any other `serde` attribute forwards the item to `serde`'s own derive macros
+//! instead. Items are forwarded as well if `serde` handles them specially or
+//! reports an error for them: newtype structs with a skipped field, `tag` or
+//! `untagged` on structs, `default` on anything but structs with named fields
+//! and serializing newtype or tuple variants of internally tagged enums.
+//!
+//! [`Deserialize`] for internally tagged or untagged enums is always forwarded
+//! to `serde`'s derive, as buffering the input for them requires `serde`'s
+//! private API. Only [`Serialize`] is generated directly for them.
 //!
 //! ## `Zeroize` options
 //!
-//! `Zeroize` has two options:
+//! `Zeroize` has five options:
 //! - `crate`: an item-level option which specifies a path to the [`zeroize`]
 //!   crate in case of a re-export or rename.
 //! - `fqs`: a field-level option which will use fully-qualified-syntax instead
 //!   of calling the [`zeroize`][method@zeroize] method on `self` directly. This
 //!   is to avoid ambiguity between another method also called `zeroize`.
+//! - `order`: a field-level option which specifies the position of this field
+//!   when zeroizing, lower values are zeroized first and fields without it have
+//!   the position `0`. Fields with the same position are zeroized in
+//!   declaration order. This is useful if one field references another.
+//! - `union`: an item-level option which is required to implement [`Zeroize`]
+//!   on unions. It overwrites the whole union with zeroes using volatile writes
+//!   followed by a compiler fence, like [`zeroize`] does. All fields have to be
+//!   [`Copy`], as anything else could own data outside of the union.
+//! - `with`: a field-level option which specifies a function that is called
+//!   with `&mut` to the field instead of the [`zeroize`][method@zeroize]
+//!   method. This allows zeroizing fields of types that don't implement
+//!   [`Zeroize`]. Can't be combined with `fqs`.
 //!
 //! ```
 //! # #[cfg(feature = "zeroize")]
@@ -279,12 +527,30 @@
 //! and can be implemented without [`Zeroize`], otherwise it only implements
 //! [`Drop`] and requires [`Zeroize`] to be implemented.
 //!
-//! [`ZeroizeOnDrop`] has two options:
+//! [`ZeroizeOnDrop`] has four options:
 //! - `crate`: an item-level option which specifies a path to the [`zeroize`]
 //!   crate in case of a re-export or rename.
 //! - `no_drop`: an item-level option which will not implement [`Drop`] but
 //!   instead only assert that every field implements [`ZeroizeOnDrop`].
 //!   Requires the `zeroize-on-drop` feature.
+//! - `on_drop`: an item-level option which specifies a function that is called
+//!   with `&mut self` on [`Drop`] before any field is zeroized, e.g. to release
+//!   resources held by the item. Can't be combined with `no_drop`.
+//! - `union`: an item-level option which is required to implement
+//!   [`ZeroizeOnDrop`] on unions. The whole union is zeroized on [`Drop`] like
+//!   with `Zeroize(union)`. Can't be combined with `no_drop`.
+//!
+//! Fields using the `Zeroize(with = ..)` or `Zeroize(order = ..)` options are
+//! zeroized with that function or in that order on [`Drop`] as well, which
+//! can't be combined with `no_drop` unless [`Zeroize`] is derived too. Without
+//! the `zeroize-on-drop` feature, these options require deriving [`Zeroize`].
+//! `Zeroize(fqs)` requires deriving [`Zeroize`].
+//!
+//! Enums can mark a unit variant with the variant-level `zeroize_to` option.
+//! After zeroizing the fields on [`Drop`], the enum is overwritten with this
+//! variant, so the discriminant of the dropped variant doesn't linger in
+//! memory. The fields are still dropped normally. Can't be combined with
+//! `no_drop`.
 //!
 //! ```
 //! # #[cfg(feature = "zeroize-on-drop")]
@@ -298,20 +564,215 @@
 //! # }
 //! ```
 //!
+//! ## `ConditionallySelectable` options
+//!
+//! [`ConditionallySelectable`] selects every field with its own
+//! `conditional_select`. Because [`ConditionallySelectable`] requires [`Copy`],
+//! the item has to implement it as well. Enums aren't supported, as selecting
+//! between different variants can't be done in constant time.
+//!
+//! [`ConditionallyNegatable`] isn't derived: [`subtle`] already implements it
+//! for any [`ConditionallySelectable`] type which implements [`Neg`] on a
+//! reference.
+//!
+//! [`ConditionallySelectable`] has one option:
+//! - `crate`: an item-level option which specifies a path to the [`subtle`]
+//!   crate in case of a re-export or rename.
+//!
+//! ```
+//! # #[cfg(feature = "subtle")]
+//! # {
+//! # use derive_where::derive_where;
+//! # use subtle_::{Choice, ConditionallySelectable};
+//! #[derive_where(ConditionallySelectable(crate = subtle_))]
+//! #[derive(Clone, Copy, Debug, PartialEq)]
+//! struct Example {
+//! 	a: u32,
+//! 	b: u8,
+//! }
+//!
+//! let a = Example { a: 1, b: 2 };
+//! let b = Example { a: 3, b: 4 };
+//!
+//! assert_eq!(Example::conditional_select(&a, &b, Choice::from(1)), b);
+//! # }
+//! ```
+//!
+//! ## `ConstantTimeEq` options
+//!
+//! [`ConstantTimeEq`] combines the [`Choice`] of all fields with `&`, which
+//! doesn't short-circuit, so all fields are always compared. Different enum
+//! variants are never equal. Fields can be excluded with the `ConstantTimeEq`
+//! skip group, e.g. for fields like [`PhantomData`] that don't implement it.
+//!
+//! [`ConstantTimeEq`] has one option:
+//! - `crate`: an item-level option which specifies a path to the [`subtle`]
+//!   crate in case of a re-export or rename.
+//!
+//! ```
+//! # #[cfg(feature = "subtle")]
+//! # {
+//! # use std::marker::PhantomData;
+//! # use derive_where::derive_where;
+//! # use subtle_::ConstantTimeEq;
+//! #[derive_where(ConstantTimeEq(crate = subtle_))]
+//! struct Example<T> {
+//! 	key: u64,
+//! 	#[derive_where(skip(ConstantTimeEq))]
+//! 	marker: PhantomData<T>,
+//! }
+//!
+//! let key = Example::<()> {
+//! 	key: 42,
+//! 	marker: PhantomData,
+//! };
+//!
+//! assert!(bool::from(key.ct_eq(&key)));
+//! # }
+//! ```
+//!
+//! ## `ProptestArbitrary` options
+//!
+//! `ProptestArbitrary` implements `proptest`'s
+//! [`Arbitrary`](https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html)
+//! by combining the strategies of all fields, generated with [`any`], and
+//! choosing between variants like [`prop_oneof!`]. It has two options:
+//! - `strategy`: a field-level option which specifies a [`Strategy`] to
+//!   generate this field with instead of [`any`], e.g.
+//!   `ProptestArbitrary(strategy = 0..10_u8)`. The strategy is boxed, so it has
+//!   to be `'static`.
+//! - `weight`: a variant-level option which specifies the relative weight of
+//!   this variant, defaults to `1`. The sum of all weights has to fit in a
+//!   `u32`.
+//!
+//! ```
+//! # #[cfg(feature = "proptest")]
+//! # extern crate proptest_ as proptest;
+//! # #[cfg(feature = "proptest")]
+//! # {
+//! # use std::marker::PhantomData;
+//! # use derive_where::derive_where;
+//! #[derive_where(Debug; T)]
+//! #[derive_where(ProptestArbitrary; T)]
+//! enum Example<T, U> {
+//! 	A(
+//! 		T,
+//! 		#[derive_where(ProptestArbitrary(strategy = 0..10_u8))] u8,
+//! 	),
+//! 	#[derive_where(ProptestArbitrary(weight = 3))]
+//! 	B(PhantomData<U>),
+//! }
+//! # }
+//! ```
+//!
+//! ## Unsafe `Send` and `Sync`
+//!
+//! [`Send`] and [`Sync`] are `unsafe` traits and therefore have to be wrapped
+//! in `unsafe(..)` to acknowledge that the implementation upholds their safety
+//! requirements. This is useful for types that e.g. contain raw pointers but
+//! are known to be thread-safe under custom bounds:
+//!
+//! ```
+//! # #[cfg(not(feature = "safe"))]
+//! # {
+//! # use std::ptr::NonNull;
+//! # use derive_where::derive_where;
+//! #[derive_where(unsafe(Send, Sync); T: Send + Sync)]
+//! struct Example<T>(NonNull<T>);
+//! # }
+//! ```
+//!
+//! Unsafe traits aren't available with the `safe` crate feature.
+//!
+//! ## Assertions
+//!
+//! `assert(..)` adds a compile-time check that the item satisfies the given
+//! bounds, e.g. auto traits or lifetimes. It doesn't implement anything and can
+//! be used with the same generic type bounds as traits:
+//!
+//! ```
+//! # use std::marker::PhantomData;
+//! # use derive_where::derive_where;
+//! #[derive_where(assert(Send, Sync, 'static); T: Send + Sync + 'static)]
+//! struct Example<T>(PhantomData<T>);
+//! ```
+//!
+//! ## `const` implementations
+//!
+//! With the `nightly` crate feature, [`Clone`], [`Default`] and [`PartialEq`]
+//! support the `const` option, e.g. `#[derive_where(PartialEq(const))]`, which
+//! emits a `const` implementation with `[const]` bounds. This requires the
+//! `const_trait_impl` feature, and `const_clone`, `const_default` or
+//! `const_cmp` respectively to use the implementations in `const` contexts.
+//!
+//! ## `ConstDefault`
+//!
+//! `ConstDefault` isn't a trait, it emits an inherent `pub const DEFAULT: Self`
+//! instead, which is usable in `const` contexts. Every field uses the `DEFAULT`
+//! constant of its type, unless a value is specified with the field-level
+//! `ConstDefault(value = expr)` option. Enums use the variant marked with
+//! `#[derive_where(default)]`, same as [`Default`]. As there is no trait to
+//! bind generics to, only custom bounds like `ConstDefault; T: Trait` are
+//! supported.
+//!
+//! ```
+//! # use std::marker::PhantomData;
+//! # use derive_where::derive_where;
+//! struct Nested(u8);
+//!
+//! impl Nested {
+//! 	const DEFAULT: Self = Nested(42);
+//! }
+//!
+//! #[derive_where(ConstDefault)]
+//! struct Example<T> {
+//! 	nested: Nested,
+//! 	#[derive_where(ConstDefault(value = PhantomData))]
+//! 	marker: PhantomData<T>,
+//! }
+//!
+//! const EXAMPLE: Example<()> = Example::DEFAULT;
+//! assert_eq!(EXAMPLE.nested.0, 42);
+//! ```
+//!
 //! ## Supported traits
 //!
 //! The following traits can be derived with derive-where:
+//! - [`Arbitrary`]: Only available with the `arbitrary` crate feature.
+//! - [`Archive`]: Only available with the `rkyv` crate feature.
+//! - [`BorshDeserialize`]: Only available with the `borsh` crate feature.
+//! - [`BorshSerialize`]: Only available with the `borsh` crate feature.
 //! - [`Clone`]
+//! - [`ConditionallySelectable`]: Only available with the `subtle` crate
+//!   feature.
+//! - `ConstDefault`: Emits an inherent `DEFAULT` constant instead of a trait
+//!   implementation.
+//! - [`ConstantTimeEq`]: Only available with the `subtle` crate feature.
 //! - [`Copy`]
 //! - [`Debug`]
 //! - [`Default`]
 //! - [`Deserialize`]: Only available with the `serde` crate feature.
 //! - [`Eq`]
+//! - [`Format`]: Only available with the `defmt` crate feature. The output
+//!   mirrors [`Debug`].
+//! - [`FromBytes`]: Only available with the `zerocopy` crate feature.
+//! - [`FromZeros`]: Only available with the `zerocopy` crate feature.
 //! - [`Hash`]
+//! - [`Immutable`]: Only available with the `zerocopy` crate feature.
+//! - [`IntoBytes`]: Only available with the `zerocopy` crate feature.
+//! - [`JsonSchema`]: Only available with the `schemars` crate feature.
+//! - [`KnownLayout`]: Only available with the `zerocopy` crate feature.
 //! - [`Ord`]
 //! - [`PartialEq`]
 //! - [`PartialOrd`]
+//! - [`Pod`]: Only available with the `bytemuck` crate feature.
+//! - `ProptestArbitrary`: Only available with the `proptest` crate feature.
+//! - `RkyvDeserialize`: Only available with the `rkyv` crate feature.
+//! - `RkyvSerialize`: Only available with the `rkyv` crate feature.
+//! - [`Send`]: Has to be specified with `unsafe(..)`.
 //! - [`Serialize`]: Only available with the `serde` crate feature.
+//! - [`Sync`]: Has to be specified with `unsafe(..)`.
+//! - [`Zeroable`]: Only available with the `bytemuck` crate feature.
 //! - [`Zeroize`]: Only available with the `zeroize` crate feature.
 //! - [`ZeroizeOnDrop`]: Only available with the `zeroize` crate feature. If the
 //!   `zeroize-on-drop` feature is enabled, it implements [`ZeroizeOnDrop`],
@@ -324,7 +785,8 @@
 //! example unit structs and enums only containing unit variants aren't
 //! supported.
 //!
-//! Unions only support [`Clone`] and [`Copy`].
+//! Unions only support [`Clone`], [`Copy`] and, with the `union` option,
+//! [`Zeroize`] and [`ZeroizeOnDrop`].
 //!
 //! [`PartialOrd`] and [`Ord`] need to determine the discriminant type to
 //! function correctly. To protect against a potential future change to the
@@ -337,13 +799,30 @@
 //!
 //! # Crate features
 //!
+//! - `arbitrary`: Allows deriving [`Arbitrary`].
+//! - `borsh`: Allows deriving [`BorshDeserialize`] and [`BorshSerialize`].
+//! - `bytemuck`: Allows deriving [`Pod`] and [`Zeroable`].
+//! - `defmt`: Allows deriving [`Format`].
 //! - `nightly`: Implements [`Ord`] and [`PartialOrd`] with the help of
 //!   [`core::intrinsics::discriminant_value`], which is what Rust does by
-//!   default too. This requires a nightly version of the Rust compiler.
+//!   default too. This requires a nightly version of the Rust compiler. Also
+//!   implements `StructuralPartialEq` if [`Eq`] and [`PartialEq`] are derived
+//!   with the same bounds, no field is skipped and the item isn't `secret`,
+//!   allowing constants to be used in patterns.
+//! - `proptest`: Allows deriving `ProptestArbitrary`.
+//! - `rkyv`: Allows deriving [`Archive`], `RkyvDeserialize` and
+//!   `RkyvSerialize`.
 //! - `safe`: `safe`: Uses only safe ways to access the discriminant of the enum
 //!   for [`Ord`] and [`PartialOrd`]. It also replaces all cases of
 //!   [`core::hint::unreachable_unchecked`] in [`Ord`], [`PartialEq`] and
-//!   [`PartialOrd`], which is what std uses, with [`unreachable`].
+//!   [`PartialOrd`], which is what std uses, with [`unreachable`]. Disallows
+//!   deriving `unsafe` traits like [`Send`] and [`Sync`] and the `zeroize_to`
+//!   option, which overwrites the enum with `unsafe` code.
+//! - `schemars`: Allows deriving [`JsonSchema`].
+//! - `subtle`: Allows deriving [`ConditionallySelectable`] and
+//!   [`ConstantTimeEq`].
+//! - `zerocopy`: Allows deriving [`FromBytes`], [`FromZeros`], [`Immutable`],
+//!   [`IntoBytes`] and [`KnownLayout`].
 //! - `zeroize`: Allows deriving [`Zeroize`] and [`zeroize`][method@zeroize] on
 //!   [`Drop`].
 //! - `zeroize-on-drop`: Allows deriving [`Zeroize`] and [`ZeroizeOnDrop`] and
@@ -386,15 +865,38 @@
 //! [CHANGELOG]: https://github.com/ModProg/derive-where/blob/main/CHANGELOG.md
 //! [LICENSE-MIT]: https://github.com/ModProg/derive-where/blob/main/LICENSE-MIT
 //! [LICENSE-APACHE]: https://github.com/ModProg/derive-where/blob/main/LICENSE-APACHE
+//! [`any`]: https://docs.rs/proptest/latest/proptest/arbitrary/fn.any.html
+//! [`prop_oneof!`]: https://docs.rs/proptest/latest/proptest/macro.prop_oneof.html
+//! [`Strategy`]: https://docs.rs/proptest/latest/proptest/strategy/trait.Strategy.html
+//! [`Arbitrary`]: https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html
+//! [`Archive`]: https://docs.rs/rkyv/latest/rkyv/trait.Archive.html
+//! [`BorshDeserialize`]: https://docs.rs/borsh/latest/borsh/de/trait.BorshDeserialize.html
+//! [`BorshSerialize`]: https://docs.rs/borsh/latest/borsh/ser/trait.BorshSerialize.html
+//! [`Choice`]: https://docs.rs/subtle/latest/subtle/struct.Choice.html
+//! [`ConditionallyNegatable`]: https://docs.rs/subtle/latest/subtle/trait.ConditionallyNegatable.html
+//! [`ConditionallySelectable`]: https://docs.rs/subtle/latest/subtle/trait.ConditionallySelectable.html
+//! [`ConstantTimeEq`]: https://docs.rs/subtle/latest/subtle/trait.ConstantTimeEq.html
 //! [`Debug`]: core::fmt::Debug
 //! [`Default`]: core::default::Default
 //! [`Deserialize`]: https://docs.rs/serde/latest/serde/derive.Deserialize.html
 //! [`Eq`]: core::cmp::Eq
+//! [`Format`]: https://docs.rs/defmt/latest/defmt/trait.Format.html
+//! [`FromBytes`]: https://docs.rs/zerocopy/latest/zerocopy/trait.FromBytes.html
+//! [`FromZeros`]: https://docs.rs/zerocopy/latest/zerocopy/trait.FromZeros.html
 //! [`Hash`]: core::hash::Hash
+//! [`Immutable`]: https://docs.rs/zerocopy/latest/zerocopy/trait.Immutable.html
+//! [`IntoBytes`]: https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html
+//! [`JsonSchema`]: https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html
+//! [`KnownLayout`]: https://docs.rs/zerocopy/latest/zerocopy/trait.KnownLayout.html
+//! [`Neg`]: core::ops::Neg
 //! [`Ord`]: core::cmp::Ord
 //! [`PartialEq`]: core::cmp::PartialEq
+//! [`PhantomData`]: core::marker::PhantomData
+//! [`Pod`]: https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html
 //! [`PartialOrd`]: core::cmp::PartialOrd
 //! [`Serialize`]: https://docs.rs/serde/latest/serde/derive.Serialize.html
+//! [`subtle`]: https://docs.rs/subtle
+//! [`Zeroable`]: https://docs.rs/bytemuck/latest/bytemuck/trait.Zeroable.html
 //! [`zeroize`]: https://docs.rs/zeroize
 //! [`Zeroize`]: https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html
 //! [`ZeroizeOnDrop`]: https://docs.rs/zeroize/latest/zeroize/trait.ZeroizeOnDrop.html
@@ -421,12 +923,19 @@ use syn::{
 };
 use util::MetaListExt;
 
+#[cfg(feature = "serde")]
+use self::attr::Expose;
+#[cfg(feature = "proptest")]
+use self::attr::{ProptestStrategy, ProptestWeight};
 #[cfg(feature = "zeroize")]
-use self::attr::ZeroizeFqs;
+use self::attr::{ZeroizeField, ZeroizeTo};
 #[cfg(not(feature = "nightly"))]
 use self::item::Discriminant;
 use self::{
-	attr::{Default, DeriveWhere, FieldAttr, Incomparable, ItemAttr, Skip, SkipGroup, VariantAttr},
+	attr::{
+		ConstDefaultValue, Default, DeriveWhere, FieldAttr, Incomparable, ItemAttr, Secret, Skip,
+		SkipGroup, VariantAttr,
+	},
 	data::{Data, DataType, Field, SimpleType},
 	error::Error,
 	input::Input,
@@ -446,26 +955,54 @@ const DERIVE_WHERE_VISITED: &str = "derive_where_visited";
 /// - `#[derive_where(crate = path)]`: Specify path to the `derive_where` crate.
 /// - `#[derive_where(Clone, ..; T, ..)]`: Specify traits to implement and
 ///   optionally bounds.
+///   - `#[derive_where(unsafe(Send, ..))]`: Specify `unsafe` traits to
+///     implement.
+///   - `#[derive_where(assert(Send, 'static, ..))]`: Assert that the item
+///     satisfies bounds at compile-time.
+///   - `#[derive_where(Clone(const), Default(const), PartialEq(const))]`:
+///     `const` implementation, requires the `nightly` crate feature.
 ///   - `#[derive_where(Zeroize(crate = path))]`: Specify path to [`Zeroize`]
 ///     trait.
 ///   - `#[derive_where(ZeroizeOnDrop(crate = path))]`: Specify path to
 ///     [`ZeroizeOnDrop`] trait.
 ///   - `#[derive_where(ZeroizeOnDrop(no_drop))]`: no [`Drop`] implementation.
+///   - `#[derive_where(ZeroizeOnDrop(on_drop = path))]`: Call this function on
+///     [`Drop`] before zeroizing.
+///   - `#[derive_where(Zeroize(union), ZeroizeOnDrop(union))]`: Zeroize a
+///     union.
+///   - `#[derive_where(Deserialize(crate = path))]`: Specify path to the
+///     `serde` crate.
+///   - `#[derive_where(Serialize(crate = path))]`: Specify path to the `serde`
+///     crate.
 /// - `#[derive_where(skip_inner(EqHashOrd, ..))]`: Skip all fields in the item.
 ///   Optionally specify trait groups to constrain skipping fields. Only works
 ///   for structs, for enums use this on the variant-level.
+/// - `#[derive_where(secret)]`: Redact [`Debug`](trait@core::fmt::Debug) and
+///   `Format`, compare in constant time in [`PartialEq`] and require fields to
+///   be exposed for `Serialize`.
 ///
 /// Variant-level options:
 /// - `#[derive_where(default)]`: Uses this variant as the default for the
-///   [`Default`](trait@core::default::Default) implementation.
+///   [`Default`](trait@core::default::Default) implementation and
+///   `ConstDefault`.
 /// - `#[derive_where(skip_inner(EqHashOrd, ..))]`: Skip all fields in this
 ///   variant. Optionally specify trait groups to constrain skipping fields.
+/// - `#[derive_where(zeroize_to)]`: Overwrite the enum with this variant after
+///   zeroizing it on [`Drop`].
 ///
 /// Field-level options:
 /// - `#[derive_where(skip(EqHashOrd, ...))]`: Skip field. Optionally specify
 ///   trait groups to constrain skipping field.
+/// - `#[derive_where(ConstDefault(value = expr))]`: Use this expression instead
+///   of the `DEFAULT` constant of the field type.
+/// - `#[derive_where(Serialize(expose))]`: Allow serializing this field of a
+///   `secret` item.
 /// - `#[derive_where(Zeroize(fqs))]`: Use fully-qualified-syntax when
 ///   implementing [`Zeroize`].
+/// - `#[derive_where(Zeroize(order = 1))]`: Zeroize fields with a lower order
+///   first.
+/// - `#[derive_where(Zeroize(with = path))]`: Call this function with `&mut` to
+///   the field instead of implementing [`Zeroize`].
 ///
 /// See the [crate] level description for more details.
 ///
@@ -556,14 +1093,33 @@ fn derive_where_internal(mut item: DeriveInput) -> Result<TokenStream> {
 /// `cfg`s.
 #[doc(hidden)]
 #[cfg_attr(
-	not(feature = "serde"),
+	not(any(feature = "rkyv", feature = "schemars", feature = "serde")),
 	proc_macro_derive(DeriveWhere, attributes(derive_where))
 )]
 #[cfg_attr(
-	feature = "serde",
+	all(not(feature = "rkyv"), not(feature = "schemars"), feature = "serde"),
 	proc_macro_derive(DeriveWhere, attributes(derive_where, serde))
 )]
-#[cfg_attr(feature = "nightly", allow_internal_unstable(core_intrinsics))]
+#[cfg_attr(
+	all(not(feature = "rkyv"), feature = "schemars"),
+	proc_macro_derive(DeriveWhere, attributes(derive_where, schemars, serde))
+)]
+#[cfg_attr(
+	all(feature = "rkyv", not(any(feature = "schemars", feature = "serde"))),
+	proc_macro_derive(DeriveWhere, attributes(derive_where, rkyv))
+)]
+#[cfg_attr(
+	all(feature = "rkyv", not(feature = "schemars"), feature = "serde"),
+	proc_macro_derive(DeriveWhere, attributes(derive_where, rkyv, serde))
+)]
+#[cfg_attr(
+	all(feature = "rkyv", feature = "schemars"),
+	proc_macro_derive(DeriveWhere, attributes(derive_where, rkyv, schemars, serde))
+)]
+#[cfg_attr(
+	feature = "nightly",
+	allow_internal_unstable(core_intrinsics, structural_match)
+)]
 pub fn derive_where_actual(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	let input = TokenStream::from(input);
 	let full_item = match syn::parse2::<DeriveInput>(input) {
@@ -641,6 +1197,7 @@ fn generate_impl(
 		imp,
 		ty,
 		where_clause,
+		..
 	} = generics;
 	let mut where_clause = where_clause.map(Cow::Borrowed);
 	derive_where.where_clause(&mut where_clause, trait_, item);
@@ -648,9 +1205,13 @@ fn generate_impl(
 	let body = generate_body(derive_where, trait_, item, generics);
 
 	let ident = item.ident();
-	let mut output = trait_.impl_item(crate_, full_item, imp, ident, ty, &where_clause, body);
+	let mut output = trait_
+		.impl_with_item(crate_, full_item, item, ty, &where_clause, &body)
+		.unwrap_or_else(|| {
+			trait_.impl_item(crate_, full_item, imp, ident, ty, &where_clause, body)
+		});
 
-	if let Some((path, body)) = trait_.additional_impl() {
+	if let Some((path, body)) = trait_.additional_impl(derive_where, item) {
 		output.extend(quote! {
 			#[automatically_derived]
 			impl #imp #path for #ident #ty
@@ -740,8 +1301,14 @@ fn input_without_derive_where_attributes(mut input: DeriveInput) -> DeriveInput
 	input
 }
 
-/// This is used by the Serde implementation to remove the duplicate item.
-#[cfg(feature = "serde")]
+/// This is used by the Serde, JsonSchema, rkyv and zerocopy implementations to
+/// remove the duplicate item.
+#[cfg(any(
+	feature = "rkyv",
+	feature = "schemars",
+	feature = "serde",
+	feature = "zerocopy"
+))]
 #[doc(hidden)]
 #[proc_macro_attribute]
 pub fn derive_where_serde(
@@ -0,0 +1,103 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn struct_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Arbitrary; T)]
+			struct Test<T, U> {
+				a: T,
+				#[derive_where(skip(Arbitrary))]
+				b: std::marker::PhantomData<U>,
+				c: u8,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<'arbitrary, T, U> ::arbitrary::Arbitrary<'arbitrary> for Test<T, U>
+			where T: ::arbitrary::Arbitrary<'arbitrary>
+			{
+				fn arbitrary(__u: &mut ::arbitrary::Unstructured<'arbitrary>) -> ::arbitrary::Result<Self> {
+					::core::result::Result::Ok(Test {
+						a: ::arbitrary::Arbitrary::arbitrary(__u)?,
+						b: ::core::default::Default::default(),
+						c: ::arbitrary::Arbitrary::arbitrary(__u)?
+					})
+				}
+
+				fn arbitrary_take_rest(mut __u: ::arbitrary::Unstructured<'arbitrary>) -> ::arbitrary::Result<Self> {
+					::core::result::Result::Ok(Test {
+						a: ::arbitrary::Arbitrary::arbitrary(&mut __u)?,
+						b: ::core::default::Default::default(),
+						c: ::arbitrary::Arbitrary::arbitrary_take_rest(__u)?
+					})
+				}
+
+				fn size_hint(__depth: usize) -> (usize, ::core::option::Option<usize>) {
+					if __depth > 20 {
+						return (0, ::core::option::Option::None);
+					}
+
+					let __depth = __depth + 1;
+					::arbitrary::size_hint::and_all(&[
+						<T as ::arbitrary::Arbitrary>::size_hint(__depth),
+						<u8 as ::arbitrary::Arbitrary>::size_hint(__depth)
+					])
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn enum_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Arbitrary)]
+			enum Test<T> {
+				A(std::marker::PhantomData<T>),
+				B,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<'arbitrary, T> ::arbitrary::Arbitrary<'arbitrary> for Test<T> {
+				fn arbitrary(__u: &mut ::arbitrary::Unstructured<'arbitrary>) -> ::arbitrary::Result<Self> {
+					::core::result::Result::Ok(match (u64::from(<u32 as ::arbitrary::Arbitrary>::arbitrary(__u)?) * 2u64) >> 32 {
+						0u64 => Test::A { 0: ::arbitrary::Arbitrary::arbitrary(__u)? },
+						1u64 => Test::B,
+						_ => ::core::unreachable!(),
+					})
+				}
+
+				fn arbitrary_take_rest(mut __u: ::arbitrary::Unstructured<'arbitrary>) -> ::arbitrary::Result<Self> {
+					::core::result::Result::Ok(match (u64::from(<u32 as ::arbitrary::Arbitrary>::arbitrary(&mut __u)?) * 2u64) >> 32 {
+						0u64 => Test::A { 0: ::arbitrary::Arbitrary::arbitrary_take_rest(__u)? },
+						1u64 => Test::B,
+						_ => ::core::unreachable!(),
+					})
+				}
+
+				fn size_hint(__depth: usize) -> (usize, ::core::option::Option<usize>) {
+					if __depth > 20 {
+						return (0, ::core::option::Option::None);
+					}
+
+					let __depth = __depth + 1;
+					::arbitrary::size_hint::and(
+						<u32 as ::arbitrary::Arbitrary>::size_hint(__depth),
+						::arbitrary::size_hint::or_all(&[
+							::arbitrary::size_hint::and_all(&[
+								<std::marker::PhantomData<T> as ::arbitrary::Arbitrary>::size_hint(__depth)
+							]),
+							::arbitrary::size_hint::and_all(&[])
+						]),
+					)
+				}
+			}
+		},
+	)
+}
@@ -0,0 +1,58 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn bounds() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(assert(Send, Sync, 'static); T: Send)]
+			struct Test<T>(PhantomData<T>);
+		},
+		quote! {
+			const _: () = {
+				trait DeriveWhereAssert {
+					fn assert(&self);
+				}
+
+				impl<T> DeriveWhereAssert for Test<T>
+				where T: Send
+				{
+					fn assert(&self) {
+						struct __Assert<__T: Send + Sync + 'static + ?::core::marker::Sized>(::core::marker::PhantomData<__T>);
+
+						let _: __Assert<Self>;
+					}
+				}
+			};
+		},
+	)
+}
+
+#[test]
+fn no_bound() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(assert(Send); T)]
+			struct Test<T>(PhantomData<T>);
+		},
+		quote! {
+			const _: () = {
+				trait DeriveWhereAssert {
+					fn assert(&self);
+				}
+
+				impl<T> DeriveWhereAssert for Test<T>
+				where T: Send
+				{
+					fn assert(&self) {
+						struct __Assert<__T: Send + ?::core::marker::Sized>(::core::marker::PhantomData<__T>);
+
+						let _: __Assert<Self>;
+					}
+				}
+			};
+		},
+	)
+}
@@ -5,6 +5,15 @@ use super::test_derive;
 
 #[test]
 fn struct_() -> Result<()> {
+	#[cfg(feature = "nightly")]
+	let structural = quote! {
+		#[automatically_derived]
+		impl<T> ::core::marker::StructuralPartialEq for Test<T>
+		{ }
+	};
+	#[cfg(not(feature = "nightly"))]
+	let structural = quote! {};
+
 	test_derive(
 		quote! {
 			#[derive_where(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -97,6 +106,8 @@ fn struct_() -> Result<()> {
 				}
 			}
 
+			#structural
+
 			#[automatically_derived]
 			impl<T> ::core::cmp::PartialOrd for Test<T> {
 				#[inline]
@@ -110,6 +121,15 @@ fn struct_() -> Result<()> {
 
 #[test]
 fn tuple() -> Result<()> {
+	#[cfg(feature = "nightly")]
+	let structural = quote! {
+		#[automatically_derived]
+		impl<T> ::core::marker::StructuralPartialEq for Test<T>
+		{ }
+	};
+	#[cfg(not(feature = "nightly"))]
+	let structural = quote! {};
+
 	test_derive(
 		quote! {
 			#[derive_where(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -200,6 +220,8 @@ fn tuple() -> Result<()> {
 				}
 			}
 
+			#structural
+
 			#[automatically_derived]
 			impl<T> ::core::cmp::PartialOrd for Test<T> {
 				#[inline]
@@ -213,6 +235,14 @@ fn tuple() -> Result<()> {
 
 #[test]
 fn enum_() -> Result<()> {
+	#[cfg(feature = "nightly")]
+	let structural = quote! {
+		#[automatically_derived]
+		impl<T> ::core::marker::StructuralPartialEq for Test<T>
+		{ }
+	};
+	#[cfg(not(feature = "nightly"))]
+	let structural = quote! {};
 	#[cfg(feature = "nightly")]
 	let discriminant = quote! {
 		let __self_disc = ::core::intrinsics::discriminant_value(self);
@@ -389,6 +419,8 @@ fn enum_() -> Result<()> {
 				}
 			}
 
+			#structural
+
 			#[automatically_derived]
 			impl<T> ::core::cmp::PartialOrd for Test<T> {
 				#[inline]
@@ -0,0 +1,97 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn struct_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(BorshDeserialize, BorshSerialize; T)]
+			struct Test<T, U> {
+				a: T,
+				b: std::marker::PhantomData<U>,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T, U> ::borsh::BorshDeserialize for Test<T, U>
+			where T: ::borsh::BorshDeserialize
+			{
+				fn deserialize_reader<__R: ::borsh::io::Read>(__reader: &mut __R) -> ::core::result::Result<Self, ::borsh::io::Error> {
+					::core::result::Result::Ok(Test {
+						a: ::borsh::BorshDeserialize::deserialize_reader(__reader)?,
+						b: ::borsh::BorshDeserialize::deserialize_reader(__reader)?
+					})
+				}
+			}
+
+			#[automatically_derived]
+			impl<T, U> ::borsh::BorshSerialize for Test<T, U>
+			where T: ::borsh::BorshSerialize
+			{
+				fn serialize<__W: ::borsh::io::Write>(&self, __writer: &mut __W) -> ::core::result::Result<(), ::borsh::io::Error> {
+					match self {
+						Test { a: ref __field_a, b: ref __field_b } => {
+							::borsh::BorshSerialize::serialize(__field_a, __writer)?;
+							::borsh::BorshSerialize::serialize(__field_b, __writer)?;
+						}
+					}
+
+					::core::result::Result::Ok(())
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn enum_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(BorshDeserialize, BorshSerialize)]
+			#[repr(u8)]
+			enum Test<T> {
+				A(std::marker::PhantomData<T>) = 2,
+				B,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::borsh::BorshDeserialize for Test<T> {
+				fn deserialize_reader<__R: ::borsh::io::Read>(__reader: &mut __R) -> ::core::result::Result<Self, ::borsh::io::Error> {
+					let __tag = <::core::primitive::u8 as ::borsh::BorshDeserialize>::deserialize_reader(__reader)?;
+
+					if __tag == (2) {
+						return ::core::result::Result::Ok(Test::A { 0: ::borsh::BorshDeserialize::deserialize_reader(__reader)? });
+					}
+					if __tag == ((2) + 1) {
+						return ::core::result::Result::Ok(Test::B);
+					}
+
+					::core::result::Result::Err(::borsh::io::Error::new(
+						::borsh::io::ErrorKind::InvalidData,
+						"Unexpected variant tag",
+					))
+				}
+			}
+
+			#[automatically_derived]
+			impl<T> ::borsh::BorshSerialize for Test<T> {
+				fn serialize<__W: ::borsh::io::Write>(&self, __writer: &mut __W) -> ::core::result::Result<(), ::borsh::io::Error> {
+					match self {
+						Test::A(ref __field_0) => {
+							<::core::primitive::u8 as ::borsh::BorshSerialize>::serialize(&(2), __writer)?;
+							::borsh::BorshSerialize::serialize(__field_0, __writer)?;
+						}
+						Test::B => {
+							<::core::primitive::u8 as ::borsh::BorshSerialize>::serialize(&((2) + 1), __writer)?;
+						}
+					}
+
+					::core::result::Result::Ok(())
+				}
+			}
+		},
+	)
+}
@@ -139,6 +139,57 @@ fn for_lifetime() -> Result<()> {
 	)
 }
 
+#[test]
+fn lifetime() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Clone; 'a: 'b, T)]
+			struct Test<'a, 'b, T>(&'a T, &'b T);
+		},
+		quote! {
+			#[automatically_derived]
+			impl<'a, 'b, T> ::core::clone::Clone for Test<'a, 'b, T>
+			where
+				'a: 'b,
+				T: ::core::clone::Clone
+			{
+				#[inline]
+				fn clone(&self) -> Self {
+					match self {
+						Test(ref __field_0, ref __field_1) => Test {
+							0: ::core::clone::Clone::clone(__field_0),
+							1: ::core::clone::Clone::clone(__field_1)
+						},
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn for_lifetime_custom_bound() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Clone; for<'a> T: Trait<'a>)]
+			struct Test<T>(T);
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::core::clone::Clone for Test<T>
+			where for<'a> T: Trait<'a>
+			{
+				#[inline]
+				fn clone(&self) -> Self {
+					match self {
+						Test(ref __field_0) => Test { 0: ::core::clone::Clone::clone(__field_0) },
+					}
+				}
+			}
+		},
+	)
+}
+
 #[test]
 fn associated_type() -> Result<()> {
 	test_derive(
@@ -187,6 +238,16 @@ fn associated_type_custom_bound() -> Result<()> {
 
 #[test]
 fn check_trait_bounds() -> Result<()> {
+	#[cfg(feature = "nightly")]
+	let structural = quote! {
+		#[automatically_derived]
+		impl<T, U> ::core::marker::StructuralPartialEq for Test<T, U>
+		where T: ::core::cmp::PartialEq
+		{ }
+	};
+	#[cfg(not(feature = "nightly"))]
+	let structural = quote! {};
+
 	test_derive(
 		quote! {
 			#[derive_where(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd; T)]
@@ -306,6 +367,8 @@ fn check_trait_bounds() -> Result<()> {
 				}
 			}
 
+			#structural
+
 			#[automatically_derived]
 			impl<T, U> ::core::cmp::PartialOrd for Test<T, U>
 			where T: ::core::cmp::PartialOrd
@@ -330,6 +393,18 @@ fn check_trait_bounds() -> Result<()> {
 
 #[test]
 fn check_multiple_trait_bounds() -> Result<()> {
+	#[cfg(feature = "nightly")]
+	let structural = quote! {
+		#[automatically_derived]
+		impl<T, U, V> ::core::marker::StructuralPartialEq for Test<T, U, V>
+		where
+			T: ::core::cmp::PartialEq,
+			U: ::core::cmp::PartialEq
+		{ }
+	};
+	#[cfg(not(feature = "nightly"))]
+	let structural = quote! {};
+
 	test_derive(
 		quote! {
 			#[derive_where(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd; T, U)]
@@ -467,6 +542,8 @@ fn check_multiple_trait_bounds() -> Result<()> {
 				}
 			}
 
+			#structural
+
 			#[automatically_derived]
 			impl<T, U, V> ::core::cmp::PartialOrd for Test<T, U, V>
 			where
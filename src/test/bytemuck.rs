@@ -0,0 +1,96 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn bound() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Zeroable, Pod; T: Pod)]
+			#[repr(transparent)]
+			struct Test<T>(T);
+		},
+		quote! {
+			const _: () = {
+				trait DeriveWhereAssertZeroable {
+					fn assert(&self);
+				}
+
+				impl<T> DeriveWhereAssertZeroable for Test<T>
+				where T: Pod
+				{
+					fn assert(&self) {
+						struct __AssertZeroable<__T: ::bytemuck::Zeroable>(::core::marker::PhantomData<__T>);
+
+						let _: __AssertZeroable<T>;
+					}
+				}
+			};
+
+			#[automatically_derived]
+			unsafe impl<T> ::bytemuck::Zeroable for Test<T>
+			where T: Pod
+			{ }
+
+			const _: () = {
+				trait DeriveWhereAssertPod {
+					fn assert(&self);
+				}
+
+				impl<T> DeriveWhereAssertPod for Test<T>
+				where T: Pod
+				{
+					fn assert(&self) {
+						struct __AssertPod<__T: ::bytemuck::Pod>(::core::marker::PhantomData<__T>);
+
+						let _: __AssertPod<T>;
+					}
+				}
+			};
+
+			#[automatically_derived]
+			unsafe impl<T> ::bytemuck::Pod for Test<T>
+			where T: Pod
+			{ }
+		},
+	)
+}
+
+#[test]
+fn padding() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Pod)]
+			#[repr(C)]
+			struct Test {
+				a: u8,
+				b: u16,
+			}
+		},
+		quote! {
+			const _: () = {
+				trait DeriveWhereAssertPod {
+					fn assert(&self);
+				}
+
+				impl DeriveWhereAssertPod for Test {
+					fn assert(&self) {
+						struct __AssertPod<__T: ::bytemuck::Pod>(::core::marker::PhantomData<__T>);
+
+						let _: __AssertPod<u8>;
+						let _: __AssertPod<u16>;
+					}
+				}
+			};
+
+			#[automatically_derived]
+			unsafe impl ::bytemuck::Pod for Test { }
+
+			const _: () = ::core::assert!(
+				::core::mem::size_of::<Test>() == 0 + ::core::mem::size_of::<u8>() + ::core::mem::size_of::<u16>(),
+				"`Pod` doesn't support types with padding"
+			);
+		},
+	)
+}
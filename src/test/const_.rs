@@ -0,0 +1,90 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn struct_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Clone(const), Default(const), PartialEq(const); T)]
+			struct Test<T, U> { field: T, marker: Marker<U> }
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T, U> const ::core::clone::Clone for Test<T, U>
+			where T: [const] ::core::clone::Clone
+			{
+				#[inline]
+				fn clone(&self) -> Self {
+					match self {
+						Test { field: ref __field_field, marker: ref __field_marker } => Test {
+							field: ::core::clone::Clone::clone(__field_field),
+							marker: ::core::clone::Clone::clone(__field_marker)
+						},
+					}
+				}
+			}
+
+			#[automatically_derived]
+			impl<T, U> const ::core::default::Default for Test<T, U>
+			where T: [const] ::core::default::Default
+			{
+				fn default() -> Self {
+					Test {
+						field: ::core::default::Default::default(),
+						marker: ::core::default::Default::default()
+					}
+				}
+			}
+
+			#[automatically_derived]
+			impl<T, U> const ::core::cmp::PartialEq for Test<T, U>
+			where T: [const] ::core::cmp::PartialEq
+			{
+				#[inline]
+				fn eq(&self, __other: &Self) -> ::core::primitive::bool {
+					match (self, __other) {
+						(
+							Test { field: ref __field_field, marker: ref __field_marker },
+							Test { field: ref __other_field_field, marker: ref __other_field_marker }
+						) =>
+							true
+							&& ::core::cmp::PartialEq::eq(__field_field, __other_field_field)
+							&& ::core::cmp::PartialEq::eq(__field_marker, __other_field_marker),
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn enum_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(PartialEq(const))]
+			enum Test<T> {
+				A(Marker<T>),
+				B,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> const ::core::cmp::PartialEq for Test<T> {
+				#[inline]
+				fn eq(&self, __other: &Self) -> ::core::primitive::bool {
+					if ::core::intrinsics::discriminant_value(self) == ::core::intrinsics::discriminant_value(__other) {
+						match (self, __other) {
+							(Test::A(ref __field_0), Test::A(ref __other_field_0)) =>
+								true && ::core::cmp::PartialEq::eq(__field_0, __other_field_0),
+							_ => true,
+						}
+					} else {
+						false
+					}
+				}
+			}
+		},
+	)
+}
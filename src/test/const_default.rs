@@ -0,0 +1,95 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn struct_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ConstDefault; T: Trait)]
+			struct Test<T, U> {
+				field: T,
+				#[derive_where(ConstDefault(value = PhantomData))]
+				marker: PhantomData<U>,
+			}
+		},
+		quote! {
+			impl<T, U> Test<T, U>
+			where T: Trait
+			{
+				/// The default value of this type, usable in `const` contexts.
+				pub const DEFAULT: Self = Test {
+					field: <T>::DEFAULT,
+					marker: PhantomData
+				};
+			}
+		},
+	)
+}
+
+#[test]
+fn tuple() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ConstDefault; T: Trait)]
+			struct Test<T, U>(T, #[derive_where(ConstDefault(value = PhantomData))] PhantomData<U>);
+		},
+		quote! {
+			impl<T, U> Test<T, U>
+			where T: Trait
+			{
+				/// The default value of this type, usable in `const` contexts.
+				pub const DEFAULT: Self = Test(<T>::DEFAULT, PhantomData);
+			}
+		},
+	)
+}
+
+#[test]
+fn enum_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ConstDefault)]
+			enum Test<T> {
+				A(T),
+				#[derive_where(default)]
+				B { field: u8 },
+			}
+		},
+		quote! {
+			impl<T> Test<T>
+			{
+				/// The default value of this type, usable in `const` contexts.
+				pub const DEFAULT: Self = Test::B { field: <u8>::DEFAULT };
+			}
+		},
+	)
+}
+
+#[test]
+fn with_default() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Default, ConstDefault; T: Trait)]
+			struct Test<T>(#[derive_where(ConstDefault(value = 1))] T);
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::core::default::Default for Test<T>
+			where T: Trait
+			{
+				fn default() -> Self {
+					Test(::core::default::Default::default())
+				}
+			}
+
+			impl<T> Test<T>
+			where T: Trait
+			{
+				/// The default value of this type, usable in `const` contexts.
+				pub const DEFAULT: Self = Test(1);
+			}
+		},
+	)
+}
@@ -0,0 +1,102 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn struct_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Format; T)]
+			struct Test<T, U> {
+				a: T,
+				#[derive_where(skip(Format))]
+				b: std::marker::PhantomData<U>,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T, U> ::defmt::Format for Test<T, U>
+			where T: ::defmt::Format
+			{
+				fn format(&self, __f: ::defmt::Formatter<'_>) {
+					match self {
+						Test { a: ref __field_a, b: ref __field_b } => ::defmt::write!(__f, "Test {{ a: {=?}, .. }}", __field_a),
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn tuple() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Format; T)]
+			struct Test<T, U>(T, std::marker::PhantomData<U>);
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T, U> ::defmt::Format for Test<T, U>
+			where T: ::defmt::Format
+			{
+				fn format(&self, __f: ::defmt::Formatter<'_>) {
+					match self {
+						Test(ref __field_0, ref __field_1) => ::defmt::write!(__f, "Test({=?}, {=?})", __field_0, __field_1),
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn enum_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Format)]
+			enum Test<T> {
+				A { a: std::marker::PhantomData<T> },
+				B,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::defmt::Format for Test<T> {
+				fn format(&self, __f: ::defmt::Formatter<'_>) {
+					match self {
+						Test::A { a: ref __field_a } => ::defmt::write!(__f, "A {{ a: {=?} }}", __field_a),
+						Test::B => ::defmt::write!(__f, "B"),
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn empty() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Format)]
+			enum Test<T> {
+				A(std::marker::PhantomData<T>),
+				B {},
+				C(),
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::defmt::Format for Test<T> {
+				fn format(&self, __f: ::defmt::Formatter<'_>) {
+					match self {
+						Test::A(ref __field_0) => ::defmt::write!(__f, "A({=?})", __field_0),
+						Test::B { } => ::defmt::write!(__f, "B"),
+						Test::C() => ::defmt::write!(__f, "C"),
+					}
+				}
+			}
+		},
+	)
+}
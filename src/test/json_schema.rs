@@ -0,0 +1,87 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn bound() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(JsonSchema; T)]
+			#[schemars(bound = "U: Clone", rename = "test")]
+			struct Test<T, U>(T, std::marker::PhantomData<U>);
+		},
+		quote! {
+			#[::core::prelude::v1::derive(::schemars::JsonSchema)]
+			#[schemars(bound = "T : :: schemars :: JsonSchema , U : Clone")]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(JsonSchema; T)]
+			#[schemars(rename = "test")]
+			struct Test<T, U>(T, std::marker::PhantomData<U>);
+		},
+	)
+}
+
+#[test]
+fn skip() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(JsonSchema)]
+			struct Test<T> {
+				a: u8,
+				#[derive_where(skip(JsonSchema))]
+				b: std::marker::PhantomData<T>,
+			}
+		},
+		quote! {
+			#[::core::prelude::v1::derive(::schemars::JsonSchema)]
+			#[schemars(bound = "")]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(JsonSchema)]
+			struct Test<T> {
+				a: u8,
+				#[derive_where(skip(JsonSchema))]
+				#[schemars(skip)]
+				b: std::marker::PhantomData<T>,
+			}
+		},
+	)
+}
+
+#[test]
+fn skip_all() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Debug, JsonSchema)]
+			struct Test<T> {
+				a: u8,
+				#[derive_where(skip)]
+				b: std::marker::PhantomData<T>,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::core::fmt::Debug for Test<T> {
+				fn fmt(&self, __f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					match self {
+						Test { a: ref __field_a, b: ref __field_b } => {
+							let mut __builder = ::core::fmt::Formatter::debug_struct(__f, "Test");
+							::core::fmt::DebugStruct::field(&mut __builder, "a", __field_a);
+							::core::fmt::DebugStruct::finish_non_exhaustive(&mut __builder)
+						}
+					}
+				}
+			}
+
+			#[::core::prelude::v1::derive(::schemars::JsonSchema)]
+			#[schemars(bound = "")]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(Debug, JsonSchema)]
+			struct Test<T> {
+				a: u8,
+				#[derive_where(skip)]
+				b: std::marker::PhantomData<T>,
+			}
+		},
+	)
+}
@@ -1,16 +1,41 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod assert;
 mod basic;
+#[cfg(feature = "borsh")]
+mod borsh;
 mod bound;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
 mod clone;
+#[cfg(feature = "nightly")]
+mod const_;
+mod const_default;
 mod discriminant;
 mod enum_;
+#[cfg(feature = "defmt")]
+mod format;
 #[cfg(not(any(feature = "nightly", feature = "safe")))]
 mod incomparable;
+#[cfg(feature = "schemars")]
+mod json_schema;
 mod misc;
 mod partial_ord;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+mod secret;
 #[cfg(feature = "serde")]
 mod serde;
 mod skip;
+#[cfg(feature = "subtle")]
+mod subtle;
+#[cfg(not(feature = "safe"))]
+mod unsafe_;
 mod use_case;
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
 #[cfg(feature = "zeroize")]
 mod zeroize;
 
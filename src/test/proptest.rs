@@ -0,0 +1,107 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn struct_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ProptestArbitrary; T)]
+			struct Test<T, U> {
+				a: T,
+				#[derive_where(skip(ProptestArbitrary))]
+				b: std::marker::PhantomData<U>,
+				#[derive_where(ProptestArbitrary(strategy = 0..10))]
+				c: u8,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T, U> ::proptest::arbitrary::Arbitrary for Test<T, U>
+			where T: ::proptest::arbitrary::Arbitrary
+			{
+				type Parameters = ();
+				type Strategy = ::proptest::strategy::Map<
+					(
+						<T as ::proptest::arbitrary::Arbitrary>::Strategy,
+						::proptest::strategy::BoxedStrategy<u8>
+					),
+					fn((T, u8)) -> Self
+				>;
+
+				fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+					::proptest::strategy::Strategy::prop_map(
+						(
+							::proptest::arbitrary::any::<T>(),
+							::proptest::strategy::Strategy::boxed(0..10)
+						),
+						(|(__field_a, __field_c)| Test {
+							a: __field_a,
+							b: ::core::default::Default::default(),
+							c: __field_c
+						}) as fn((T, u8)) -> Self,
+					)
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn enum_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ProptestArbitrary)]
+			enum Test<T> {
+				A(u8),
+				#[derive_where(ProptestArbitrary(weight = 2))]
+				B(std::marker::PhantomData<T>),
+				C,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::proptest::arbitrary::Arbitrary for Test<T>
+			{
+				type Parameters = ();
+				type Strategy = ::proptest::strategy::TupleUnion<(
+					(
+						u32,
+						::proptest::std_facade::Arc<::proptest::strategy::Map<
+							<u8 as ::proptest::arbitrary::Arbitrary>::Strategy,
+							fn(u8) -> Self
+						> >
+					),
+					(
+						u32,
+						::proptest::std_facade::Arc<::proptest::strategy::Map<
+							<std::marker::PhantomData<T> as ::proptest::arbitrary::Arbitrary>::Strategy,
+							fn(std::marker::PhantomData<T>) -> Self
+						> >
+					),
+					(
+						u32,
+						::proptest::std_facade::Arc<::proptest::strategy::LazyJust<Self, fn() -> Self> >
+					),
+				)>;
+
+				fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+					::proptest::strategy::TupleUnion::new((
+						(1u32, ::proptest::std_facade::Arc::new(::proptest::strategy::Strategy::prop_map(
+							::proptest::arbitrary::any::<u8>(),
+							(|__field_0| Test::A { 0: __field_0 }) as fn(u8) -> Self,
+						))),
+						(2u32, ::proptest::std_facade::Arc::new(::proptest::strategy::Strategy::prop_map(
+							::proptest::arbitrary::any::<std::marker::PhantomData<T> >(),
+							(|__field_0| Test::B { 0: __field_0 }) as fn(std::marker::PhantomData<T>) -> Self,
+						))),
+						(1u32, ::proptest::std_facade::Arc::new(
+							::proptest::strategy::LazyJust::new((|| Test::C) as fn() -> Self)
+						)),
+					))
+				}
+			}
+		},
+	)
+}
@@ -0,0 +1,83 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn bound() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Archive, RkyvDeserialize, RkyvSerialize; T)]
+			struct Test<T, U>(T, std::marker::PhantomData<U>);
+		},
+		quote! {
+			#[::core::prelude::v1::derive(::rkyv::Archive)]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(Archive, RkyvDeserialize, RkyvSerialize; T)]
+			struct Test<T, U>(T, std::marker::PhantomData<U>)
+			where
+				T: ::rkyv::Archive;
+
+			#[::core::prelude::v1::derive(::rkyv::Deserialize)]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(Archive, RkyvDeserialize, RkyvSerialize; T)]
+			struct Test<T, U>(T, std::marker::PhantomData<U>)
+			where
+				T: ::rkyv::Archive;
+
+			#[::core::prelude::v1::derive(::rkyv::Serialize)]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(Archive, RkyvDeserialize, RkyvSerialize; T)]
+			struct Test<T, U>(T, std::marker::PhantomData<U>)
+			where
+				T: ::rkyv::Serialize<__S>;
+		},
+	)
+}
+
+#[test]
+fn skip() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Archive; T)]
+			struct Test<T, U> {
+				a: T,
+				#[derive_where(skip(Archive))]
+				b: std::marker::PhantomData<U>,
+			}
+		},
+		quote! {
+			#[::core::prelude::v1::derive(::rkyv::Archive)]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(Archive; T)]
+			struct Test<T, U>
+			where
+				T: ::rkyv::Archive
+			{
+				a: T,
+				#[derive_where(skip(Archive))]
+				#[rkyv(with = ::rkyv::with::Skip)]
+				b: std::marker::PhantomData<U>,
+			}
+		},
+	)
+}
+
+#[test]
+fn crate_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Archive(crate = rkyv_); T)]
+			struct Test<T, U>(T, std::marker::PhantomData<U>);
+		},
+		quote! {
+			#[::core::prelude::v1::derive(rkyv_::Archive)]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(Archive(crate = rkyv_); T)]
+			#[rkyv(crate = rkyv_)]
+			struct Test<T, U>(T, std::marker::PhantomData<U>)
+			where
+				T: rkyv_::Archive;
+		},
+	)
+}
@@ -0,0 +1,164 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn debug() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Debug; T)]
+			#[derive_where(secret)]
+			struct Test<T> {
+				a: T,
+				#[derive_where(skip(Debug))]
+				b: u8,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::core::fmt::Debug for Test<T>
+			where T: ::core::fmt::Debug
+			{
+				fn fmt(&self, __f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					match self {
+						Test { a: ref __field_a, b: ref __field_b } => {
+							let mut __builder = ::core::fmt::Formatter::debug_struct(__f, "Test");
+							::core::fmt::DebugStruct::field(&mut __builder, "a", &::core::format_args!("[REDACTED]"));
+							::core::fmt::DebugStruct::finish_non_exhaustive(&mut __builder)
+						}
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn debug_enum() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Debug)]
+			#[derive_where(secret)]
+			enum Test<T> {
+				A(std::marker::PhantomData<T>),
+				B,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::core::fmt::Debug for Test<T> {
+				fn fmt(&self, __f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					match self {
+						Test::A(ref __field_0) => {
+							let mut __builder = ::core::fmt::Formatter::debug_tuple(__f, "A");
+							::core::fmt::DebugTuple::field(&mut __builder, &::core::format_args!("[REDACTED]"));
+							::core::fmt::DebugTuple::finish(&mut __builder)
+						}
+						Test::B => ::core::fmt::Formatter::write_str(__f, "B"),
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+#[cfg(feature = "defmt")]
+fn format() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Format; T)]
+			#[derive_where(secret)]
+			enum Test<T> {
+				A { a: T, b: u8 },
+				B(T),
+				C,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::defmt::Format for Test<T>
+			where T: ::defmt::Format
+			{
+				fn format(&self, __f: ::defmt::Formatter<'_>) {
+					match self {
+						Test::A { a: ref __field_a, b: ref __field_b } => ::defmt::write!(__f, "A {{ a: [REDACTED], b: [REDACTED] }}"),
+						Test::B(ref __field_0) => ::defmt::write!(__f, "B([REDACTED])"),
+						Test::C => ::defmt::write!(__f, "C"),
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+#[cfg(feature = "subtle")]
+fn partial_eq() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(PartialEq; T)]
+			#[derive_where(secret)]
+			struct Test<T> {
+				a: T,
+				b: u8,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::core::cmp::PartialEq for Test<T>
+			where T: ::core::cmp::PartialEq + ::subtle::ConstantTimeEq
+			{
+				#[inline]
+				fn eq(&self, __other: &Self) -> ::core::primitive::bool {
+					match (self, __other) {
+						(Test { a: ref __field_a, b: ref __field_b }, Test { a: ref __other_field_a, b: ref __other_field_b }) =>
+							<::core::primitive::bool as ::core::convert::From<::subtle::Choice>>::from(
+								::subtle::Choice::from(1)
+									& ::subtle::ConstantTimeEq::ct_eq(__field_a, __other_field_a)
+									& ::subtle::ConstantTimeEq::ct_eq(__field_b, __other_field_b)
+							),
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+#[cfg(feature = "subtle")]
+fn partial_eq_crate() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ConstantTimeEq(crate = custom::subtle), PartialEq)]
+			#[derive_where(secret)]
+			struct Test(u8);
+		},
+		quote! {
+			#[automatically_derived]
+			impl custom::subtle::ConstantTimeEq for Test {
+				fn ct_eq(&self, __other: &Self) -> custom::subtle::Choice {
+					match (self, __other) {
+						(Test(ref __field_0), Test(ref __other_field_0)) =>
+							custom::subtle::Choice::from(1) & custom::subtle::ConstantTimeEq::ct_eq(__field_0, __other_field_0),
+					}
+				}
+			}
+
+			#[automatically_derived]
+			impl ::core::cmp::PartialEq for Test {
+				#[inline]
+				fn eq(&self, __other: &Self) -> ::core::primitive::bool {
+					match (self, __other) {
+						(Test(ref __field_0), Test(ref __other_field_0)) =>
+							<::core::primitive::bool as ::core::convert::From<custom::subtle::Choice>>::from(
+								custom::subtle::Choice::from(1)
+									& custom::subtle::ConstantTimeEq::ct_eq(__field_0, __other_field_0)
+							),
+					}
+				}
+			}
+		},
+	)
+}
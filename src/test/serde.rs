@@ -8,6 +8,7 @@ fn basic() -> Result<()> {
 	test_derive(
 		quote! {
 			#[derive_where(Deserialize, Serialize)]
+			#[serde(expecting = "test")]
 			struct Test<T>(std::marker::PhantomData<T>);
 		},
 		quote! {
@@ -15,12 +16,14 @@ fn basic() -> Result<()> {
 			#[serde(bound(deserialize = ""))]
 			#[::derive_where::derive_where_serde]
 			#[derive_where(Deserialize, Serialize)]
+			#[serde(expecting = "test")]
 			struct Test<T>(std::marker::PhantomData<T>);
 
 			#[::core::prelude::v1::derive(::serde::Serialize)]
 			#[serde(bound(serialize = ""))]
 			#[::derive_where::derive_where_serde]
 			#[derive_where(Deserialize, Serialize)]
+			#[serde(expecting = "test")]
 			struct Test<T>(std::marker::PhantomData<T>);
 		},
 	)
@@ -31,6 +34,7 @@ fn bound() -> Result<()> {
 	test_derive(
 		quote! {
 			#[derive_where(Deserialize, Serialize; T)]
+			#[serde(expecting = "test")]
 			struct Test<T, U>(T, std::marker::PhantomData<U>);
 		},
 		quote! {
@@ -38,12 +42,14 @@ fn bound() -> Result<()> {
 			#[serde(bound(deserialize = "T : :: serde :: Deserialize < 'de >"))]
 			#[::derive_where::derive_where_serde]
 			#[derive_where(Deserialize, Serialize; T)]
+			#[serde(expecting = "test")]
 			struct Test<T, U>(T, std::marker::PhantomData<U>);
 
 			#[::core::prelude::v1::derive(::serde::Serialize)]
 			#[serde(bound(serialize = "T : :: serde :: Serialize"))]
 			#[::derive_where::derive_where_serde]
 			#[derive_where(Deserialize, Serialize; T)]
+			#[serde(expecting = "test")]
 			struct Test<T, U>(T, std::marker::PhantomData<U>);
 		},
 	)
@@ -54,6 +60,7 @@ fn bound_two() -> Result<()> {
 	test_derive(
 		quote! {
 			#[derive_where(Deserialize, Serialize; T, U)]
+			#[serde(expecting = "test")]
 			struct Test<T, U, V>(T, U, std::marker::PhantomData<V>);
 		},
 		quote! {
@@ -61,17 +68,45 @@ fn bound_two() -> Result<()> {
 			#[serde(bound(deserialize = "T : :: serde :: Deserialize < 'de > , U : :: serde :: Deserialize < 'de >"))]
 			#[::derive_where::derive_where_serde]
 			#[derive_where(Deserialize, Serialize; T, U)]
+			#[serde(expecting = "test")]
 			struct Test<T, U, V>(T, U, std::marker::PhantomData<V>);
 
 			#[::core::prelude::v1::derive(::serde::Serialize)]
 			#[serde(bound(serialize = "T : :: serde :: Serialize , U : :: serde :: Serialize"))]
 			#[::derive_where::derive_where_serde]
 			#[derive_where(Deserialize, Serialize; T, U)]
+			#[serde(expecting = "test")]
 			struct Test<T, U, V>(T, U, std::marker::PhantomData<V>);
 		},
 	)
 }
 
+#[test]
+fn bound_merge() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Deserialize, Serialize; T)]
+			#[serde(bound(deserialize = "U: Default"), expecting = "test")]
+			struct Test<T, U>(T, #[serde(skip)] U);
+		},
+		quote! {
+			#[::core::prelude::v1::derive(::serde::Deserialize)]
+			#[serde(bound(deserialize = "T : :: serde :: Deserialize < 'de > , U : Default"))]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(Deserialize, Serialize; T)]
+			#[serde(expecting = "test")]
+			struct Test<T, U>(T, #[serde(skip)] U);
+
+			#[::core::prelude::v1::derive(::serde::Serialize)]
+			#[serde(bound(serialize = "T : :: serde :: Serialize"))]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(Deserialize, Serialize; T)]
+			#[serde(expecting = "test")]
+			struct Test<T, U>(T, #[serde(skip)] U);
+		},
+	)
+}
+
 #[test]
 fn attribute() -> Result<()> {
 	test_derive(
@@ -104,6 +139,7 @@ fn crate_() -> Result<()> {
 		quote! {
 			#[derive_where(Deserialize, Serialize)]
 			#[serde(crate = "serde_")]
+			#[serde(expecting = "test")]
 			struct Test<T>(std::marker::PhantomData<T>);
 		},
 		quote! {
@@ -112,6 +148,7 @@ fn crate_() -> Result<()> {
 			#[::derive_where::derive_where_serde]
 			#[derive_where(Deserialize, Serialize)]
 			#[serde(crate = "serde_")]
+			#[serde(expecting = "test")]
 			struct Test<T>(std::marker::PhantomData<T>);
 
 			#[::core::prelude::v1::derive(serde_::Serialize)]
@@ -119,7 +156,422 @@ fn crate_() -> Result<()> {
 			#[::derive_where::derive_where_serde]
 			#[derive_where(Deserialize, Serialize)]
 			#[serde(crate = "serde_")]
+			#[serde(expecting = "test")]
+			struct Test<T>(std::marker::PhantomData<T>);
+		},
+	)
+}
+
+#[test]
+fn crate_option() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Deserialize(crate = serde_), Serialize(crate = "serde_"))]
+			#[serde(expecting = "test")]
 			struct Test<T>(std::marker::PhantomData<T>);
 		},
+		quote! {
+			#[::core::prelude::v1::derive(serde_::Deserialize)]
+			#[serde(bound(deserialize = ""))]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(Deserialize(crate = serde_), Serialize(crate = "serde_"))]
+			#[serde(expecting = "test")]
+			#[serde(crate = "serde_")]
+			struct Test<T>(std::marker::PhantomData<T>);
+
+			#[::core::prelude::v1::derive(serde_::Serialize)]
+			#[serde(bound(serialize = ""))]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(Deserialize(crate = serde_), Serialize(crate = "serde_"))]
+			#[serde(expecting = "test")]
+			#[serde(crate = "serde_")]
+			struct Test<T>(std::marker::PhantomData<T>);
+		},
+	)
+}
+
+#[test]
+fn skip() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Deserialize, Serialize)]
+			#[serde(expecting = "test")]
+			struct Test<T> {
+				#[derive_where(skip(Serialize))]
+				a: std::marker::PhantomData<T>,
+				#[derive_where(skip(Serde))]
+				b: std::marker::PhantomData<T>,
+			}
+		},
+		quote! {
+			#[::core::prelude::v1::derive(::serde::Deserialize)]
+			#[serde(bound(deserialize = ""))]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(Deserialize, Serialize)]
+			#[serde(expecting = "test")]
+			struct Test<T> {
+				#[derive_where(skip(Serialize))]
+				a: std::marker::PhantomData<T>,
+				#[derive_where(skip(Serde))]
+				#[serde(skip_deserializing)]
+				b: std::marker::PhantomData<T>,
+			}
+
+			#[::core::prelude::v1::derive(::serde::Serialize)]
+			#[serde(bound(serialize = ""))]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(Deserialize, Serialize)]
+			#[serde(expecting = "test")]
+			struct Test<T> {
+				#[derive_where(skip(Serialize))]
+				#[serde(skip_serializing)]
+				a: std::marker::PhantomData<T>,
+				#[derive_where(skip(Serde))]
+				#[serde(skip_serializing)]
+				b: std::marker::PhantomData<T>,
+			}
+		},
+	)
+}
+
+#[test]
+fn native_serialize_struct() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Serialize; T)]
+			#[serde(rename_all = "camelCase")]
+			struct Test<T, U> {
+				field_a: T,
+				#[serde(skip)]
+				field_b: std::marker::PhantomData<U>,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T, U> ::serde::Serialize for Test<T, U>
+			where
+				T: ::serde::Serialize
+			{
+				fn serialize<__S>(&self, __serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+				where
+					__S: ::serde::Serializer,
+				{
+					match self {
+						Test { field_a: ref __field_field_a, field_b: ref __field_field_b } => {
+							let mut __state = ::serde::Serializer::serialize_struct(__serializer, "Test", 1usize)?;
+							::serde::ser::SerializeStruct::serialize_field(&mut __state, "fieldA", __field_field_a)?;
+							::serde::ser::SerializeStruct::end(__state)
+						}
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn native_serialize_bound() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Serialize; T)]
+			#[serde(bound(serialize = "U: Clone"))]
+			struct Test<T, U>(T, std::marker::PhantomData<U>);
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T, U> ::serde::Serialize for Test<T, U>
+			where
+				T: ::serde::Serialize,
+				U: Clone
+			{
+				fn serialize<__S>(&self, __serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+				where
+					__S: ::serde::Serializer,
+				{
+					match self {
+						Test(ref __field_0, ref __field_1) => {
+							let mut __state = ::serde::Serializer::serialize_tuple_struct(__serializer, "Test", 2usize)?;
+							::serde::ser::SerializeTupleStruct::serialize_field(&mut __state, __field_0)?;
+							::serde::ser::SerializeTupleStruct::serialize_field(&mut __state, __field_1)?;
+							::serde::ser::SerializeTupleStruct::end(__state)
+						}
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn native_serialize_enum() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Serialize; T)]
+			enum Test<T, U> {
+				A,
+				B(T),
+				#[serde(rename = "c")]
+				C(T, std::marker::PhantomData<U>),
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T, U> ::serde::Serialize for Test<T, U>
+			where
+				T: ::serde::Serialize
+			{
+				fn serialize<__S>(&self, __serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+				where
+					__S: ::serde::Serializer,
+				{
+					match self {
+						Test::A => {
+							::serde::Serializer::serialize_unit_variant(__serializer, "Test", 0u32, "A")
+						}
+						Test::B(ref __field_0) => {
+							::serde::Serializer::serialize_newtype_variant(__serializer, "Test", 1u32, "B", __field_0)
+						}
+						Test::C(ref __field_0, ref __field_1) => {
+							let mut __state = ::serde::Serializer::serialize_tuple_variant(__serializer, "Test", 2u32, "c", 2usize)?;
+							::serde::ser::SerializeTupleVariant::serialize_field(&mut __state, __field_0)?;
+							::serde::ser::SerializeTupleVariant::serialize_field(&mut __state, __field_1)?;
+							::serde::ser::SerializeTupleVariant::end(__state)
+						}
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn native_rename_all_underscore_field() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Serialize; T)]
+			#[serde(rename_all = "camelCase")]
+			struct Test<T, U> {
+				__: T,
+				#[serde(skip)]
+				marker: std::marker::PhantomData<U>,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T, U> ::serde::Serialize for Test<T, U>
+			where
+				T: ::serde::Serialize
+			{
+				fn serialize<__S>(&self, __serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+				where
+					__S: ::serde::Serializer,
+				{
+					match self {
+						Test { __: ref __field___, marker: ref __field_marker } => {
+							let mut __state = ::serde::Serializer::serialize_struct(__serializer, "Test", 1usize)?;
+							::serde::ser::SerializeStruct::serialize_field(&mut __state, "", __field___)?;
+							::serde::ser::SerializeStruct::end(__state)
+						}
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn native_rename_all_non_ascii_variant() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Serialize; T)]
+			#[serde(rename_all = "camelCase")]
+			enum Test<T, U> {
+				Über(T, std::marker::PhantomData<U>),
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T, U> ::serde::Serialize for Test<T, U>
+			where
+				T: ::serde::Serialize
+			{
+				fn serialize<__S>(&self, __serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+				where
+					__S: ::serde::Serializer,
+				{
+					match self {
+						Test::Über(ref __field_0, ref __field_1) => {
+							let mut __state = ::serde::Serializer::serialize_tuple_variant(__serializer, "Test", 0u32, "Über", 2usize)?;
+							::serde::ser::SerializeTupleVariant::serialize_field(&mut __state, __field_0)?;
+							::serde::ser::SerializeTupleVariant::serialize_field(&mut __state, __field_1)?;
+							::serde::ser::SerializeTupleVariant::end(__state)
+						}
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn native_deserialize() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Deserialize; T)]
+			struct Test<T, U> {
+				field: T,
+				#[serde(skip)]
+				marker: std::marker::PhantomData<U>,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<'de, T, U> ::serde::Deserialize<'de> for Test<T, U>
+			where
+				T: ::serde::Deserialize<'de>
+			{
+				fn deserialize<__D>(__deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+				where
+					__D: ::serde::Deserializer<'de>,
+				{
+					fn __missing_field<'de, __V, __E>(__field: &'static str) -> ::core::result::Result<__V, __E>
+					where
+						__V: ::serde::Deserialize<'de>,
+						__E: ::serde::de::Error,
+					{
+						struct __MissingFieldDeserializer<__E>(&'static str, ::core::marker::PhantomData<__E>);
+						impl<'de, __E> ::serde::Deserializer<'de> for __MissingFieldDeserializer<__E>
+						where
+							__E: ::serde::de::Error,
+						{
+							type Error = __E;
+							fn deserialize_any<__V>(self, _: __V) -> ::core::result::Result<__V::Value, __E>
+							where
+								__V: ::serde::de::Visitor<'de>,
+							{
+								::core::result::Result::Err(<__E as ::serde::de::Error>::missing_field(self.0))
+							}
+							fn deserialize_option<__V>(self, __visitor: __V) -> ::core::result::Result<__V::Value, __E>
+							where
+								__V: ::serde::de::Visitor<'de>,
+							{
+								::serde::de::Visitor::visit_none(__visitor)
+							}
+							::serde::forward_to_deserialize_any! {
+								bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+								bytes byte_buf unit unit_struct newtype_struct seq tuple
+								tuple_struct map struct enum identifier ignored_any
+							}
+						}
+						<__V as ::serde::Deserialize>::deserialize(__MissingFieldDeserializer(__field, ::core::marker::PhantomData))
+					}
+					const FIELDS: &[&str] = &["field"];
+					#[allow(non_camel_case_types)]
+					enum __Field {
+						__field_field,
+						__ignore,
+					}
+					struct __FieldVisitor;
+					impl<'de> ::serde::de::Visitor<'de> for __FieldVisitor {
+						type Value = __Field;
+						fn expecting(&self, __formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+							::core::fmt::Formatter::write_str(__formatter, "field identifier")
+						}
+						fn visit_u64<__E>(self, __value: u64) -> ::core::result::Result<Self::Value, __E>
+						where
+							__E: ::serde::de::Error,
+						{
+							match __value {
+								0u64 => ::core::result::Result::Ok(__Field::__field_field),
+								_ => ::core::result::Result::Ok(__Field::__ignore),
+							}
+						}
+						fn visit_str<__E>(self, __value: &str) -> ::core::result::Result<Self::Value, __E>
+						where
+							__E: ::serde::de::Error,
+						{
+							match __value {
+								"field" => ::core::result::Result::Ok(__Field::__field_field),
+								_ => ::core::result::Result::Ok(__Field::__ignore),
+							}
+						}
+						fn visit_bytes<__E>(self, __value: &[u8]) -> ::core::result::Result<Self::Value, __E>
+						where
+							__E: ::serde::de::Error,
+						{
+							match __value {
+								b"field" => ::core::result::Result::Ok(__Field::__field_field),
+								_ => ::core::result::Result::Ok(__Field::__ignore),
+							}
+						}
+					}
+					impl<'de> ::serde::Deserialize<'de> for __Field {
+						fn deserialize<__D>(__deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+						where
+							__D: ::serde::Deserializer<'de>,
+						{
+							::serde::Deserializer::deserialize_identifier(__deserializer, __FieldVisitor)
+						}
+					}
+					struct __Visitor<'de, T, U>
+					where
+						T: ::serde::Deserialize<'de>
+					{
+						marker: ::core::marker::PhantomData<Test<T, U> >,
+						lifetime: ::core::marker::PhantomData<&'de ()>,
+					}
+					impl<'de, T, U> ::serde::de::Visitor<'de> for __Visitor<'de, T, U>
+					where
+						T: ::serde::Deserialize<'de>
+					{
+						type Value = Test<T, U>;
+						fn expecting(&self, __formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+							::core::fmt::Formatter::write_str(__formatter, "struct Test")
+						}
+						fn visit_seq<__A>(self, mut __seq: __A) -> ::core::result::Result<Self::Value, __A::Error>
+						where
+							__A: ::serde::de::SeqAccess<'de>,
+						{
+							let __field_field = match ::serde::de::SeqAccess::next_element::<T>(&mut __seq)? {
+								::core::option::Option::Some(__value) => __value,
+								::core::option::Option::None => return ::core::result::Result::Err(<__A::Error as ::serde::de::Error>::invalid_length(0usize, &"struct Test with 1 element")),
+							};
+							let __field_marker = ::core::default::Default::default();
+							::core::result::Result::Ok(Test { field: __field_field, marker: __field_marker })
+						}
+						fn visit_map<__A>(self, mut __map: __A) -> ::core::result::Result<Self::Value, __A::Error>
+						where
+							__A: ::serde::de::MapAccess<'de>,
+						{
+							let mut __field_field: ::core::option::Option<T> = ::core::option::Option::None;
+							while let ::core::option::Option::Some(__key) = ::serde::de::MapAccess::next_key::<__Field>(&mut __map)? {
+								match __key {
+									__Field::__field_field => {
+										if ::core::option::Option::is_some(&__field_field) {
+											return ::core::result::Result::Err(<__A::Error as ::serde::de::Error>::duplicate_field("field"));
+										}
+
+										__field_field = ::core::option::Option::Some(::serde::de::MapAccess::next_value::<T>(&mut __map)?);
+									}
+									_ => {
+										let _ = ::serde::de::MapAccess::next_value::<::serde::de::IgnoredAny>(&mut __map)?;
+									}
+								}
+							}
+							let __field_field = match __field_field {
+								::core::option::Option::Some(__value) => __value,
+								::core::option::Option::None => __missing_field::<_, __A::Error>("field")?,
+							};
+							let __field_marker = ::core::default::Default::default();
+							::core::result::Result::Ok(Test { field: __field_field, marker: __field_marker })
+						}
+					}
+					::serde::Deserializer::deserialize_struct(__deserializer, "Test", FIELDS, __Visitor {
+						marker: ::core::marker::PhantomData,
+						lifetime: ::core::marker::PhantomData,
+					})
+				}
+			}
+		},
 	)
 }
@@ -0,0 +1,131 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn struct_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ConstantTimeEq; T)]
+			struct Test<T, U> {
+				a: T,
+				#[derive_where(skip(ConstantTimeEq))]
+				b: std::marker::PhantomData<U>,
+				c: u8,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T, U> ::subtle::ConstantTimeEq for Test<T, U>
+			where T: ::subtle::ConstantTimeEq
+			{
+				fn ct_eq(&self, __other: &Self) -> ::subtle::Choice {
+					match (self, __other) {
+						(Test { a: ref __field_a, b: ref __field_b, c: ref __field_c }, Test { a: ref __other_field_a, b: ref __other_field_b, c: ref __other_field_c }) =>
+							::subtle::Choice::from(1)
+								& ::subtle::ConstantTimeEq::ct_eq(__field_a, __other_field_a)
+								& ::subtle::ConstantTimeEq::ct_eq(__field_c, __other_field_c),
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn enum_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ConstantTimeEq)]
+			enum Test<T> {
+				A(std::marker::PhantomData<T>),
+				B,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::subtle::ConstantTimeEq for Test<T> {
+				fn ct_eq(&self, __other: &Self) -> ::subtle::Choice {
+					match (self, __other) {
+						(Test::A(ref __field_0), Test::A(ref __other_field_0)) =>
+							::subtle::Choice::from(1) & ::subtle::ConstantTimeEq::ct_eq(__field_0, __other_field_0),
+						(Test::B, Test::B) => ::subtle::Choice::from(1),
+						_ => ::subtle::Choice::from(0),
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn crate_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ConstantTimeEq(crate = custom::subtle))]
+			struct Test(u8);
+		},
+		quote! {
+			#[automatically_derived]
+			impl custom::subtle::ConstantTimeEq for Test {
+				fn ct_eq(&self, __other: &Self) -> custom::subtle::Choice {
+					match (self, __other) {
+						(Test(ref __field_0), Test(ref __other_field_0)) =>
+							custom::subtle::Choice::from(1) & custom::subtle::ConstantTimeEq::ct_eq(__field_0, __other_field_0),
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn conditionally_selectable_struct() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ConditionallySelectable; T)]
+			struct Test<T> {
+				a: T,
+				b: u8,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::subtle::ConditionallySelectable for Test<T>
+			where T: ::subtle::ConditionallySelectable
+			{
+				fn conditional_select(__a: &Self, __b: &Self, __choice: ::subtle::Choice) -> Self {
+					match (__a, __b) {
+						(Test { a: ref __field_a, b: ref __field_b }, Test { a: ref __other_field_a, b: ref __other_field_b }) => Test {
+							a: ::subtle::ConditionallySelectable::conditional_select(__field_a, __other_field_a, __choice),
+							b: ::subtle::ConditionallySelectable::conditional_select(__field_b, __other_field_b, __choice)
+						},
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn conditionally_selectable_tuple() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ConditionallySelectable(crate = custom::subtle))]
+			struct Test(u8);
+		},
+		quote! {
+			#[automatically_derived]
+			impl custom::subtle::ConditionallySelectable for Test {
+				fn conditional_select(__a: &Self, __b: &Self, __choice: custom::subtle::Choice) -> Self {
+					match (__a, __b) {
+						(Test(ref __field_0), Test(ref __other_field_0)) => Test {
+							0: custom::subtle::ConditionallySelectable::conditional_select(__field_0, __other_field_0, __choice)
+						},
+					}
+				}
+			}
+		},
+	)
+}
@@ -0,0 +1,56 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn send_sync() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(unsafe(Send, Sync); T: Send)]
+			struct Test<T>(*const T);
+		},
+		quote! {
+			#[automatically_derived]
+			unsafe impl<T> ::core::marker::Send for Test<T>
+			where T: Send
+			{ }
+
+			#[automatically_derived]
+			unsafe impl<T> ::core::marker::Sync for Test<T>
+			where T: Send
+			{ }
+		},
+	)
+}
+
+#[test]
+fn bound() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Clone, unsafe(Send); T)]
+			struct Test<T, U>(*const T, PhantomData<U>);
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T, U> ::core::clone::Clone for Test<T, U>
+			where T: ::core::clone::Clone
+			{
+				#[inline]
+				fn clone(&self) -> Self {
+					match self {
+						Test(ref __field_0, ref __field_1) => Test {
+							0: ::core::clone::Clone::clone(__field_0),
+							1: ::core::clone::Clone::clone(__field_1)
+						},
+					}
+				}
+			}
+
+			#[automatically_derived]
+			unsafe impl<T, U> ::core::marker::Send for Test<T, U>
+			where T: ::core::marker::Send
+			{ }
+		},
+	)
+}
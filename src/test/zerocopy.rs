@@ -0,0 +1,102 @@
+use quote::quote;
+use syn::Result;
+
+use super::test_derive;
+
+#[test]
+fn bound() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(FromBytes; T)]
+			#[repr(C)]
+			struct Test<T, E>(T, std::marker::PhantomData<E>);
+		},
+		quote! {
+			#[::core::prelude::v1::derive(::zerocopy::FromBytes)]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(FromBytes; T)]
+			#[repr(C)]
+			struct Test<T, E>(T, std::marker::PhantomData<E>)
+			where
+				T: ::zerocopy::FromBytes;
+		},
+	)
+}
+
+#[test]
+fn where_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(IntoBytes, KnownLayout, Immutable)]
+			#[repr(transparent)]
+			struct Test<T, E>
+			where
+				T: Copy,
+			{
+				a: u32,
+				b: std::marker::PhantomData<(T, E)>,
+			}
+		},
+		quote! {
+			#[::core::prelude::v1::derive(::zerocopy::IntoBytes)]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(IntoBytes, KnownLayout, Immutable)]
+			#[repr(transparent)]
+			struct Test<T, E>
+			where
+				T: Copy,
+			{
+				a: u32,
+				b: std::marker::PhantomData<(T, E)>,
+			}
+
+			#[::core::prelude::v1::derive(::zerocopy::KnownLayout)]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(IntoBytes, KnownLayout, Immutable)]
+			#[repr(transparent)]
+			struct Test<T, E>
+			where
+				T: Copy,
+			{
+				a: u32,
+				b: std::marker::PhantomData<(T, E)>,
+			}
+
+			#[::core::prelude::v1::derive(::zerocopy::Immutable)]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(IntoBytes, KnownLayout, Immutable)]
+			#[repr(transparent)]
+			struct Test<T, E>
+			where
+				T: Copy,
+			{
+				a: u32,
+				b: std::marker::PhantomData<(T, E)>,
+			}
+		},
+	)
+}
+
+#[test]
+fn enum_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(FromZeros)]
+			#[repr(u8)]
+			enum Test<E> {
+				A(std::marker::PhantomData<E>),
+				B,
+			}
+		},
+		quote! {
+			#[::core::prelude::v1::derive(::zerocopy::FromZeros)]
+			#[::derive_where::derive_where_serde]
+			#[derive_where(FromZeros)]
+			#[repr(u8)]
+			enum Test<E> {
+				A(std::marker::PhantomData<E>),
+				B,
+			}
+		},
+	)
+}
@@ -276,6 +276,246 @@ fn fqs() -> Result<()> {
 	)
 }
 
+#[test]
+fn with() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Zeroize)]
+			struct Test<T>(#[derive_where(Zeroize(with = wipe))] std::marker::PhantomData<T>);
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::zeroize::Zeroize for Test<T> {
+				fn zeroize(&mut self) {
+					use ::zeroize::Zeroize;
+
+					match self {
+						Test(ref mut __field_0) => {
+							wipe(__field_0);
+						}
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+#[cfg(feature = "zeroize-on-drop")]
+fn with_drop() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ZeroizeOnDrop)]
+			struct Test<T>(u8, #[derive_where(Zeroize(with = wipe))] std::marker::PhantomData<T>);
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T> ::core::ops::Drop for Test<T> {
+				fn drop(&mut self) {
+					trait AssertZeroizeOnDrop {
+						fn __derive_where_zeroize_or_on_drop(self);
+					}
+
+					impl<T: ::zeroize::ZeroizeOnDrop + ?::core::marker::Sized> AssertZeroizeOnDrop for &&mut T {
+						fn __derive_where_zeroize_or_on_drop(self) {}
+					}
+
+					trait AssertZeroize {
+						fn __derive_where_zeroize_or_on_drop(&mut self);
+					}
+
+					impl<T: ::zeroize::Zeroize + ?::core::marker::Sized> AssertZeroize for T {
+						fn __derive_where_zeroize_or_on_drop(&mut self) {
+							::zeroize::Zeroize::zeroize(self);
+						}
+					}
+
+					match self {
+						Test(ref mut __field_0, ref mut __field_1) => {
+							__field_0.__derive_where_zeroize_or_on_drop();
+							wipe(__field_1);
+						}
+					}
+				}
+			}
+
+			#[automatically_derived]
+			impl<T> ::zeroize::ZeroizeOnDrop for Test<T> { }
+		},
+	)
+}
+
+#[test]
+fn on_drop() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ZeroizeOnDrop(on_drop = release))]
+			struct Test<T>(std::marker::PhantomData<T>);
+		},
+		#[cfg(not(feature = "zeroize-on-drop"))]
+		quote! {
+			#[automatically_derived]
+			impl<T> ::core::ops::Drop for Test<T> {
+				fn drop(&mut self) {
+					release(self);
+					::zeroize::Zeroize::zeroize(self);
+				}
+			}
+		},
+		#[cfg(feature = "zeroize-on-drop")]
+		quote! {
+			#[automatically_derived]
+			impl<T> ::core::ops::Drop for Test<T> {
+				fn drop(&mut self) {
+					trait AssertZeroizeOnDrop {
+						fn __derive_where_zeroize_or_on_drop(self);
+					}
+
+					impl<T: ::zeroize::ZeroizeOnDrop + ?::core::marker::Sized> AssertZeroizeOnDrop for &&mut T {
+						fn __derive_where_zeroize_or_on_drop(self) {}
+					}
+
+					trait AssertZeroize {
+						fn __derive_where_zeroize_or_on_drop(&mut self);
+					}
+
+					impl<T: ::zeroize::Zeroize + ?::core::marker::Sized> AssertZeroize for T {
+						fn __derive_where_zeroize_or_on_drop(&mut self) {
+							::zeroize::Zeroize::zeroize(self);
+						}
+					}
+
+					release(self);
+
+					match self {
+						Test(ref mut __field_0) => {
+							__field_0.__derive_where_zeroize_or_on_drop();
+						}
+					}
+				}
+			}
+
+			#[automatically_derived]
+			impl<T> ::zeroize::ZeroizeOnDrop for Test<T> { }
+		},
+	)
+}
+
+#[test]
+fn order() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Zeroize)]
+			struct Test<T>(
+				#[derive_where(Zeroize(order = 1))] std::marker::PhantomData<T>,
+				std::marker::PhantomData<T>,
+				#[derive_where(Zeroize(order = -1, fqs))] std::marker::PhantomData<T>,
+			);
+		},
+		quote! {
+			#[automatically_derived]
+			impl <T> ::zeroize::Zeroize for Test<T> {
+				fn zeroize(&mut self) {
+					use ::zeroize::Zeroize;
+
+					match self {
+						Test(ref mut __field_0, ref mut __field_1, ref mut __field_2) => {
+							::zeroize::Zeroize::zeroize(__field_2);
+							__field_1.zeroize();
+							__field_0.zeroize();
+						}
+					}
+				}
+			}
+		},
+	)
+}
+
+#[test]
+#[cfg(not(feature = "safe"))]
+fn zeroize_to() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ZeroizeOnDrop)]
+			enum Test<T> {
+				A(std::marker::PhantomData<T>),
+				B,
+				#[derive_where(zeroize_to)]
+				C,
+			}
+		},
+		#[cfg(not(feature = "zeroize-on-drop"))]
+		quote! {
+			#[automatically_derived]
+			impl<T> ::core::ops::Drop for Test<T> {
+				fn drop(&mut self) {
+					::zeroize::Zeroize::zeroize(self);
+
+					match self {
+						Test::A(ref mut __field_0) => {
+							let __fields = unsafe { (::core::ptr::read(__field_0),) };
+							unsafe { ::core::ptr::write_volatile(self, Test::C) };
+							::core::mem::drop(__fields);
+						}
+						Test::B => unsafe { ::core::ptr::write_volatile(self, Test::C) },
+						Test::C => unsafe { ::core::ptr::write_volatile(self, Test::C) },
+					}
+
+					::core::sync::atomic::compiler_fence(::core::sync::atomic::Ordering::SeqCst);
+				}
+			}
+		},
+		#[cfg(feature = "zeroize-on-drop")]
+		quote! {
+			#[automatically_derived]
+			impl <T> ::core::ops::Drop for Test<T> {
+				fn drop(&mut self) {
+					trait AssertZeroizeOnDrop {
+						fn __derive_where_zeroize_or_on_drop(self);
+					}
+
+					impl<T: ::zeroize::ZeroizeOnDrop + ?::core::marker::Sized> AssertZeroizeOnDrop for &&mut T {
+						fn __derive_where_zeroize_or_on_drop(self) {}
+					}
+
+					trait AssertZeroize {
+						fn __derive_where_zeroize_or_on_drop(&mut self);
+					}
+
+					impl<T: ::zeroize::Zeroize + ?::core::marker::Sized> AssertZeroize for T {
+						fn __derive_where_zeroize_or_on_drop(&mut self) {
+							::zeroize::Zeroize::zeroize(self);
+						}
+					}
+
+					match self {
+						Test::A(ref mut __field_0) => {
+							__field_0.__derive_where_zeroize_or_on_drop();
+						}
+						Test::B => { }
+						Test::C => { }
+					}
+
+					match self {
+						Test::A(ref mut __field_0) => {
+							let __fields = unsafe { (::core::ptr::read(__field_0),) };
+							unsafe { ::core::ptr::write_volatile(self, Test::C) };
+							::core::mem::drop(__fields);
+						}
+						Test::B => unsafe { ::core::ptr::write_volatile(self, Test::C) },
+						Test::C => unsafe { ::core::ptr::write_volatile(self, Test::C) },
+					}
+
+					::core::sync::atomic::compiler_fence(::core::sync::atomic::Ordering::SeqCst);
+				}
+			}
+
+			#[automatically_derived]
+			impl<T> ::zeroize::ZeroizeOnDrop for Test<T> { }
+		},
+	)
+}
+
 #[test]
 fn enum_skip() -> Result<()> {
 	test_derive(
@@ -406,3 +646,85 @@ fn no_drop() -> Result<()> {
 		},
 	)
 }
+
+#[test]
+fn union_() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(Zeroize(union))]
+			union Test<T: Copy> {
+				a: u8,
+				b: T,
+			}
+		},
+		quote! {
+			#[automatically_derived]
+			impl<T: Copy> ::zeroize::Zeroize for Test<T> {
+				fn zeroize(&mut self) {
+					struct __AssertCopy<__T: ::core::marker::Copy + ?::core::marker::Sized>(::core::marker::PhantomData<__T>);
+					let _: __AssertCopy<u8>;
+					let _: __AssertCopy<T>;
+
+					let __ptr = (self as *mut Self).cast::<::core::primitive::u8>();
+
+					for __index in 0..::core::mem::size_of::<Self>() {
+						unsafe { ::core::ptr::write_volatile(__ptr.add(__index), 0) };
+					}
+
+					::core::sync::atomic::compiler_fence(::core::sync::atomic::Ordering::SeqCst);
+				}
+			}
+		},
+	)
+}
+
+#[test]
+fn union_drop() -> Result<()> {
+	test_derive(
+		quote! {
+			#[derive_where(ZeroizeOnDrop(union))]
+			union Test {
+				a: u8,
+			}
+		},
+		#[cfg(not(feature = "zeroize-on-drop"))]
+		quote! {
+			#[automatically_derived]
+			impl ::core::ops::Drop for Test {
+				fn drop(&mut self) {
+					struct __AssertCopy<__T: ::core::marker::Copy + ?::core::marker::Sized>(::core::marker::PhantomData<__T>);
+					let _: __AssertCopy<u8>;
+
+					let __ptr = (self as *mut Self).cast::<::core::primitive::u8>();
+
+					for __index in 0..::core::mem::size_of::<Self>() {
+						unsafe { ::core::ptr::write_volatile(__ptr.add(__index), 0) };
+					}
+
+					::core::sync::atomic::compiler_fence(::core::sync::atomic::Ordering::SeqCst);
+				}
+			}
+		},
+		#[cfg(feature = "zeroize-on-drop")]
+		quote! {
+			#[automatically_derived]
+			impl ::core::ops::Drop for Test {
+				fn drop(&mut self) {
+					struct __AssertCopy<__T: ::core::marker::Copy + ?::core::marker::Sized>(::core::marker::PhantomData<__T>);
+					let _: __AssertCopy<u8>;
+
+					let __ptr = (self as *mut Self).cast::<::core::primitive::u8>();
+
+					for __index in 0..::core::mem::size_of::<Self>() {
+						unsafe { ::core::ptr::write_volatile(__ptr.add(__index), 0) };
+					}
+
+					::core::sync::atomic::compiler_fence(::core::sync::atomic::Ordering::SeqCst);
+				}
+			}
+
+			#[automatically_derived]
+			impl ::zeroize::ZeroizeOnDrop for Test { }
+		},
+	)
+}
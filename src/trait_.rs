@@ -1,21 +1,67 @@
 //! Individual implementation for all traits.
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "rkyv")]
+pub mod archive;
+pub mod assert;
+#[cfg(feature = "borsh")]
+pub mod borsh_deserialize;
+#[cfg(feature = "borsh")]
+pub mod borsh_serialize;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
 pub mod clone;
 mod common_ord;
+#[cfg(feature = "subtle")]
+pub mod conditionally_selectable;
+pub mod const_default;
+#[cfg(feature = "subtle")]
+pub mod constant_time_eq;
 pub mod copy;
 pub mod debug;
 pub mod default;
 #[cfg(feature = "serde")]
 pub mod deserialize;
 pub mod eq;
+#[cfg(feature = "defmt")]
+pub mod format;
+#[cfg(feature = "zerocopy")]
+pub mod from_bytes;
+#[cfg(feature = "zerocopy")]
+pub mod from_zeros;
 pub mod hash;
+#[cfg(feature = "zerocopy")]
+pub mod immutable;
+#[cfg(feature = "zerocopy")]
+pub mod into_bytes;
+#[cfg(feature = "schemars")]
+pub mod json_schema;
+#[cfg(feature = "zerocopy")]
+pub mod known_layout;
 pub mod ord;
 pub mod partial_eq;
 pub mod partial_ord;
+#[cfg(feature = "bytemuck")]
+pub mod pod;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "rkyv")]
+pub mod rkyv_deserialize;
+#[cfg(feature = "rkyv")]
+pub mod rkyv_serialize;
+pub mod send;
 #[cfg(feature = "serde")]
 mod serde;
 #[cfg(feature = "serde")]
 pub mod serialize;
+pub mod sync;
+#[cfg(feature = "bytemuck")]
+pub mod zeroable;
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
 #[cfg(feature = "zeroize")]
 pub mod zeroize;
 #[cfg(feature = "zeroize")]
@@ -30,7 +76,7 @@ use syn::{
 	punctuated::Punctuated,
 	spanned::Spanned,
 	Attribute, DeriveInput, Ident, ImplGenerics, Meta, Path, Result, Token, TraitBound,
-	TraitBoundModifier, TypeGenerics, TypeParamBound, WhereClause,
+	TraitBoundModifier, TypeGenerics, TypeParamBound, WhereClause, WherePredicate,
 };
 
 use crate::{util::MetaListExt, Data, DeriveWhere, Error, Item, SplitGenerics};
@@ -39,8 +85,30 @@ use crate::{util::MetaListExt, Data, DeriveWhere, Error, Item, SplitGenerics};
 #[derive(Clone, Copy, Eq, PartialEq)]
 #[cfg_attr(test, derive(Debug))]
 pub enum Trait {
+	/// [`Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html).
+	#[cfg(feature = "arbitrary")]
+	Arbitrary,
+	/// [`Archive`](https://docs.rs/rkyv/latest/rkyv/trait.Archive.html).
+	#[cfg(feature = "rkyv")]
+	Archive,
+	/// Compile-time assertion of bounds.
+	Assert,
+	/// [`BorshDeserialize`](https://docs.rs/borsh/latest/borsh/de/trait.BorshDeserialize.html).
+	#[cfg(feature = "borsh")]
+	BorshDeserialize,
+	/// [`BorshSerialize`](https://docs.rs/borsh/latest/borsh/ser/trait.BorshSerialize.html).
+	#[cfg(feature = "borsh")]
+	BorshSerialize,
 	/// [`Clone`].
 	Clone,
+	/// [`ConditionallySelectable`](https://docs.rs/subtle/latest/subtle/trait.ConditionallySelectable.html).
+	#[cfg(feature = "subtle")]
+	ConditionallySelectable,
+	/// Associated `const DEFAULT`.
+	ConstDefault,
+	/// [`ConstantTimeEq`](https://docs.rs/subtle/latest/subtle/trait.ConstantTimeEq.html).
+	#[cfg(feature = "subtle")]
+	ConstantTimeEq,
 	/// [`Copy`].
 	Copy,
 	/// [`Debug`](std::fmt::Debug).
@@ -52,17 +120,60 @@ pub enum Trait {
 	Deserialize,
 	/// [`Eq`].
 	Eq,
+	/// [`Format`](https://docs.rs/defmt/latest/defmt/trait.Format.html).
+	#[cfg(feature = "defmt")]
+	Format,
+	/// [`FromBytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.FromBytes.html).
+	#[cfg(feature = "zerocopy")]
+	FromBytes,
+	/// [`FromZeros`](https://docs.rs/zerocopy/latest/zerocopy/trait.FromZeros.html).
+	#[cfg(feature = "zerocopy")]
+	FromZeros,
 	/// [`Hash`](std::hash::Hash).
 	Hash,
+	/// [`Immutable`](https://docs.rs/zerocopy/latest/zerocopy/trait.Immutable.html).
+	#[cfg(feature = "zerocopy")]
+	Immutable,
+	/// [`IntoBytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html).
+	#[cfg(feature = "zerocopy")]
+	IntoBytes,
+	/// [`JsonSchema`](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html).
+	#[cfg(feature = "schemars")]
+	JsonSchema,
+	/// [`KnownLayout`](https://docs.rs/zerocopy/latest/zerocopy/trait.KnownLayout.html).
+	#[cfg(feature = "zerocopy")]
+	KnownLayout,
 	/// [`Ord`].
 	Ord,
 	/// [`PartialEq`].
 	PartialEq,
 	/// [`PartialOrd`].
 	PartialOrd,
+	/// [`Pod`](https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html).
+	#[cfg(feature = "bytemuck")]
+	Pod,
+	/// [`Arbitrary`](https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html)
+	/// from `proptest`.
+	#[cfg(feature = "proptest")]
+	ProptestArbitrary,
+	/// [`Deserialize`](https://docs.rs/rkyv/latest/rkyv/trait.Deserialize.html)
+	/// from `rkyv`.
+	#[cfg(feature = "rkyv")]
+	RkyvDeserialize,
+	/// [`Serialize`](https://docs.rs/rkyv/latest/rkyv/trait.Serialize.html)
+	/// from `rkyv`.
+	#[cfg(feature = "rkyv")]
+	RkyvSerialize,
+	/// [`Send`].
+	Send,
 	/// [`Serialize`](https://docs.rs/serde/latest/serde/derive.Serialize.html).
 	#[cfg(feature = "serde")]
 	Serialize,
+	/// [`Sync`].
+	Sync,
+	/// [`Zeroable`](https://docs.rs/bytemuck/latest/bytemuck/trait.Zeroable.html).
+	#[cfg(feature = "bytemuck")]
+	Zeroable,
 	/// [`Zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html).
 	#[cfg(feature = "zeroize")]
 	Zeroize,
@@ -76,19 +187,59 @@ pub enum Trait {
 macro_rules! trait_dispatch {
 	($self:expr, $method:ident($($par:expr),*)) => {
 		match $self {
+			#[cfg(feature = "arbitrary")]
+			Trait::Arbitrary => arbitrary::Arbitrary::$method($($par),*),
+			#[cfg(feature = "rkyv")]
+			Trait::Archive => archive::Archive::$method($($par),*),
+			Trait::Assert => assert::Assert::$method($($par),*),
+			#[cfg(feature = "borsh")]
+			Trait::BorshDeserialize => borsh_deserialize::BorshDeserialize::$method($($par),*),
+			#[cfg(feature = "borsh")]
+			Trait::BorshSerialize => borsh_serialize::BorshSerialize::$method($($par),*),
 			Trait::Clone => clone::Clone::$method($($par),*),
+			#[cfg(feature = "subtle")]
+			Trait::ConditionallySelectable => conditionally_selectable::ConditionallySelectable::$method($($par),*),
+			Trait::ConstDefault => const_default::ConstDefault::$method($($par),*),
+			#[cfg(feature = "subtle")]
+			Trait::ConstantTimeEq => constant_time_eq::ConstantTimeEq::$method($($par),*),
 			Trait::Copy => copy::Copy::$method($($par),*),
 			Trait::Debug => debug::Debug::$method($($par),*),
 			Trait::Default => default::Default::$method($($par),*),
 			#[cfg(feature = "serde")]
 			Trait::Deserialize => deserialize::Deserialize::$method($($par),*),
 			Trait::Eq => eq::Eq::$method($($par),*),
+			#[cfg(feature = "defmt")]
+			Trait::Format => format::Format::$method($($par),*),
+			#[cfg(feature = "zerocopy")]
+			Trait::FromBytes => from_bytes::FromBytes::$method($($par),*),
+			#[cfg(feature = "zerocopy")]
+			Trait::FromZeros => from_zeros::FromZeros::$method($($par),*),
 			Trait::Hash => hash::Hash::$method($($par),*),
+			#[cfg(feature = "zerocopy")]
+			Trait::Immutable => immutable::Immutable::$method($($par),*),
+			#[cfg(feature = "zerocopy")]
+			Trait::IntoBytes => into_bytes::IntoBytes::$method($($par),*),
+			#[cfg(feature = "schemars")]
+			Trait::JsonSchema => json_schema::JsonSchema::$method($($par),*),
+			#[cfg(feature = "zerocopy")]
+			Trait::KnownLayout => known_layout::KnownLayout::$method($($par),*),
 			Trait::Ord => ord::Ord::$method($($par),*),
 			Trait::PartialEq => partial_eq::PartialEq::$method($($par),*),
 			Trait::PartialOrd => partial_ord::PartialOrd::$method($($par),*),
+			#[cfg(feature = "bytemuck")]
+			Trait::Pod => pod::Pod::$method($($par),*),
+			#[cfg(feature = "proptest")]
+			Trait::ProptestArbitrary => proptest::ProptestArbitrary::$method($($par),*),
+			#[cfg(feature = "rkyv")]
+			Trait::RkyvDeserialize => rkyv_deserialize::RkyvDeserialize::$method($($par),*),
+			#[cfg(feature = "rkyv")]
+			Trait::RkyvSerialize => rkyv_serialize::RkyvSerialize::$method($($par),*),
+			Trait::Send => send::Send::$method($($par),*),
 			#[cfg(feature = "serde")]
 			Trait::Serialize => serialize::Serialize::$method($($par),*),
+			Trait::Sync => sync::Sync::$method($($par),*),
+			#[cfg(feature = "bytemuck")]
+			Trait::Zeroable => zeroable::Zeroable::$method($($par),*),
 			#[cfg(feature = "zeroize")]
 			Trait::Zeroize => zeroize::Zeroize::$method($($par),*),
 			#[cfg(feature = "zeroize")]
@@ -104,7 +255,33 @@ impl Trait {
 			use Trait::*;
 
 			match ident.to_string().as_str() {
+				#[cfg(feature = "arbitrary")]
+				"Arbitrary" => Ok(Arbitrary),
+				#[cfg(not(feature = "arbitrary"))]
+				"Arbitrary" => Err(Error::arbitrary_feature(path.span())),
+				#[cfg(feature = "rkyv")]
+				"Archive" => Ok(Archive),
+				#[cfg(not(feature = "rkyv"))]
+				"Archive" => Err(Error::rkyv_feature(path.span())),
+				"assert" => Ok(Assert),
+				#[cfg(feature = "borsh")]
+				"BorshDeserialize" => Ok(BorshDeserialize),
+				#[cfg(not(feature = "borsh"))]
+				"BorshDeserialize" => Err(Error::borsh_feature(path.span())),
+				#[cfg(feature = "borsh")]
+				"BorshSerialize" => Ok(BorshSerialize),
+				#[cfg(not(feature = "borsh"))]
+				"BorshSerialize" => Err(Error::borsh_feature(path.span())),
 				"Clone" => Ok(Clone),
+				#[cfg(feature = "subtle")]
+				"ConditionallySelectable" => Ok(ConditionallySelectable),
+				#[cfg(not(feature = "subtle"))]
+				"ConditionallySelectable" => Err(Error::subtle_feature(path.span())),
+				"ConstDefault" => Ok(ConstDefault),
+				#[cfg(feature = "subtle")]
+				"ConstantTimeEq" => Ok(ConstantTimeEq),
+				#[cfg(not(feature = "subtle"))]
+				"ConstantTimeEq" => Err(Error::subtle_feature(path.span())),
 				"Copy" => Ok(Copy),
 				"Debug" => Ok(Debug),
 				"Default" => Ok(Default),
@@ -113,14 +290,64 @@ impl Trait {
 				#[cfg(not(feature = "serde"))]
 				"Deserialize" => Err(Error::serde_feature(path.span())),
 				"Eq" => Ok(Eq),
+				#[cfg(feature = "defmt")]
+				"Format" => Ok(Format),
+				#[cfg(not(feature = "defmt"))]
+				"Format" => Err(Error::defmt_feature(path.span())),
+				#[cfg(feature = "zerocopy")]
+				"FromBytes" => Ok(FromBytes),
+				#[cfg(not(feature = "zerocopy"))]
+				"FromBytes" => Err(Error::zerocopy_feature(path.span())),
+				#[cfg(feature = "zerocopy")]
+				"FromZeros" => Ok(FromZeros),
+				#[cfg(not(feature = "zerocopy"))]
+				"FromZeros" => Err(Error::zerocopy_feature(path.span())),
 				"Hash" => Ok(Hash),
+				#[cfg(feature = "zerocopy")]
+				"Immutable" => Ok(Immutable),
+				#[cfg(not(feature = "zerocopy"))]
+				"Immutable" => Err(Error::zerocopy_feature(path.span())),
+				#[cfg(feature = "zerocopy")]
+				"IntoBytes" => Ok(IntoBytes),
+				#[cfg(not(feature = "zerocopy"))]
+				"IntoBytes" => Err(Error::zerocopy_feature(path.span())),
+				#[cfg(feature = "schemars")]
+				"JsonSchema" => Ok(JsonSchema),
+				#[cfg(not(feature = "schemars"))]
+				"JsonSchema" => Err(Error::schemars_feature(path.span())),
+				#[cfg(feature = "zerocopy")]
+				"KnownLayout" => Ok(KnownLayout),
+				#[cfg(not(feature = "zerocopy"))]
+				"KnownLayout" => Err(Error::zerocopy_feature(path.span())),
 				"Ord" => Ok(Ord),
 				"PartialEq" => Ok(PartialEq),
 				"PartialOrd" => Ok(PartialOrd),
+				#[cfg(feature = "bytemuck")]
+				"Pod" => Ok(Pod),
+				#[cfg(not(feature = "bytemuck"))]
+				"Pod" => Err(Error::bytemuck_feature(path.span())),
+				#[cfg(feature = "proptest")]
+				"ProptestArbitrary" => Ok(ProptestArbitrary),
+				#[cfg(not(feature = "proptest"))]
+				"ProptestArbitrary" => Err(Error::proptest_feature(path.span())),
+				#[cfg(feature = "rkyv")]
+				"RkyvDeserialize" => Ok(RkyvDeserialize),
+				#[cfg(not(feature = "rkyv"))]
+				"RkyvDeserialize" => Err(Error::rkyv_feature(path.span())),
+				#[cfg(feature = "rkyv")]
+				"RkyvSerialize" => Ok(RkyvSerialize),
+				#[cfg(not(feature = "rkyv"))]
+				"RkyvSerialize" => Err(Error::rkyv_feature(path.span())),
+				"Send" => Ok(Send),
 				#[cfg(feature = "serde")]
 				"Serialize" => Ok(Serialize),
 				#[cfg(not(feature = "serde"))]
 				"Serialize" => Err(Error::serde_feature(path.span())),
+				"Sync" => Ok(Sync),
+				#[cfg(feature = "bytemuck")]
+				"Zeroable" => Ok(Zeroable),
+				#[cfg(not(feature = "bytemuck"))]
+				"Zeroable" => Err(Error::bytemuck_feature(path.span())),
 				#[cfg(feature = "zeroize")]
 				"Zeroize" => Ok(Zeroize),
 				#[cfg(not(feature = "zeroize"))]
@@ -162,39 +389,123 @@ impl Trait {
 		trait_dispatch!(self, supports_union())
 	}
 
+	/// Re-direct to [`TraitImpl::supports_enum()`].
+	pub fn supports_enum(&self) -> bool {
+		trait_dispatch!(self, supports_enum())
+	}
+
+	/// Re-direct to [`TraitImpl::is_unsafe()`].
+	pub fn is_unsafe(&self) -> bool {
+		trait_dispatch!(self, is_unsafe())
+	}
+
+	/// Re-direct to [`TraitImpl::lifetime()`].
+	pub fn lifetime(&self) -> Option<&'static str> {
+		trait_dispatch!(self, lifetime())
+	}
+
 	/// Re-direct to [`TraitImpl::additional_where_bounds()`].
-	pub fn additional_where_bounds(&self, data: &Item) -> Option<TypeParamBound> {
-		trait_dispatch!(self, additional_where_bounds(data))
+	pub fn additional_where_bounds(
+		&self,
+		derive_where: &DeriveWhere,
+		data: &Item,
+	) -> Option<TypeParamBound> {
+		trait_dispatch!(self, additional_where_bounds(derive_where, data))
 	}
 }
 
 /// Trait to implement.
 #[derive(Eq, PartialEq)]
 pub enum DeriveTrait {
+	/// [`Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html).
+	#[cfg(feature = "arbitrary")]
+	Arbitrary,
+	/// [`Archive`](https://docs.rs/rkyv/latest/rkyv/trait.Archive.html).
+	#[cfg(feature = "rkyv")]
+	Archive(archive::Archive),
+	/// Compile-time assertion of bounds.
+	Assert(assert::Assert),
+	/// [`BorshDeserialize`](https://docs.rs/borsh/latest/borsh/de/trait.BorshDeserialize.html).
+	#[cfg(feature = "borsh")]
+	BorshDeserialize,
+	/// [`BorshSerialize`](https://docs.rs/borsh/latest/borsh/ser/trait.BorshSerialize.html).
+	#[cfg(feature = "borsh")]
+	BorshSerialize,
 	/// [`Clone`].
-	Clone,
+	Clone(clone::Clone),
+	/// [`ConditionallySelectable`](https://docs.rs/subtle/latest/subtle/trait.ConditionallySelectable.html).
+	#[cfg(feature = "subtle")]
+	ConditionallySelectable(conditionally_selectable::ConditionallySelectable),
+	/// Associated `const DEFAULT`.
+	ConstDefault,
+	/// [`ConstantTimeEq`](https://docs.rs/subtle/latest/subtle/trait.ConstantTimeEq.html).
+	#[cfg(feature = "subtle")]
+	ConstantTimeEq(constant_time_eq::ConstantTimeEq),
 	/// [`Copy`].
 	Copy,
 	/// [`Debug`](std::fmt::Debug).
 	Debug,
 	/// [`Default`].
-	Default,
+	Default(default::Default),
 	/// [`Deserialize`](https://docs.rs/serde/latest/serde/derive.Deserialize.html).
 	#[cfg(feature = "serde")]
 	Deserialize(deserialize::Deserialize),
 	/// [`Eq`].
 	Eq,
+	/// [`Format`](https://docs.rs/defmt/latest/defmt/trait.Format.html).
+	#[cfg(feature = "defmt")]
+	Format,
+	/// [`FromBytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.FromBytes.html).
+	#[cfg(feature = "zerocopy")]
+	FromBytes,
+	/// [`FromZeros`](https://docs.rs/zerocopy/latest/zerocopy/trait.FromZeros.html).
+	#[cfg(feature = "zerocopy")]
+	FromZeros,
 	/// [`Hash`](std::hash::Hash).
 	Hash,
+	/// [`Immutable`](https://docs.rs/zerocopy/latest/zerocopy/trait.Immutable.html).
+	#[cfg(feature = "zerocopy")]
+	Immutable,
+	/// [`IntoBytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html).
+	#[cfg(feature = "zerocopy")]
+	IntoBytes,
+	/// [`JsonSchema`](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html).
+	#[cfg(feature = "schemars")]
+	JsonSchema(json_schema::JsonSchema),
+	/// [`KnownLayout`](https://docs.rs/zerocopy/latest/zerocopy/trait.KnownLayout.html).
+	#[cfg(feature = "zerocopy")]
+	KnownLayout,
 	/// [`Ord`].
 	Ord,
 	/// [`PartialEq`].
-	PartialEq,
+	PartialEq(partial_eq::PartialEq),
 	/// [`PartialOrd`].
 	PartialOrd,
+	/// [`Pod`](https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html).
+	#[cfg(feature = "bytemuck")]
+	Pod,
+	/// [`Arbitrary`](https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html)
+	/// from `proptest`.
+	#[cfg(feature = "proptest")]
+	ProptestArbitrary,
+	/// [`Deserialize`](https://docs.rs/rkyv/latest/rkyv/trait.Deserialize.html)
+	/// from `rkyv`.
+	#[cfg(feature = "rkyv")]
+	RkyvDeserialize(rkyv_deserialize::RkyvDeserialize),
+	/// [`Serialize`](https://docs.rs/rkyv/latest/rkyv/trait.Serialize.html)
+	/// from `rkyv`.
+	#[cfg(feature = "rkyv")]
+	RkyvSerialize(rkyv_serialize::RkyvSerialize),
+	/// [`Send`].
+	Send,
 	/// [`Serialize`](https://docs.rs/serde/latest/serde/derive.Serialize.html).
 	#[cfg(feature = "serde")]
 	Serialize(serialize::Serialize),
+	/// [`Sync`].
+	Sync,
+	/// [`Zeroable`](https://docs.rs/bytemuck/latest/bytemuck/trait.Zeroable.html).
+	#[cfg(feature = "bytemuck")]
+	Zeroable,
 	/// [`Zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html).
 	#[cfg(feature = "zeroize")]
 	Zeroize(zeroize::Zeroize),
@@ -210,19 +521,59 @@ impl Deref for DeriveTrait {
 		use DeriveTrait::*;
 
 		match self {
-			Clone => &clone::Clone,
+			#[cfg(feature = "arbitrary")]
+			Arbitrary => &arbitrary::Arbitrary,
+			#[cfg(feature = "rkyv")]
+			Archive(trait_) => trait_,
+			Assert(trait_) => trait_,
+			#[cfg(feature = "borsh")]
+			BorshDeserialize => &borsh_deserialize::BorshDeserialize,
+			#[cfg(feature = "borsh")]
+			BorshSerialize => &borsh_serialize::BorshSerialize,
+			Clone(trait_) => trait_,
+			#[cfg(feature = "subtle")]
+			ConditionallySelectable(trait_) => trait_,
+			ConstDefault => &const_default::ConstDefault,
+			#[cfg(feature = "subtle")]
+			ConstantTimeEq(trait_) => trait_,
 			Copy => &copy::Copy,
 			Debug => &debug::Debug,
-			Default => &default::Default,
+			Default(trait_) => trait_,
 			#[cfg(feature = "serde")]
 			Deserialize(trait_) => trait_,
 			Eq => &eq::Eq,
+			#[cfg(feature = "defmt")]
+			Format => &format::Format,
+			#[cfg(feature = "zerocopy")]
+			FromBytes => &from_bytes::FromBytes,
+			#[cfg(feature = "zerocopy")]
+			FromZeros => &from_zeros::FromZeros,
 			Hash => &hash::Hash,
+			#[cfg(feature = "zerocopy")]
+			Immutable => &immutable::Immutable,
+			#[cfg(feature = "zerocopy")]
+			IntoBytes => &into_bytes::IntoBytes,
+			#[cfg(feature = "schemars")]
+			JsonSchema(trait_) => trait_,
+			#[cfg(feature = "zerocopy")]
+			KnownLayout => &known_layout::KnownLayout,
 			Ord => &ord::Ord,
-			PartialEq => &partial_eq::PartialEq,
+			PartialEq(trait_) => trait_,
 			PartialOrd => &partial_ord::PartialOrd,
+			#[cfg(feature = "bytemuck")]
+			Pod => &pod::Pod,
+			#[cfg(feature = "proptest")]
+			ProptestArbitrary => &proptest::ProptestArbitrary,
+			#[cfg(feature = "rkyv")]
+			RkyvDeserialize(trait_) => trait_,
+			#[cfg(feature = "rkyv")]
+			RkyvSerialize(trait_) => trait_,
+			Send => &send::Send,
 			#[cfg(feature = "serde")]
 			Serialize(trait_) => trait_,
+			Sync => &sync::Sync,
+			#[cfg(feature = "bytemuck")]
+			Zeroable => &zeroable::Zeroable,
 			#[cfg(feature = "zeroize")]
 			Zeroize(trait_) => trait_,
 			#[cfg(feature = "zeroize")]
@@ -240,61 +591,191 @@ impl PartialEq<Trait> for &DeriveTrait {
 
 impl DeriveTrait {
 	/// Returns where-clause bounds for the trait in respect of the item type.
-	pub fn where_bounds(&self, data: &Item) -> Punctuated<TypeParamBound, Token![+]> {
+	pub fn where_bounds(
+		&self,
+		derive_where: &DeriveWhere,
+		data: &Item,
+	) -> Punctuated<TypeParamBound, Token![+]> {
+		match self {
+			// Assertions bind generics to the asserted bounds.
+			DeriveTrait::Assert(assert) => return assert.bounds.clone(),
+			// There is no trait to bind generics to.
+			DeriveTrait::ConstDefault => return Punctuated::new(),
+			_ => (),
+		}
+
 		let mut list = Punctuated::new();
 
-		list.push(TypeParamBound::Trait(TraitBound {
-			paren_token: None,
-			modifier: TraitBoundModifier::None,
-			lifetimes: None,
-			path: self.path(),
-		}));
+		// `const` implementations require `const` bounds.
+		if self.is_const() {
+			let path = self.path();
+			list.push(TypeParamBound::Verbatim(quote! { [const] #path }));
+		} else {
+			list.push(TypeParamBound::Trait(TraitBound {
+				paren_token: None,
+				modifier: TraitBoundModifier::None,
+				lifetimes: None,
+				path: self.path(),
+			}));
+		}
 
 		// Add bounds specific to the trait.
-		if let Some(bound) = self.additional_where_bounds(data) {
+		if let Some(bound) = self.additional_where_bounds(derive_where, data) {
 			list.push(bound)
 		}
 
 		list
 	}
 
-	/// Create [`DeriveTrait`] from [`ParseStream`].
+	/// Returns user-provided predicates for the `where` clause, e.g. from
+	/// `#[serde(bound = "...")]`.
+	pub fn where_predicates(&self) -> Option<&Punctuated<WherePredicate, Token![,]>> {
+		match self {
+			#[cfg(feature = "serde")]
+			DeriveTrait::Deserialize(trait_) => Some(&trait_.bound),
+			#[cfg(feature = "serde")]
+			DeriveTrait::Serialize(trait_) => Some(&trait_.bound),
+			_ => None,
+		}
+	}
+
+	/// Create [`DeriveTrait`]s from [`ParseStream`]. Returns multiple
+	/// [`DeriveTrait`]s if an `unsafe(..)` list was found.
 	pub fn from_stream(
 		attrs: &[Attribute],
 		span: Span,
 		data: &syn::Data,
 		input: ParseStream,
-	) -> Result<(Span, Self)> {
+	) -> Result<Vec<(Span, Self)>> {
 		match Meta::parse(input) {
-			Ok(meta) => {
-				let trait_ = Trait::from_path(meta.path())?;
-
-				if let syn::Data::Union(_) = data {
-					// Make sure this `Trait` supports unions.
-					if !trait_.supports_union() {
-						return Err(Error::union(span));
+			Ok(meta) if meta.path().is_ident("unsafe") => {
+				if let Meta::List(list) = &meta {
+					#[cfg(feature = "safe")]
+					{
+						let _ = list;
+						Err(Error::unsafe_safe(meta.path().span()))
 					}
+					#[cfg(not(feature = "safe"))]
+					list.parse_non_empty_nested_metas()?
+						.iter()
+						.map(|meta| Self::from_meta(attrs, span, data, meta, true))
+						.collect()
+				} else {
+					Err(Error::option_syntax(meta.span()))
 				}
+			}
+			Ok(meta) => Ok(vec![Self::from_meta(attrs, span, data, &meta, false)?]),
+			Err(error) => Err(Error::trait_syntax(error.span())),
+		}
+	}
 
-				match &meta {
-					Meta::Path(path) => Ok((
-						path.span(),
-						trait_.parse_derive_trait(attrs, meta.span(), None)?,
-					)),
-					Meta::List(list) => {
-						let nested = list.parse_non_empty_nested_metas()?;
-
-						// This will return an error if no options are supported.
-						Ok((
-							list.span(),
-							trait_.parse_derive_trait(attrs, meta.span(), Some(nested))?,
-						))
-					}
-					Meta::NameValue(name_value) => Err(Error::option_syntax(name_value.span())),
+	/// Create [`DeriveTrait`] from [`Meta`]. `unsafe_` signifies if the trait
+	/// was found in an `unsafe(..)` list.
+	fn from_meta(
+		attrs: &[Attribute],
+		span: Span,
+		data: &syn::Data,
+		meta: &Meta,
+		unsafe_: bool,
+	) -> Result<(Span, Self)> {
+		let trait_ = Trait::from_path(meta.path())?;
+
+		// Unsafe traits have to be explicitly marked with `unsafe(..)`.
+		if trait_.is_unsafe() && !unsafe_ {
+			return Err(Error::unsafe_required(meta.path().span(), trait_.as_str()));
+		} else if !trait_.is_unsafe() && unsafe_ {
+			return Err(Error::unsafe_unexpected(
+				meta.path().span(),
+				trait_.as_str(),
+			));
+		}
+
+		if let syn::Data::Union(_) = data {
+			// Make sure this `Trait` supports unions.
+			if !trait_.supports_union() {
+				return Err(Error::union(span));
+			}
+		}
+
+		if let syn::Data::Enum(_) = data {
+			// Make sure this `Trait` supports enums.
+			if !trait_.supports_enum() {
+				return Err(Error::enum_(span, trait_.as_str()));
+			}
+		}
+
+		// `zerocopy` requires a defined layout.
+		#[cfg(feature = "zerocopy")]
+		zerocopy::check_repr(trait_, meta.path().span(), attrs, data)?;
+
+		let (span, derive_trait) = match meta {
+			Meta::Path(path) => (
+				path.span(),
+				trait_.parse_derive_trait(attrs, meta.span(), None)?,
+			),
+			// `assert(..)` doesn't take `Meta`s.
+			Meta::List(list) if trait_ == Trait::Assert => {
+				(list.span(), assert::Assert::from_list(list)?)
+			}
+			Meta::List(list) => {
+				let nested = list.parse_non_empty_nested_metas()?;
+
+				// This will return an error if no options are supported.
+				(
+					list.span(),
+					trait_.parse_derive_trait(attrs, meta.span(), Some(nested))?,
+				)
+			}
+			Meta::NameValue(name_value) => return Err(Error::option_syntax(name_value.span())),
+		};
+
+		// Unions have to opt-in to `Zeroize` and `ZeroizeOnDrop` with the `union`
+		// option.
+		#[cfg(feature = "zeroize")]
+		if let DeriveTrait::Zeroize(zeroize::Zeroize { union_, .. })
+		| DeriveTrait::ZeroizeOnDrop(zeroize_on_drop::ZeroizeOnDrop { union_, .. }) = &derive_trait
+		{
+			match (data, union_) {
+				(syn::Data::Union(_), false) => {
+					return Err(Error::zeroize_union_required(span, trait_.as_str()))
+				}
+				(syn::Data::Struct(_) | syn::Data::Enum(_), true) => {
+					return Err(Error::zeroize_union(span))
 				}
+				_ => (),
 			}
-			Err(error) => Err(Error::trait_syntax(error.span())),
 		}
+
+		Ok((span, derive_trait))
+	}
+}
+
+/// Parse the `const` option of [`Clone`], [`Default`] and [`PartialEq`].
+fn parse_const(trait_: &str, list: Option<Punctuated<Meta, Token![,]>>) -> Result<bool> {
+	let unknown = |meta: Meta| match meta {
+		Meta::Path(path) => Error::option_trait(path.span(), trait_),
+		_ => Error::option_syntax(meta.span()),
+	};
+
+	let mut list = list.into_iter().flatten();
+
+	let const_ = match list.next() {
+		Some(Meta::Path(path)) if path.is_ident("const") => path,
+		Some(meta) => return Err(unknown(meta)),
+		None => return Ok(false),
+	};
+
+	#[cfg(not(feature = "nightly"))]
+	return Err(Error::nightly_feature(const_.span()));
+
+	// Check for duplicate `const` option.
+	#[cfg(feature = "nightly")]
+	match list.next() {
+		Some(Meta::Path(path)) if path == const_ => {
+			Err(Error::option_duplicate(path.span(), "const"))
+		}
+		Some(meta) => Err(unknown(meta)),
+		None => Ok(true),
 	}
 }
 
@@ -336,8 +817,39 @@ pub trait TraitImpl: Deref<Target = Trait> {
 		false
 	}
 
+	/// Returns `true` if [`Trait`] supports enums.
+	fn supports_enum() -> bool
+	where
+		Self: Sized,
+	{
+		true
+	}
+
+	/// Returns `true` if [`Trait`] is an `unsafe trait`. These have to be
+	/// explicitly specified with `unsafe(..)`.
+	fn is_unsafe() -> bool
+	where
+		Self: Sized,
+	{
+		false
+	}
+
+	/// Returns the name of the lifetime parameter of this [`Trait`], which can
+	/// be referred to in custom bounds.
+	fn lifetime() -> Option<&'static str>
+	where
+		Self: Sized,
+	{
+		None
+	}
+
+	/// Returns `true` if this is a `const` implementation.
+	fn is_const(&self) -> bool {
+		false
+	}
+
 	/// Additional bounds to add to [`WhereClause`].
-	fn additional_where_bounds(_data: &Item) -> Option<TypeParamBound>
+	fn additional_where_bounds(_derive_where: &DeriveWhere, _data: &Item) -> Option<TypeParamBound>
 	where
 		Self: Sized,
 	{
@@ -348,7 +860,11 @@ pub trait TraitImpl: Deref<Target = Trait> {
 	fn path(&self) -> Path;
 
 	/// Additional implementation to add for this [`Trait`].
-	fn additional_impl(&self) -> Option<(Path, TokenStream)> {
+	fn additional_impl(
+		&self,
+		_derive_where: &DeriveWhere,
+		_item: &Item,
+	) -> Option<(Path, TokenStream)> {
 		None
 	}
 
@@ -366,10 +882,13 @@ pub trait TraitImpl: Deref<Target = Trait> {
 		body: TokenStream,
 	) -> TokenStream {
 		let path = self.path();
+		let trait_: &Trait = self;
+		let unsafe_ = trait_.is_unsafe().then(<Token![unsafe]>::default);
+		let const_ = self.is_const().then(<Token![const]>::default);
 
 		quote! {
 			#[automatically_derived]
-			impl #imp #path for #ident #ty
+			#unsafe_ impl #imp #const_ #path for #ident #ty
 			#where_clause
 			{
 				#body
@@ -377,6 +896,21 @@ pub trait TraitImpl: Deref<Target = Trait> {
 		}
 	}
 
+	/// Implementation for traits requiring the parsed [`Item`], e.g. to
+	/// forward it to another derive macro. Falls back to
+	/// [`impl_item()`](Self::impl_item) if [`None`].
+	fn impl_with_item(
+		&self,
+		_crate_: Option<&Path>,
+		_full_item: &DeriveInput,
+		_item: &Item,
+		_ty: &TypeGenerics<'_>,
+		_where_clause: &Option<Cow<'_, WhereClause>>,
+		_body: &TokenStream,
+	) -> Option<TokenStream> {
+		None
+	}
+
 	/// Build method signature for this [`Trait`].
 	fn build_signature(
 		&self,
@@ -0,0 +1,195 @@
+//! [`Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html) implementation.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+	DeriveInput, GenericParam, Ident, ImplGenerics, Lifetime, LifetimeParam, Path, TypeGenerics,
+	WhereClause,
+};
+
+use crate::{Data, DeriveTrait, DeriveWhere, Either, Item, SplitGenerics, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html).
+pub struct Arbitrary;
+
+impl TraitImpl for Arbitrary {
+	fn as_str() -> &'static str {
+		"Arbitrary"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::Arbitrary
+	}
+
+	fn lifetime() -> Option<&'static str> {
+		Some("arbitrary")
+	}
+
+	fn path(&self) -> Path {
+		syn::parse2(quote! { ::arbitrary::Arbitrary<'arbitrary> }).expect("failed to parse path")
+	}
+
+	fn impl_item(
+		&self,
+		_crate_: Option<&Path>,
+		full_item: &DeriveInput,
+		_: &ImplGenerics<'_>,
+		ident: &Ident,
+		ty: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		body: TokenStream,
+	) -> TokenStream {
+		// Add the `'arbitrary` lifetime, which has to outlive all lifetimes on the
+		// item.
+		let mut generics = full_item.generics.clone();
+		let mut lifetime = LifetimeParam::new(Lifetime::new("'arbitrary", Span::call_site()));
+		lifetime
+			.bounds
+			.extend(generics.lifetimes().map(|param| param.lifetime.clone()));
+		generics.params.insert(0, GenericParam::Lifetime(lifetime));
+		let (imp, _, _) = generics.split_for_impl();
+		let path = self.path();
+
+		quote! {
+			#[automatically_derived]
+			impl #imp #path for #ident #ty
+			#where_clause
+			{
+				#body
+			}
+		}
+	}
+
+	fn build_signature(
+		&self,
+		_derive_where: &DeriveWhere,
+		item: &Item,
+		_generics: &SplitGenerics<'_>,
+		_body: &TokenStream,
+	) -> TokenStream {
+		let (arbitrary, take_rest, size_hint) = match item {
+			Item::Item(data) => (
+				build_constructor(data, false),
+				build_constructor(data, true),
+				build_size_hint(data),
+			),
+			Item::Enum { variants, .. } => {
+				let count = variants.len() as u64;
+				let indices: Vec<_> = (0..count).collect();
+
+				let arbitrary = variants.iter().map(|data| build_constructor(data, false));
+				let take_rest = variants.iter().map(|data| build_constructor(data, true));
+				let size_hints = variants.iter().map(build_size_hint);
+
+				(
+					// Use a multiply and shift to choose a variant, like `arbitrary`'s own derive.
+					quote! {
+						match (u64::from(<u32 as ::arbitrary::Arbitrary>::arbitrary(__u)?) * #count) >> 32 {
+							#(#indices => #arbitrary,)*
+							_ => ::core::unreachable!(),
+						}
+					},
+					quote! {
+						match (u64::from(<u32 as ::arbitrary::Arbitrary>::arbitrary(&mut __u)?) * #count) >> 32 {
+							#(#indices => #take_rest,)*
+							_ => ::core::unreachable!(),
+						}
+					},
+					quote! {
+						::arbitrary::size_hint::and(
+							<u32 as ::arbitrary::Arbitrary>::size_hint(__depth),
+							::arbitrary::size_hint::or_all(&[#(#size_hints),*]),
+						)
+					},
+				)
+			}
+		};
+
+		// `__u` is only borrowed mutably if a variant or more than one field is
+		// generated.
+		let mut_ = match item {
+			Item::Item(data) => data.iter_fields(Trait::Arbitrary).count() > 1,
+			Item::Enum { .. } => true,
+		}
+		.then(|| format_ident!("mut"));
+
+		quote! {
+			fn arbitrary(__u: &mut ::arbitrary::Unstructured<'arbitrary>) -> ::arbitrary::Result<Self> {
+				::core::result::Result::Ok(#arbitrary)
+			}
+
+			fn arbitrary_take_rest(#mut_ __u: ::arbitrary::Unstructured<'arbitrary>) -> ::arbitrary::Result<Self> {
+				::core::result::Result::Ok(#take_rest)
+			}
+
+			fn size_hint(__depth: usize) -> (usize, ::core::option::Option<usize>) {
+				// Guard against infinite recursion, with the same limit `arbitrary` uses.
+				if __depth > 20 {
+					return (0, ::core::option::Option::None);
+				}
+
+				let __depth = __depth + 1;
+				#size_hint
+			}
+		}
+	}
+}
+
+impl Deref for Arbitrary {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::Arbitrary
+	}
+}
+
+/// Build an expression constructing `data` from arbitrary data. Skipped fields
+/// are filled with [`Default`]. If `take_rest` is `true`, the last field not
+/// skipped consumes the remaining data.
+fn build_constructor(data: &Data, take_rest: bool) -> TokenStream {
+	let path = &data.path;
+	let default_path = Trait::Default.default_derive_trait().path();
+
+	let fields = match data.fields() {
+		Either::Left(fields) => &fields.fields,
+		Either::Right(_) => return quote! { #path },
+	};
+
+	// Number of fields to generate.
+	let len = data.iter_fields(Trait::Arbitrary).count();
+	let mut index = 0;
+
+	let fields = fields.iter().map(|field| {
+		let member = &field.member;
+
+		let value = if field.skip(Trait::Arbitrary) || data.skip(Trait::Arbitrary) {
+			quote! { #default_path::default() }
+		} else {
+			index += 1;
+
+			if !take_rest {
+				quote! { ::arbitrary::Arbitrary::arbitrary(__u)? }
+			} else if index == len {
+				quote! { ::arbitrary::Arbitrary::arbitrary_take_rest(__u)? }
+			} else {
+				quote! { ::arbitrary::Arbitrary::arbitrary(&mut __u)? }
+			}
+		};
+
+		quote! { #member: #value }
+	});
+
+	quote! { #path { #(#fields),* } }
+}
+
+/// Build an expression combining the size hints of all fields not skipped.
+fn build_size_hint(data: &Data) -> TokenStream {
+	let hints = data.iter_fields(Trait::Arbitrary).map(|field| {
+		let type_ = field.type_;
+		quote! { <#type_ as ::arbitrary::Arbitrary>::size_hint(__depth) }
+	});
+
+	quote! { ::arbitrary::size_hint::and_all(&[#(#hints),*]) }
+}
@@ -0,0 +1,77 @@
+//! [`Archive`](https://docs.rs/rkyv/latest/rkyv/trait.Archive.html) implementation.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::{Span, TokenStream};
+use syn::{
+	punctuated::Punctuated, Attribute, DeriveInput, Meta, Path, Result, Token, TypeGenerics,
+	WhereClause,
+};
+
+use super::rkyv;
+use crate::{attr::crate_root, util, DeriveTrait, Item, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`Archive`](https://docs.rs/rkyv/latest/rkyv/trait.Archive.html).
+#[derive(Eq, PartialEq)]
+pub struct Archive {
+	/// [`Archive`](https://docs.rs/rkyv/latest/rkyv/trait.Archive.html) path.
+	pub crate_: Option<Path>,
+}
+
+impl TraitImpl for Archive {
+	fn as_str() -> &'static str {
+		"Archive"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::Archive(Self { crate_: None })
+	}
+
+	fn parse_derive_trait(
+		attrs: &[Attribute],
+		_span: Span,
+		list: Option<Punctuated<Meta, Token![,]>>,
+	) -> Result<DeriveTrait> {
+		let crate_ = rkyv::parse_derive_trait(Trait::Archive, attrs, list)?;
+
+		Ok(DeriveTrait::Archive(Self { crate_ }))
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_root_and_strs(self.crate_(), &["Archive"])
+	}
+
+	fn impl_with_item(
+		&self,
+		crate_: Option<&Path>,
+		full_item: &DeriveInput,
+		item: &Item,
+		_: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		_: &TokenStream,
+	) -> Option<TokenStream> {
+		Some(rkyv::impl_item(
+			crate_,
+			&self.crate_(),
+			Trait::Archive,
+			full_item,
+			item,
+			where_clause,
+		))
+	}
+}
+
+impl Archive {
+	/// Returns the path to the root crate for this trait.
+	fn crate_(&self) -> Path {
+		crate_root(self.crate_.as_ref(), "rkyv")
+	}
+}
+
+impl Deref for Archive {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::Archive
+	}
+}
@@ -0,0 +1,109 @@
+//! Compile-time assertion that the item implements the given bounds.
+
+use std::{borrow::Cow, iter, ops::Deref};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+	punctuated::Punctuated, spanned::Spanned, Attribute, DeriveInput, Ident, ImplGenerics, Meta,
+	MetaList, Path, Result, Token, TypeGenerics, TypeParamBound, WhereClause,
+};
+
+use crate::{util, DeriveTrait, Error, Trait, TraitImpl};
+
+/// [`TraitImpl`] for asserting bounds on the item.
+#[derive(Eq, PartialEq)]
+pub struct Assert {
+	/// Bounds the item has to satisfy.
+	pub bounds: Punctuated<TypeParamBound, Token![+]>,
+}
+
+impl Assert {
+	/// Parse the bounds of `assert(..)`. These aren't [`Meta`]s, e.g.
+	/// `'static`, so they are parsed directly from the [`MetaList`].
+	pub fn from_list(list: &MetaList) -> Result<DeriveTrait> {
+		let bounds = list
+			.parse_args_with(Punctuated::<TypeParamBound, Token![,]>::parse_terminated)
+			.map_err(|error| Error::option_syntax(error.span()))?;
+
+		if bounds.is_empty() {
+			return Err(Error::option_empty(list.span()));
+		}
+
+		Ok(DeriveTrait::Assert(Self {
+			bounds: bounds.into_iter().collect(),
+		}))
+	}
+}
+
+impl TraitImpl for Assert {
+	fn as_str() -> &'static str {
+		"assert"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::Assert(Self {
+			bounds: Punctuated::new(),
+		})
+	}
+
+	fn parse_derive_trait(
+		_: &[Attribute],
+		span: Span,
+		_: Option<Punctuated<Meta, Token![,]>>,
+	) -> Result<DeriveTrait> {
+		// A list is handled by `Assert::from_list()`.
+		Err(Error::option_required(span, Self::as_str()))
+	}
+
+	fn supports_union() -> bool {
+		true
+	}
+
+	fn path(&self) -> Path {
+		Path {
+			leading_colon: None,
+			segments: Punctuated::from_iter(iter::once(util::path_segment("DeriveWhereAssert"))),
+		}
+	}
+
+	fn impl_item(
+		&self,
+		_: Option<&Path>,
+		_: &DeriveInput,
+		imp: &ImplGenerics<'_>,
+		ident: &Ident,
+		ty: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		_: TokenStream,
+	) -> TokenStream {
+		let path = self.path();
+		let bounds = &self.bounds;
+
+		quote! {
+			const _: () = {
+				trait #path {
+					fn assert(&self);
+				}
+
+				impl #imp #path for #ident #ty
+				#where_clause
+				{
+					fn assert(&self) {
+						struct __Assert<__T: #bounds + ?::core::marker::Sized>(::core::marker::PhantomData<__T>);
+
+						let _: __Assert<Self>;
+					}
+				}
+			};
+		}
+	}
+}
+
+impl Deref for Assert {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::Assert
+	}
+}
@@ -0,0 +1,100 @@
+//! [`BorshDeserialize`](https://docs.rs/borsh/latest/borsh/de/trait.BorshDeserialize.html)
+//! implementation.
+
+use std::ops::Deref;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+
+use crate::{
+	item, util, Data, DeriveTrait, DeriveWhere, Either, Item, SplitGenerics, Trait, TraitImpl,
+};
+
+/// [`TraitImpl`] for [`BorshDeserialize`](https://docs.rs/borsh/latest/borsh/de/trait.BorshDeserialize.html).
+pub struct BorshDeserialize;
+
+impl TraitImpl for BorshDeserialize {
+	fn as_str() -> &'static str {
+		"BorshDeserialize"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::BorshDeserialize
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_strs(&["borsh", "BorshDeserialize"])
+	}
+
+	fn build_signature(
+		&self,
+		_derive_where: &DeriveWhere,
+		item: &Item,
+		_generics: &SplitGenerics<'_>,
+		_body: &TokenStream,
+	) -> TokenStream {
+		let body = match item {
+			Item::Item(data) => {
+				let constructor = build_constructor(data);
+				quote! { ::core::result::Result::Ok(#constructor) }
+			}
+			Item::Enum { variants, .. } => {
+				// Variants are read from a `u8` tag, which is their discriminant.
+				let variants = variants.iter().zip(item::discriminants(variants)).map(
+					|(data, discriminant)| {
+						let constructor = build_constructor(data);
+
+						quote! {
+							if __tag == (#discriminant) {
+								return ::core::result::Result::Ok(#constructor);
+							}
+						}
+					},
+				);
+
+				quote! {
+					let __tag = <::core::primitive::u8 as ::borsh::BorshDeserialize>::deserialize_reader(__reader)?;
+
+					#(#variants)*
+
+					::core::result::Result::Err(::borsh::io::Error::new(
+						::borsh::io::ErrorKind::InvalidData,
+						"Unexpected variant tag",
+					))
+				}
+			}
+		};
+
+		quote! {
+			fn deserialize_reader<__R: ::borsh::io::Read>(__reader: &mut __R) -> ::core::result::Result<Self, ::borsh::io::Error> {
+				#body
+			}
+		}
+	}
+}
+
+impl Deref for BorshDeserialize {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::BorshDeserialize
+	}
+}
+
+/// Build an expression reading all fields of `data` in declaration order.
+fn build_constructor(data: &Data) -> TokenStream {
+	let path = &data.path;
+
+	match data.fields() {
+		Either::Left(fields) => {
+			let fields = fields.fields.iter().map(|field| {
+				let member = &field.member;
+				quote! { #member: ::borsh::BorshDeserialize::deserialize_reader(__reader)? }
+			});
+
+			quote! { #path { #(#fields),* } }
+		}
+		Either::Right(_) => quote! { #path },
+	}
+}
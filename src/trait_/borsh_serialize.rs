@@ -0,0 +1,83 @@
+//! [`BorshSerialize`](https://docs.rs/borsh/latest/borsh/ser/trait.BorshSerialize.html)
+//! implementation.
+
+use std::ops::Deref;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+
+use crate::{item, util, Data, DeriveTrait, DeriveWhere, Item, SplitGenerics, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`BorshSerialize`](https://docs.rs/borsh/latest/borsh/ser/trait.BorshSerialize.html).
+pub struct BorshSerialize;
+
+impl TraitImpl for BorshSerialize {
+	fn as_str() -> &'static str {
+		"BorshSerialize"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::BorshSerialize
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_strs(&["borsh", "BorshSerialize"])
+	}
+
+	fn build_signature(
+		&self,
+		_derive_where: &DeriveWhere,
+		item: &Item,
+		_generics: &SplitGenerics<'_>,
+		_body: &TokenStream,
+	) -> TokenStream {
+		let body = match item {
+			Item::Item(data) => build_fields(data, None),
+			Item::Enum { variants, .. } => {
+				// Variants are written as a `u8` tag, which is their discriminant.
+				let variants = variants
+					.iter()
+					.zip(item::discriminants(variants))
+					.map(|(data, discriminant)| build_fields(data, Some(quote! { #discriminant })));
+
+				quote! { #(#variants)* }
+			}
+		};
+
+		quote! {
+			fn serialize<__W: ::borsh::io::Write>(&self, __writer: &mut __W) -> ::core::result::Result<(), ::borsh::io::Error> {
+				match self {
+					#body
+				}
+
+				::core::result::Result::Ok(())
+			}
+		}
+	}
+}
+
+impl Deref for BorshSerialize {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::BorshSerialize
+	}
+}
+
+/// Build a `match` arm writing the `tag`, if any, and all fields of `data` in
+/// declaration order.
+fn build_fields(data: &Data, tag: Option<TokenStream>) -> TokenStream {
+	let self_pattern = data.self_pattern();
+	let self_ident = data.iter_self_ident(Trait::BorshSerialize);
+	let tag = tag.map(|tag| {
+		quote! { <::core::primitive::u8 as ::borsh::BorshSerialize>::serialize(&(#tag), __writer)?; }
+	});
+
+	quote! {
+		#self_pattern => {
+			#tag
+			#(::borsh::BorshSerialize::serialize(#self_ident, __writer)?;)*
+		}
+	}
+}
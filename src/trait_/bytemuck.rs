@@ -0,0 +1,67 @@
+//! Common functionality between
+//! [`Pod`](https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html) and
+//! [`Zeroable`](https://docs.rs/bytemuck/latest/bytemuck/trait.Zeroable.html).
+
+use std::borrow::Cow;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, ImplGenerics, Path, TypeGenerics, WhereClause};
+
+use crate::{Data, Trait};
+
+/// Implement `trait_` together with an assertion that all fields implement it
+/// as well.
+pub fn impl_item(
+	trait_: Trait,
+	path: &Path,
+	imp: &ImplGenerics<'_>,
+	ident: &Ident,
+	ty: &TypeGenerics<'_>,
+	where_clause: &Option<Cow<'_, WhereClause>>,
+	body: TokenStream,
+) -> TokenStream {
+	let assert = format_ident!("DeriveWhereAssert{}", trait_.as_str());
+
+	quote! {
+		const _: () = {
+			trait #assert {
+				fn assert(&self);
+			}
+
+			impl #imp #assert for #ident #ty
+			#where_clause
+			{
+				#body
+			}
+		};
+
+		#[automatically_derived]
+		unsafe impl #imp #path for #ident #ty
+		#where_clause
+		{ }
+	}
+}
+
+/// Build the assertion method for `trait_`.
+pub fn build_signature(trait_: Trait, path: &Path, body: &TokenStream) -> TokenStream {
+	let assert = format_ident!("__Assert{}", trait_.as_str());
+
+	quote! {
+		fn assert(&self) {
+			struct #assert<__T: #path>(::core::marker::PhantomData<__T>);
+
+			#body
+		}
+	}
+}
+
+/// Assert that every field of `data` implements `trait_`.
+pub fn build_body(trait_: Trait, data: &Data) -> TokenStream {
+	let assert = format_ident!("__Assert{}", trait_.as_str());
+	let types = data.iter_fields(trait_).map(|field| field.type_);
+
+	quote! {
+		#(let _: #assert<#types>;)*
+	}
+}
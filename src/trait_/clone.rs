@@ -2,9 +2,12 @@
 
 use std::ops::Deref;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{TraitBound, TraitBoundModifier, TypeParamBound};
+use syn::{
+	punctuated::Punctuated, Attribute, Meta, Result, Token, TraitBound, TraitBoundModifier,
+	TypeParamBound,
+};
 
 use crate::{
 	data::Field, util, Data, DataType, DeriveTrait, DeriveWhere, Item, SimpleType, SplitGenerics,
@@ -12,7 +15,11 @@ use crate::{
 };
 
 /// [`TraitImpl`] for [`Clone`](trait@std::clone::Clone).
-pub struct Clone;
+#[derive(Eq, PartialEq)]
+pub struct Clone {
+	/// If this is a `const` implementation.
+	pub const_: bool,
+}
 
 impl TraitImpl for Clone {
 	fn as_str() -> &'static str {
@@ -20,14 +27,28 @@ impl TraitImpl for Clone {
 	}
 
 	fn default_derive_trait() -> DeriveTrait {
-		DeriveTrait::Clone
+		DeriveTrait::Clone(Self { const_: false })
+	}
+
+	fn parse_derive_trait(
+		_: &[Attribute],
+		_: Span,
+		list: Option<Punctuated<Meta, Token![,]>>,
+	) -> Result<DeriveTrait> {
+		Ok(DeriveTrait::Clone(Self {
+			const_: super::parse_const(Self::as_str(), list)?,
+		}))
 	}
 
 	fn supports_union() -> bool {
 		true
 	}
 
-	fn additional_where_bounds(data: &Item) -> Option<TypeParamBound> {
+	fn is_const(&self) -> bool {
+		self.const_
+	}
+
+	fn additional_where_bounds(_derive_where: &DeriveWhere, data: &Item) -> Option<TypeParamBound> {
 		// `Clone` for unions requires the `Copy` bound.
 		if let Item::Item(Data {
 			type_: DataType::Union(..),
@@ -104,7 +125,7 @@ impl TraitImpl for Clone {
 				let self_pattern = &fields.self_pattern;
 				let item_path = &data.path;
 				let trait_path = self.path();
-				let default_path = DeriveTrait::Default.path();
+				let default_path = Trait::Default.default_derive_trait().path();
 
 				let fields = fields.fields.iter().map(
 					|field @ Field {
@@ -1,17 +1,15 @@
 //! Common implementation help for [`PartialOrd`] and [`Ord`].
 
-#[cfg(not(feature = "nightly"))]
-use std::borrow::Cow;
-
 use proc_macro2::TokenStream;
-#[cfg(not(feature = "nightly"))]
-use proc_macro2::{Literal, Span};
 use quote::quote;
 #[cfg(not(feature = "nightly"))]
-use syn::{parse_quote, Expr, ExprLit, LitInt, Path};
+use syn::Path;
 
 #[cfg(not(feature = "nightly"))]
-use crate::{item::Representation, DeriveTrait, Discriminant};
+use crate::{
+	item::{self, Representation},
+	Discriminant,
+};
 use crate::{Data, DeriveWhere, Item, SimpleType, SplitGenerics, Trait, TraitImpl};
 
 /// Build signature for [`PartialOrd`] and [`Ord`].
@@ -150,7 +148,7 @@ pub fn build_ord_signature(
 									#path::#method(&(*self as ::core::primitive::isize), &(*__other as ::core::primitive::isize))
 								}
 							} else if derive_where.contains(Trait::Clone) {
-								let clone = DeriveTrait::Clone.path();
+								let clone = Trait::Clone.default_derive_trait().path();
 								quote! {
 									#path::#method(&(#clone::clone(self) as ::core::primitive::isize), &(#clone::clone(__other) as ::core::primitive::isize))
 								}
@@ -169,7 +167,7 @@ pub fn build_ord_signature(
 									#path::#method(&(*self as #repr), &(*__other as #repr))
 								}
 							} else if derive_where.contains(Trait::Clone) {
-								let clone = DeriveTrait::Clone.path();
+								let clone = Trait::Clone.default_derive_trait().path();
 								quote! {
 									#path::#method(&(#clone::clone(self) as #repr), &(#clone::clone(__other) as #repr))
 								}
@@ -264,45 +262,7 @@ fn build_discriminant_order(
 	path: &Path,
 	method: &TokenStream,
 ) -> TokenStream {
-	let mut discriminants = Vec::<Cow<Expr>>::with_capacity(variants.len());
-	let mut last_expression: Option<(Option<usize>, usize)> = None;
-
-	for variant in variants {
-		let discriminant = if let Some(discriminant) = variant.discriminant {
-			last_expression = Some((Some(discriminants.len()), 0));
-			Cow::Borrowed(discriminant)
-		} else {
-			let discriminant = match &mut last_expression {
-				Some((Some(expr_index), counter)) => {
-					let expr = &discriminants[*expr_index];
-					*counter += 1;
-					let counter = Literal::usize_unsuffixed(*counter);
-					parse_quote! { (#expr) + #counter }
-				}
-				Some((None, counter)) => {
-					*counter += 1;
-
-					ExprLit {
-						attrs: Vec::new(),
-						lit: LitInt::new(&counter.to_string(), Span::call_site()).into(),
-					}
-					.into()
-				}
-				None => {
-					last_expression = Some((None, 0));
-					ExprLit {
-						attrs: Vec::new(),
-						lit: LitInt::new("0", Span::call_site()).into(),
-					}
-					.into()
-				}
-			};
-
-			Cow::Owned(discriminant)
-		};
-
-		discriminants.push(discriminant);
-	}
+	let discriminants = item::discriminants(variants);
 
 	let variants = variants
 		.iter()
@@ -324,6 +284,7 @@ fn build_discriminant_order(
 		imp,
 		ty,
 		where_clause,
+		..
 	} = generics;
 
 	quote! {
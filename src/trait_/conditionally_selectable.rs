@@ -0,0 +1,109 @@
+//! [`ConditionallySelectable`](https://docs.rs/subtle/latest/subtle/trait.ConditionallySelectable.html)
+//! implementation.
+
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{punctuated::Punctuated, Attribute, Meta, Path, Result, Token};
+
+use crate::{
+	attr::{crate_root, parse_crate_options},
+	util, Data, DeriveTrait, DeriveWhere, Item, SimpleType, SplitGenerics, Trait, TraitImpl,
+};
+
+/// [`TraitImpl`] for [`ConditionallySelectable`](https://docs.rs/subtle/latest/subtle/trait.ConditionallySelectable.html).
+#[derive(Eq, PartialEq)]
+pub struct ConditionallySelectable {
+	/// [`ConditionallySelectable`](https://docs.rs/subtle/latest/subtle/trait.ConditionallySelectable.html) path.
+	pub crate_: Option<Path>,
+}
+
+impl TraitImpl for ConditionallySelectable {
+	fn as_str() -> &'static str {
+		"ConditionallySelectable"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::ConditionallySelectable(Self { crate_: None })
+	}
+
+	fn supports_enum() -> bool {
+		false
+	}
+
+	fn parse_derive_trait(
+		_: &[Attribute],
+		_span: Span,
+		list: Option<Punctuated<Meta, Token![,]>>,
+	) -> Result<DeriveTrait> {
+		let crate_ = parse_crate_options(list, Self::as_str(), "subtle")?;
+
+		Ok(DeriveTrait::ConditionallySelectable(Self { crate_ }))
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_root_and_strs(self.crate_(), &["ConditionallySelectable"])
+	}
+
+	fn build_signature(
+		&self,
+		_derive_where: &DeriveWhere,
+		_item: &Item,
+		_generics: &SplitGenerics<'_>,
+		body: &TokenStream,
+	) -> TokenStream {
+		let choice = util::path_from_root_and_strs(self.crate_(), &["Choice"]);
+
+		quote! {
+			fn conditional_select(__a: &Self, __b: &Self, __choice: #choice) -> Self {
+				match (__a, __b) {
+					#body
+				}
+			}
+		}
+	}
+
+	fn build_body(&self, _derive_where: &DeriveWhere, data: &Data) -> TokenStream {
+		match data.simple_type() {
+			SimpleType::Struct(fields) | SimpleType::Tuple(fields) => {
+				let self_pattern = &fields.self_pattern;
+				let other_pattern = &fields.other_pattern;
+				let item_path = &data.path;
+				let trait_path = self.path();
+
+				let fields = data
+					.iter_fields(**self)
+					.zip(data.iter_self_ident(**self))
+					.zip(data.iter_other_ident(**self))
+					.map(|((field, self_ident), other_ident)| {
+						let member = &field.member;
+						quote!(#member: #trait_path::conditional_select(#self_ident, #other_ident, __choice))
+					});
+
+				quote! {
+					(#self_pattern, #other_pattern) => #item_path { #(#fields),* },
+				}
+			}
+			SimpleType::Unit(pattern) => {
+				quote! { (#pattern, #pattern) => #pattern, }
+			}
+			SimpleType::Union => unreachable!("unexpected trait for union"),
+		}
+	}
+}
+
+impl ConditionallySelectable {
+	/// Returns the path to the root crate for this trait.
+	fn crate_(&self) -> Path {
+		crate_root(self.crate_.as_ref(), "subtle")
+	}
+}
+
+impl Deref for ConditionallySelectable {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::ConditionallySelectable
+	}
+}
@@ -0,0 +1,79 @@
+//! Associated `const DEFAULT` implementation.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{DeriveInput, Ident, ImplGenerics, Path, TypeGenerics, WhereClause};
+
+use super::default::build_default;
+use crate::{Data, DeriveTrait, DeriveWhere, Item, SplitGenerics, Trait, TraitImpl};
+
+/// [`TraitImpl`] for an associated `const DEFAULT`.
+pub struct ConstDefault;
+
+impl TraitImpl for ConstDefault {
+	fn as_str() -> &'static str {
+		"ConstDefault"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::ConstDefault
+	}
+
+	fn path(&self) -> Path {
+		// Not a trait, only used to satisfy `TraitImpl`.
+		Path::from(Ident::new(Self::as_str(), Span::call_site()))
+	}
+
+	fn impl_item(
+		&self,
+		_: Option<&Path>,
+		_: &DeriveInput,
+		imp: &ImplGenerics<'_>,
+		ident: &Ident,
+		ty: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		body: TokenStream,
+	) -> TokenStream {
+		quote! {
+			impl #imp #ident #ty
+			#where_clause
+			{
+				#body
+			}
+		}
+	}
+
+	fn build_signature(
+		&self,
+		_derive_where: &DeriveWhere,
+		_item: &Item,
+		_generics: &SplitGenerics<'_>,
+		body: &TokenStream,
+	) -> TokenStream {
+		quote! {
+			/// The default value of this type, usable in `const` contexts.
+			pub const DEFAULT: Self = #body;
+		}
+	}
+
+	fn build_body(&self, _derive_where: &DeriveWhere, data: &Data) -> TokenStream {
+		build_default(**self, data, |field| {
+			if let Some(value) = &field.attr.const_default.0 {
+				quote! { #value }
+			} else {
+				let type_ = field.type_;
+				quote! { <#type_>::DEFAULT }
+			}
+		})
+	}
+}
+
+impl Deref for ConstDefault {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::ConstDefault
+	}
+}
@@ -0,0 +1,110 @@
+//! [`ConstantTimeEq`](https://docs.rs/subtle/latest/subtle/trait.ConstantTimeEq.html)
+//! implementation.
+
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{punctuated::Punctuated, Attribute, Meta, Path, Result, Token};
+
+use crate::{
+	attr::{crate_root, parse_crate_options},
+	util, Data, DeriveTrait, DeriveWhere, Item, SimpleType, SplitGenerics, Trait, TraitImpl,
+};
+
+/// [`TraitImpl`] for [`ConstantTimeEq`](https://docs.rs/subtle/latest/subtle/trait.ConstantTimeEq.html).
+#[derive(Eq, PartialEq)]
+pub struct ConstantTimeEq {
+	/// [`ConstantTimeEq`](https://docs.rs/subtle/latest/subtle/trait.ConstantTimeEq.html) path.
+	pub crate_: Option<Path>,
+}
+
+impl TraitImpl for ConstantTimeEq {
+	fn as_str() -> &'static str {
+		"ConstantTimeEq"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::ConstantTimeEq(Self { crate_: None })
+	}
+
+	fn parse_derive_trait(
+		_: &[Attribute],
+		_span: Span,
+		list: Option<Punctuated<Meta, Token![,]>>,
+	) -> Result<DeriveTrait> {
+		let crate_ = parse_crate_options(list, Self::as_str(), "subtle")?;
+
+		Ok(DeriveTrait::ConstantTimeEq(Self { crate_ }))
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_root_and_strs(self.crate_(), &["ConstantTimeEq"])
+	}
+
+	fn build_signature(
+		&self,
+		_derive_where: &DeriveWhere,
+		item: &Item,
+		_generics: &SplitGenerics<'_>,
+		body: &TokenStream,
+	) -> TokenStream {
+		let choice = util::path_from_root_and_strs(self.crate_(), &["Choice"]);
+
+		// Different variants are never equal.
+		let rest = match item {
+			Item::Enum { variants, .. } if variants.len() > 1 => {
+				Some(quote! { _ => #choice::from(0), })
+			}
+			_ => None,
+		};
+
+		quote! {
+			fn ct_eq(&self, __other: &Self) -> #choice {
+				match (self, __other) {
+					#body
+					#rest
+				}
+			}
+		}
+	}
+
+	fn build_body(&self, _derive_where: &DeriveWhere, data: &Data) -> TokenStream {
+		let choice = util::path_from_root_and_strs(self.crate_(), &["Choice"]);
+
+		match data.simple_type() {
+			SimpleType::Struct(fields) | SimpleType::Tuple(fields) => {
+				let self_pattern = &fields.self_pattern;
+				let other_pattern = &fields.other_pattern;
+				let trait_path = self.path();
+				let self_ident = data.iter_self_ident(**self);
+				let other_ident = data.iter_other_ident(**self);
+
+				// `&` doesn't short-circuit, so all fields are always compared.
+				quote! {
+					(#self_pattern, #other_pattern) =>
+						#choice::from(1) #(& #trait_path::ct_eq(#self_ident, #other_ident))*,
+				}
+			}
+			SimpleType::Unit(pattern) => {
+				quote! { (#pattern, #pattern) => #choice::from(1), }
+			}
+			SimpleType::Union => unreachable!("unexpected trait for union"),
+		}
+	}
+}
+
+impl ConstantTimeEq {
+	/// Returns the path to the root crate for this trait.
+	pub fn crate_(&self) -> Path {
+		crate_root(self.crate_.as_ref(), "subtle")
+	}
+}
+
+impl Deref for ConstantTimeEq {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::ConstantTimeEq
+	}
+}
@@ -45,9 +45,18 @@ impl TraitImpl for Debug {
 		let self_pattern = &data.self_pattern();
 		let debug_name = data.ident.to_string();
 
+		// Fields of `secret` items are printed as `[REDACTED]`.
+		let debug_value = |self_ident| {
+			if data.secret.is_secret() {
+				quote! { &::core::format_args!("[REDACTED]") }
+			} else {
+				quote! { #self_ident }
+			}
+		};
+
 		match data.simple_type() {
 			SimpleType::Struct(_) => {
-				let self_ident = data.iter_self_ident(**self);
+				let self_ident = data.iter_self_ident(**self).map(debug_value);
 				let debug_fields = data.iter_field_ident(**self).map(|field| field.to_string());
 
 				let finish = if data.any_skip_trait(**self) {
@@ -65,7 +74,7 @@ impl TraitImpl for Debug {
 				}
 			}
 			SimpleType::Tuple(_) => {
-				let self_ident = data.iter_self_ident(**self);
+				let self_ident = data.iter_self_ident(**self).map(debug_value);
 
 				quote! {
 					#self_pattern => {
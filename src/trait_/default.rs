@@ -2,15 +2,21 @@
 
 use std::ops::Deref;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
+use syn::{punctuated::Punctuated, Attribute, Meta, Result, Token};
 
 use crate::{
-	util, Data, DeriveTrait, DeriveWhere, Item, SimpleType, SplitGenerics, Trait, TraitImpl,
+	data::Field, util, Data, DeriveTrait, DeriveWhere, Item, SimpleType, SplitGenerics, Trait,
+	TraitImpl,
 };
 
 /// [`TraitImpl`] for [`Default`](trait@std::default::Default).
-pub struct Default;
+#[derive(Eq, PartialEq)]
+pub struct Default {
+	/// If this is a `const` implementation.
+	pub const_: bool,
+}
 
 impl TraitImpl for Default {
 	fn as_str() -> &'static str {
@@ -18,7 +24,21 @@ impl TraitImpl for Default {
 	}
 
 	fn default_derive_trait() -> DeriveTrait {
-		DeriveTrait::Default
+		DeriveTrait::Default(Self { const_: false })
+	}
+
+	fn parse_derive_trait(
+		_: &[Attribute],
+		_: Span,
+		list: Option<Punctuated<Meta, Token![,]>>,
+	) -> Result<DeriveTrait> {
+		Ok(DeriveTrait::Default(Self {
+			const_: super::parse_const(Self::as_str(), list)?,
+		}))
+	}
+
+	fn is_const(&self) -> bool {
+		self.const_
 	}
 
 	fn path(&self) -> syn::Path {
@@ -40,34 +60,47 @@ impl TraitImpl for Default {
 	}
 
 	fn build_body(&self, _derive_where: &DeriveWhere, data: &Data) -> TokenStream {
-		if data.is_default() {
-			let path = &data.path;
-
-			match data.simple_type() {
-				SimpleType::Struct(_) => {
-					let fields = data.iter_field_ident(**self);
-					let trait_path = self.path();
-
-					quote! { #path { #(#fields: #trait_path::default()),* } }
-				}
-				SimpleType::Tuple(_) => {
-					let trait_path = self.path();
-					let fields = data
-						.iter_fields(**self)
-						.map(|_| quote! { #trait_path::default() });
-
-					quote! { #path(#(#fields),*) }
-				}
-				SimpleType::Unit(_) => {
-					quote! { #path }
-				}
-				SimpleType::Union => unreachable!("unexpected trait for union"),
+		let trait_path = self.path();
+
+		build_default(**self, data, |_| quote! { #trait_path::default() })
+	}
+}
+
+/// Build the construction of the default struct, tuple or variant. `value`
+/// builds the value of each field. Shared with `ConstDefault`.
+pub fn build_default(
+	trait_: Trait,
+	data: &Data,
+	value: impl Fn(&Field) -> TokenStream,
+) -> TokenStream {
+	if data.is_default() {
+		let path = &data.path;
+
+		match data.simple_type() {
+			SimpleType::Struct(_) => {
+				let fields = data.iter_fields(trait_).map(|field| {
+					let member = &field.member;
+					let value = value(field);
+
+					quote! { #member: #value }
+				});
+
+				quote! { #path { #(#fields),* } }
 			}
+			SimpleType::Tuple(_) => {
+				let fields = data.iter_fields(trait_).map(value);
+
+				quote! { #path(#(#fields),*) }
+			}
+			SimpleType::Unit(_) => {
+				quote! { #path }
+			}
+			SimpleType::Union => unreachable!("unexpected trait for union"),
 		}
-		// Skip `Default` implementation if variant isn't marked with a `default` attribute.
-		else {
-			TokenStream::new()
-		}
+	}
+	// Skip implementation if variant isn't marked with a `default` attribute.
+	else {
+		TokenStream::new()
 	}
 }
 
@@ -3,20 +3,25 @@
 use std::{borrow::Cow, ops::Deref};
 
 use proc_macro2::{Span, TokenStream};
-use quote::{format_ident, quote};
+use quote::quote;
 use syn::{
-	punctuated::Punctuated, Attribute, DeriveInput, Ident, ImplGenerics, Meta, Path, Result,
-	TypeGenerics, WhereClause,
+	punctuated::Punctuated, Attribute, DeriveInput, GenericParam, Meta, Path, Result, Token,
+	TypeGenerics, WhereClause, WherePredicate,
 };
 
-use super::serde;
-use crate::{util, DeriveTrait, Trait, TraitImpl};
+use super::serde::{self, Container, Tagging};
+use crate::{util, DeriveTrait, DeriveWhere, Item, SplitGenerics, Trait, TraitImpl};
 
 /// [`TraitImpl`] for [`Deserialize`](https://docs.rs/serde/latest/serde/derive.Deserialize.html).
-#[derive(Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq)]
 pub struct Deserialize {
 	/// [`Deserialize`](https://docs.rs/serde/latest/serde/derive.Deserialize.html) path.
 	pub crate_: Option<Path>,
+	/// User-provided `#[serde(bound)]` predicates for this trait.
+	pub bound: Punctuated<WherePredicate, Token![,]>,
+	/// `serde` attributes on the item, [`None`] if they aren't supported by the
+	/// native implementation.
+	pub container: Option<Container>,
 }
 
 impl TraitImpl for Deserialize {
@@ -31,45 +36,117 @@ impl TraitImpl for Deserialize {
 	where
 		Self: Sized,
 	{
-		DeriveTrait::Deserialize(Self { crate_: None })
+		DeriveTrait::Deserialize(Self {
+			crate_: None,
+			bound: Punctuated::new(),
+			container: None,
+		})
 	}
 
 	fn parse_derive_trait(
 		attrs: &[Attribute],
-		span: Span,
+		_span: Span,
 		list: Option<Punctuated<Meta, syn::Token![,]>>,
 	) -> Result<DeriveTrait>
 	where
 		Self: Sized,
 	{
+		let (crate_, bound) = serde::parse_derive_trait(Trait::Deserialize, attrs, list)?;
+
+		// Internally tagged and untagged enums have to be buffered before they can be
+		// deserialized, which requires `serde`'s private API. They are always
+		// forwarded to `serde`.
+		let container =
+			Container::from_attrs(attrs).filter(|container| container.tagging == Tagging::External);
+
 		Ok(DeriveTrait::Deserialize(Self {
-			crate_: serde::parse_derive_trait(Trait::Deserialize, attrs, span, list)?,
+			crate_,
+			bound,
+			container,
 		}))
 	}
 
+	fn lifetime() -> Option<&'static str>
+	where
+		Self: Sized,
+	{
+		Some("de")
+	}
+
 	fn path(&self) -> Path {
 		let crate_ = self.crate_();
 		syn::parse2::<Path>(quote! { #crate_::Deserialize<'de> }).unwrap()
 	}
 
-	fn impl_item(
+	fn impl_with_item(
 		&self,
 		crate_: Option<&Path>,
 		full_item: &DeriveInput,
-		_: &ImplGenerics<'_>,
-		_: &Ident,
-		_: &TypeGenerics<'_>,
+		item: &Item,
+		ty: &TypeGenerics<'_>,
 		where_clause: &Option<Cow<'_, WhereClause>>,
-		_: TokenStream,
+		body: &TokenStream,
+	) -> Option<TokenStream> {
+		// Forward to `serde` if the native implementation isn't supported.
+		if body.is_empty() {
+			return Some(serde::impl_item(
+				crate_,
+				&self.crate_(),
+				Trait::Deserialize,
+				full_item,
+				item,
+				where_clause,
+			));
+		}
+
+		// Add the `'de` lifetime, which depends on the borrowed fields.
+		let mut generics = full_item.generics.clone();
+		generics
+			.params
+			.insert(0, GenericParam::Lifetime(serde::de_lifetime(item)));
+		let (imp, _, _) = generics.split_for_impl();
+		let ident = item.ident();
+		let path = self.path();
+
+		Some(quote! {
+			#[automatically_derived]
+			impl #imp #path for #ident #ty
+			#where_clause
+			{
+				#body
+			}
+		})
+	}
+
+	fn build_signature(
+		&self,
+		derive_where: &DeriveWhere,
+		item: &Item,
+		generics: &SplitGenerics<'_>,
+		_body: &TokenStream,
 	) -> TokenStream {
-		serde::impl_item(
-			crate_,
+		let container = if let Some(container) = &self.container {
+			container
+		} else {
+			return TokenStream::new();
+		};
+
+		// The visitor requires the same `where` clause as the implementation.
+		let mut where_clause = generics.where_clause.map(Cow::Borrowed);
+		derive_where.where_clause(
+			&mut where_clause,
+			&DeriveTrait::Deserialize(self.clone()),
+			item,
+		);
+
+		serde::build_deserialize(
 			&self.crate_(),
-			format_ident!("Deserialize"),
-			format_ident!("deserialize"),
-			full_item,
-			where_clause,
+			container,
+			item,
+			generics.generics,
+			where_clause.as_deref(),
 		)
+		.unwrap_or_default()
 	}
 }
 
@@ -24,7 +24,7 @@ impl TraitImpl for Eq {
 		util::path_from_strs(&["core", "cmp", "Eq"])
 	}
 
-	fn additional_impl(&self) -> Option<(Path, TokenStream)> {
+	fn additional_impl(&self, _: &DeriveWhere, _: &Item) -> Option<(Path, TokenStream)> {
 		Some((self.path(), quote! {}))
 	}
 
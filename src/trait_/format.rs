@@ -0,0 +1,108 @@
+//! [`Format`](https://docs.rs/defmt/latest/defmt/trait.Format.html) implementation.
+
+use std::ops::Deref;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::LitStr;
+
+use crate::{
+	util, Data, DeriveTrait, DeriveWhere, Item, SimpleType, SplitGenerics, Trait, TraitImpl,
+};
+
+/// [`TraitImpl`] for [`Format`](https://docs.rs/defmt/latest/defmt/trait.Format.html).
+pub struct Format;
+
+impl TraitImpl for Format {
+	fn as_str() -> &'static str {
+		"Format"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::Format
+	}
+
+	fn path(&self) -> syn::Path {
+		util::path_from_strs(&["defmt", "Format"])
+	}
+
+	fn build_signature(
+		&self,
+		_derive_where: &DeriveWhere,
+		_item: &Item,
+		_generics: &SplitGenerics<'_>,
+		body: &TokenStream,
+	) -> TokenStream {
+		quote! {
+			fn format(&self, __f: ::defmt::Formatter<'_>) {
+				match self {
+					#body
+				}
+			}
+		}
+	}
+
+	fn build_body(&self, _derive_where: &DeriveWhere, data: &Data) -> TokenStream {
+		let self_pattern = &data.self_pattern();
+		let name = data.ident.to_string();
+
+		// Format strings mirror the output of `Debug`, with `{=?}` for every field.
+		// Fields of `secret` items are printed as `[REDACTED]` instead.
+		let value = if data.secret.is_secret() {
+			"[REDACTED]"
+		} else {
+			"{=?}"
+		};
+
+		let format = match data.simple_type() {
+			SimpleType::Struct(_) => {
+				let mut fields: Vec<_> = data
+					.iter_field_ident(**self)
+					.map(|field| format!("{}: {}", field, value))
+					.collect();
+
+				if data.any_skip_trait(**self) {
+					fields.push(String::from(".."));
+				}
+
+				if fields.is_empty() {
+					name
+				} else {
+					format!("{} {{{{ {} }}}}", name, fields.join(", "))
+				}
+			}
+			SimpleType::Tuple(_) => {
+				let fields: Vec<_> = data.iter_self_ident(**self).map(|_| value).collect();
+
+				if fields.is_empty() {
+					name
+				} else {
+					format!("{}({})", name, fields.join(", "))
+				}
+			}
+			SimpleType::Unit(_) => name,
+			SimpleType::Union => unreachable!("unexpected trait for union"),
+		};
+		let format = LitStr::new(&format, Span::call_site());
+
+		if data.secret.is_secret() {
+			quote! {
+				#self_pattern => ::defmt::write!(__f, #format),
+			}
+		} else {
+			let self_ident = data.iter_self_ident(**self);
+
+			quote! {
+				#self_pattern => ::defmt::write!(__f, #format #(, #self_ident)*),
+			}
+		}
+	}
+}
+
+impl Deref for Format {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::Format
+	}
+}
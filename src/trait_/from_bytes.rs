@@ -0,0 +1,47 @@
+//! [`FromBytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.FromBytes.html) implementation.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::TokenStream;
+use syn::{DeriveInput, Ident, ImplGenerics, Path, TypeGenerics, WhereClause};
+
+use super::zerocopy;
+use crate::{util, DeriveTrait, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`FromBytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.FromBytes.html).
+pub struct FromBytes;
+
+impl TraitImpl for FromBytes {
+	fn as_str() -> &'static str {
+		"FromBytes"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::FromBytes
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_strs(&["zerocopy", "FromBytes"])
+	}
+
+	fn impl_item(
+		&self,
+		crate_: Option<&Path>,
+		full_item: &DeriveInput,
+		_: &ImplGenerics<'_>,
+		_: &Ident,
+		_: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		_: TokenStream,
+	) -> TokenStream {
+		zerocopy::impl_item(crate_, Trait::FromBytes, full_item, where_clause)
+	}
+}
+
+impl Deref for FromBytes {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::FromBytes
+	}
+}
@@ -0,0 +1,47 @@
+//! [`FromZeros`](https://docs.rs/zerocopy/latest/zerocopy/trait.FromZeros.html) implementation.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::TokenStream;
+use syn::{DeriveInput, Ident, ImplGenerics, Path, TypeGenerics, WhereClause};
+
+use super::zerocopy;
+use crate::{util, DeriveTrait, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`FromZeros`](https://docs.rs/zerocopy/latest/zerocopy/trait.FromZeros.html).
+pub struct FromZeros;
+
+impl TraitImpl for FromZeros {
+	fn as_str() -> &'static str {
+		"FromZeros"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::FromZeros
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_strs(&["zerocopy", "FromZeros"])
+	}
+
+	fn impl_item(
+		&self,
+		crate_: Option<&Path>,
+		full_item: &DeriveInput,
+		_: &ImplGenerics<'_>,
+		_: &Ident,
+		_: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		_: TokenStream,
+	) -> TokenStream {
+		zerocopy::impl_item(crate_, Trait::FromZeros, full_item, where_clause)
+	}
+}
+
+impl Deref for FromZeros {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::FromZeros
+	}
+}
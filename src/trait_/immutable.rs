@@ -0,0 +1,47 @@
+//! [`Immutable`](https://docs.rs/zerocopy/latest/zerocopy/trait.Immutable.html) implementation.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::TokenStream;
+use syn::{DeriveInput, Ident, ImplGenerics, Path, TypeGenerics, WhereClause};
+
+use super::zerocopy;
+use crate::{util, DeriveTrait, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`Immutable`](https://docs.rs/zerocopy/latest/zerocopy/trait.Immutable.html).
+pub struct Immutable;
+
+impl TraitImpl for Immutable {
+	fn as_str() -> &'static str {
+		"Immutable"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::Immutable
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_strs(&["zerocopy", "Immutable"])
+	}
+
+	fn impl_item(
+		&self,
+		crate_: Option<&Path>,
+		full_item: &DeriveInput,
+		_: &ImplGenerics<'_>,
+		_: &Ident,
+		_: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		_: TokenStream,
+	) -> TokenStream {
+		zerocopy::impl_item(crate_, Trait::Immutable, full_item, where_clause)
+	}
+}
+
+impl Deref for Immutable {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::Immutable
+	}
+}
@@ -0,0 +1,47 @@
+//! [`IntoBytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html) implementation.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::TokenStream;
+use syn::{DeriveInput, Ident, ImplGenerics, Path, TypeGenerics, WhereClause};
+
+use super::zerocopy;
+use crate::{util, DeriveTrait, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`IntoBytes`](https://docs.rs/zerocopy/latest/zerocopy/trait.IntoBytes.html).
+pub struct IntoBytes;
+
+impl TraitImpl for IntoBytes {
+	fn as_str() -> &'static str {
+		"IntoBytes"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::IntoBytes
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_strs(&["zerocopy", "IntoBytes"])
+	}
+
+	fn impl_item(
+		&self,
+		crate_: Option<&Path>,
+		full_item: &DeriveInput,
+		_: &ImplGenerics<'_>,
+		_: &Ident,
+		_: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		_: TokenStream,
+	) -> TokenStream {
+		zerocopy::impl_item(crate_, Trait::IntoBytes, full_item, where_clause)
+	}
+}
+
+impl Deref for IntoBytes {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::IntoBytes
+	}
+}
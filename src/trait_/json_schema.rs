@@ -0,0 +1,184 @@
+//! [`JsonSchema`](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html) implementation.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+	punctuated::Punctuated, spanned::Spanned, Attribute, DeriveInput, Expr, ExprLit, Lit, Meta,
+	Path, Result, Token, TypeGenerics, WhereClause, WherePredicate,
+};
+
+use crate::{
+	util, Data, DeriveTrait, DeriveWhere, Either, Error, Item, SplitGenerics, Trait, TraitImpl,
+	DERIVE_WHERE,
+};
+
+/// [`TraitImpl`] for [`JsonSchema`](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html).
+#[derive(Clone, Eq, PartialEq)]
+pub struct JsonSchema {
+	/// User-provided `#[schemars(bound)]` predicates.
+	pub bound: Punctuated<WherePredicate, Token![,]>,
+}
+
+impl TraitImpl for JsonSchema {
+	fn as_str() -> &'static str {
+		"JsonSchema"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::JsonSchema(Self {
+			bound: Punctuated::new(),
+		})
+	}
+
+	fn parse_derive_trait(
+		attrs: &[Attribute],
+		span: Span,
+		list: Option<Punctuated<Meta, Token![,]>>,
+	) -> Result<DeriveTrait> {
+		if list.is_some() {
+			return Err(Error::options(span, Self::as_str()));
+		}
+
+		let mut bound = Punctuated::new();
+
+		for attr in attrs {
+			if !attr.path().is_ident("schemars") {
+				continue;
+			}
+
+			if let Ok(nested) =
+				attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+			{
+				for meta in nested {
+					if !meta.path().is_ident("bound") {
+						continue;
+					}
+
+					match &meta {
+						Meta::NameValue(name_value) => match &name_value.value {
+							Expr::Lit(ExprLit {
+								lit: Lit::Str(lit_str),
+								..
+							}) => bound.extend(
+								lit_str
+									.parse_with(
+										Punctuated::<WherePredicate, Token![,]>::parse_terminated,
+									)
+									.map_err(|error| {
+										Error::bound_predicates(lit_str.span(), error)
+									})?,
+							),
+							value => return Err(Error::option_syntax(value.span())),
+						},
+						_ => return Err(Error::option_syntax(meta.span())),
+					}
+				}
+			}
+		}
+
+		Ok(DeriveTrait::JsonSchema(Self { bound }))
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_strs(&["schemars", "JsonSchema"])
+	}
+
+	fn impl_with_item(
+		&self,
+		crate_: Option<&Path>,
+		full_item: &DeriveInput,
+		item: &Item,
+		_: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		_: &TokenStream,
+	) -> Option<TokenStream> {
+		let derive_where = crate_
+			.map(Cow::Borrowed)
+			.unwrap_or_else(|| Cow::Owned(util::path_from_strs(&[DERIVE_WHERE])));
+
+		// Combine the `where` clause with user-provided `bound`s.
+		let mut bounds = where_clause
+			.as_ref()
+			.map(|where_clause| where_clause.predicates.clone())
+			.unwrap_or_default();
+		bounds.extend(self.bound.iter().cloned());
+		let bounds = bounds.to_token_stream().to_string();
+
+		// `bound`s are already combined and would otherwise conflict, `schemars`
+		// also reads them from `serde` attributes.
+		let mut full_item = full_item.clone();
+		util::remove_option(&mut full_item.attrs, "schemars", "bound");
+		util::remove_option(&mut full_item.attrs, "serde", "bound");
+
+		// Translate fields skipped by `derive_where` to `schemars`.
+		add_skip(&mut full_item, item);
+
+		Some(quote! {
+			#[::core::prelude::v1::derive(::schemars::JsonSchema)]
+			#[schemars(bound = #bounds)]
+			#[#derive_where::derive_where_serde]
+			#full_item
+		})
+	}
+
+	fn build_signature(
+		&self,
+		_derive_where: &DeriveWhere,
+		_item: &Item,
+		_generics: &SplitGenerics<'_>,
+		_body: &TokenStream,
+	) -> TokenStream {
+		TokenStream::new()
+	}
+}
+
+impl Deref for JsonSchema {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::JsonSchema
+	}
+}
+
+/// Adds `skip` to the `schemars` attributes of all fields skipped by
+/// `derive_where` for [`JsonSchema`], or for both `Deserialize` and
+/// `Serialize`. Fields only skipped for one of them are marked with
+/// `skip_deserializing` or `skip_serializing`, like `serde` would.
+fn add_skip(full_item: &mut DeriveInput, item: &Item) {
+	let add = |fields: &mut syn::Fields, data: &Data| {
+		if let Either::Left(data_fields) = data.fields() {
+			for (field, data_field) in fields.iter_mut().zip(&data_fields.fields) {
+				let skipped = |trait_| data.skip(trait_) || data_field.skip(trait_);
+
+				if skipped(Trait::JsonSchema) {
+					field.attrs.push(syn::parse_quote! { #[schemars(skip)] });
+					continue;
+				}
+
+				#[cfg(feature = "serde")]
+				match (skipped(Trait::Deserialize), skipped(Trait::Serialize)) {
+					(true, true) => field.attrs.push(syn::parse_quote! { #[schemars(skip)] }),
+					(true, false) => field
+						.attrs
+						.push(syn::parse_quote! { #[schemars(skip_deserializing)] }),
+					(false, true) => field
+						.attrs
+						.push(syn::parse_quote! { #[schemars(skip_serializing)] }),
+					(false, false) => (),
+				}
+			}
+		}
+	};
+
+	match (&mut full_item.data, item) {
+		(syn::Data::Struct(struct_), Item::Item(data)) => add(&mut struct_.fields, data),
+		(syn::Data::Enum(enum_), Item::Enum { variants, .. }) => {
+			for (variant, data) in enum_.variants.iter_mut().zip(variants) {
+				add(&mut variant.fields, data)
+			}
+		}
+		_ => (),
+	}
+}
@@ -0,0 +1,47 @@
+//! [`KnownLayout`](https://docs.rs/zerocopy/latest/zerocopy/trait.KnownLayout.html) implementation.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::TokenStream;
+use syn::{DeriveInput, Ident, ImplGenerics, Path, TypeGenerics, WhereClause};
+
+use super::zerocopy;
+use crate::{util, DeriveTrait, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`KnownLayout`](https://docs.rs/zerocopy/latest/zerocopy/trait.KnownLayout.html).
+pub struct KnownLayout;
+
+impl TraitImpl for KnownLayout {
+	fn as_str() -> &'static str {
+		"KnownLayout"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::KnownLayout
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_strs(&["zerocopy", "KnownLayout"])
+	}
+
+	fn impl_item(
+		&self,
+		crate_: Option<&Path>,
+		full_item: &DeriveInput,
+		_: &ImplGenerics<'_>,
+		_: &Ident,
+		_: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		_: TokenStream,
+	) -> TokenStream {
+		zerocopy::impl_item(crate_, Trait::KnownLayout, full_item, where_clause)
+	}
+}
+
+impl Deref for KnownLayout {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::KnownLayout
+	}
+}
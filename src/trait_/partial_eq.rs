@@ -2,8 +2,13 @@
 
 use std::ops::Deref;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
+#[cfg(feature = "nightly")]
+use syn::Path;
+use syn::{punctuated::Punctuated, Attribute, Meta, Result, Token};
+#[cfg(feature = "subtle")]
+use syn::{TraitBound, TraitBoundModifier, TypeParamBound};
 
 use super::common_ord::build_incomparable_pattern;
 use crate::{
@@ -11,7 +16,11 @@ use crate::{
 };
 
 /// [`TraitImpl`] for [`PartialEq`](trait@std::cmp::PartialEq).
-pub struct PartialEq;
+#[derive(Eq, PartialEq)]
+pub struct PartialEq {
+	/// If this is a `const` implementation.
+	pub const_: bool,
+}
 
 impl TraitImpl for PartialEq {
 	fn as_str() -> &'static str {
@@ -19,13 +28,65 @@ impl TraitImpl for PartialEq {
 	}
 
 	fn default_derive_trait() -> DeriveTrait {
-		DeriveTrait::PartialEq
+		DeriveTrait::PartialEq(Self { const_: false })
+	}
+
+	fn parse_derive_trait(
+		_: &[Attribute],
+		_: Span,
+		list: Option<Punctuated<Meta, Token![,]>>,
+	) -> Result<DeriveTrait> {
+		Ok(DeriveTrait::PartialEq(Self {
+			const_: super::parse_const(Self::as_str(), list)?,
+		}))
+	}
+
+	fn is_const(&self) -> bool {
+		self.const_
+	}
+
+	#[cfg(feature = "subtle")]
+	fn additional_where_bounds(derive_where: &DeriveWhere, data: &Item) -> Option<TypeParamBound> {
+		// Fields of `secret` items are compared with `ConstantTimeEq`.
+		if data.is_secret() {
+			Some(TypeParamBound::Trait(TraitBound {
+				paren_token: None,
+				modifier: TraitBoundModifier::None,
+				lifetimes: None,
+				path: util::path_from_root_and_strs(subtle(derive_where), &["ConstantTimeEq"]),
+			}))
+		} else {
+			None
+		}
 	}
 
 	fn path(&self) -> syn::Path {
 		util::path_from_strs(&["core", "cmp", "PartialEq"])
 	}
 
+	#[cfg(feature = "nightly")]
+	fn additional_impl(
+		&self,
+		derive_where: &DeriveWhere,
+		item: &Item,
+	) -> Option<(Path, TokenStream)> {
+		// Like std's `derive`, allow constants to be used in patterns. Only sound if
+		// `Eq` is implemented with the same bounds, no field is skipped and fields
+		// aren't compared with `ConstantTimeEq`, otherwise structural equality would
+		// differ from this implementation.
+		if derive_where.contains(Trait::Eq)
+			&& !item.any_skip_trait(Trait::PartialEq)
+			&& !item.is_secret()
+		{
+			Some((
+				util::path_from_strs(&["core", "marker", "StructuralPartialEq"]),
+				quote! {},
+			))
+		} else {
+			None
+		}
+	}
+
 	fn build_signature(
 		&self,
 		_derive_where: &DeriveWhere,
@@ -33,6 +94,17 @@ impl TraitImpl for PartialEq {
 		_generics: &SplitGenerics<'_>,
 		body: &TokenStream,
 	) -> TokenStream {
+		// `Discriminant` can't be compared in `const` contexts.
+		let discriminant_eq = if self.const_ {
+			quote! {
+				::core::intrinsics::discriminant_value(self) == ::core::intrinsics::discriminant_value(__other)
+			}
+		} else {
+			quote! {
+				::core::mem::discriminant(self) == ::core::mem::discriminant(__other)
+			}
+		};
+
 		let body = {
 			match item {
 				// If the whole item is incomparable return false
@@ -61,7 +133,7 @@ impl TraitImpl for PartialEq {
 					let incomparable = build_incomparable_pattern(variants).into_iter();
 
 					quote! {
-						if ::core::mem::discriminant(self) == ::core::mem::discriminant(__other) {
+						if #discriminant_eq {
 							match (self, __other) {
 								#body
 								#((#incomparable, ..) => false,)*
@@ -77,7 +149,7 @@ impl TraitImpl for PartialEq {
 				Item::Enum { variants, .. } if variants.len() > 1 && item.is_empty(**self) => {
 					let incomparable = build_incomparable_pattern(variants).into_iter();
 					quote! {
-						if ::core::mem::discriminant(self) == ::core::mem::discriminant(__other) {
+						if #discriminant_eq {
 							#(if ::core::matches!(self, #incomparable) {
 								return false;
 							})*
@@ -110,7 +182,11 @@ impl TraitImpl for PartialEq {
 		}
 	}
 
-	fn build_body(&self, _derive_where: &DeriveWhere, data: &Data) -> TokenStream {
+	fn build_body(
+		&self,
+		#[cfg_attr(not(feature = "subtle"), allow(unused_variables))] derive_where: &DeriveWhere,
+		data: &Data,
+	) -> TokenStream {
 		if data.is_empty(**self) || data.is_incomparable() {
 			TokenStream::new()
 		} else {
@@ -122,6 +198,23 @@ impl TraitImpl for PartialEq {
 					let self_ident = data.iter_self_ident(**self);
 					let other_ident = data.iter_other_ident(**self);
 
+					// `&` doesn't short-circuit, so all fields of `secret` items are always
+					// compared in constant time.
+					#[cfg(feature = "subtle")]
+					if data.secret.is_secret() {
+						let subtle = subtle(derive_where);
+						let choice = util::path_from_root_and_strs(subtle.clone(), &["Choice"]);
+						let constant_time_eq =
+							util::path_from_root_and_strs(subtle, &["ConstantTimeEq"]);
+
+						return quote! {
+							(#self_pattern, #other_pattern) =>
+								<::core::primitive::bool as ::core::convert::From<#choice>>::from(
+									#choice::from(1) #(& #constant_time_eq::ct_eq(#self_ident, #other_ident))*
+								),
+						};
+					}
+
 					quote! {
 						(#self_pattern, #other_pattern) =>
 							true #(&& #trait_path::eq(#self_ident, #other_ident))*,
@@ -141,3 +234,18 @@ impl Deref for PartialEq {
 		&Trait::PartialEq
 	}
 }
+
+/// Returns the path to the `subtle` crate, taken from the `crate` option of
+/// [`ConstantTimeEq`](https://docs.rs/subtle/latest/subtle/trait.ConstantTimeEq.html)
+/// if derived by the same [`DeriveWhere`].
+#[cfg(feature = "subtle")]
+fn subtle(derive_where: &DeriveWhere) -> syn::Path {
+	derive_where
+		.traits
+		.iter()
+		.find_map(|trait_| match trait_ {
+			DeriveTrait::ConstantTimeEq(constant_time_eq) => Some(constant_time_eq.crate_()),
+			_ => None,
+		})
+		.unwrap_or_else(|| util::path_from_strs(&["subtle"]))
+}
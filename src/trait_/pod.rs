@@ -0,0 +1,145 @@
+//! [`Pod`](https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html) implementation.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+	punctuated::Punctuated, Attribute, DeriveInput, Generics, Ident, ImplGenerics, LitInt, Meta,
+	Path, Result, Token, TypeGenerics, WhereClause,
+};
+
+use super::bytemuck;
+use crate::{util, Data, DeriveTrait, DeriveWhere, Error, Item, SplitGenerics, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`Pod`](https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html).
+pub struct Pod;
+
+impl TraitImpl for Pod {
+	fn as_str() -> &'static str {
+		"Pod"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::Pod
+	}
+
+	fn supports_enum() -> bool {
+		false
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_strs(&["bytemuck", "Pod"])
+	}
+
+	fn impl_item(
+		&self,
+		_: Option<&Path>,
+		full_item: &DeriveInput,
+		imp: &ImplGenerics<'_>,
+		ident: &Ident,
+		ty: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		body: TokenStream,
+	) -> TokenStream {
+		let mut output =
+			bytemuck::impl_item(**self, &self.path(), imp, ident, ty, where_clause, body);
+
+		// Padding can only be checked without generics, which is enforced by
+		// `Pod::check_layout()` unless padding is impossible. Fields can't be
+		// skipped for `Pod`, so they are taken from the full item.
+		let fields = match &full_item.data {
+			syn::Data::Struct(data) => Some(&data.fields),
+			syn::Data::Enum(_) | syn::Data::Union(_) => None,
+		};
+
+		if let (true, Some(fields)) = (full_item.generics.params.is_empty(), fields) {
+			let types = fields.iter().map(|field| &field.ty);
+
+			output.extend(quote! {
+				const _: () = ::core::assert!(
+					::core::mem::size_of::<#ident>() == 0 #(+ ::core::mem::size_of::<#types>())*,
+					"`Pod` doesn't support types with padding"
+				);
+			});
+		}
+
+		output
+	}
+
+	fn build_signature(
+		&self,
+		_derive_where: &DeriveWhere,
+		_item: &Item,
+		_generics: &SplitGenerics<'_>,
+		body: &TokenStream,
+	) -> TokenStream {
+		bytemuck::build_signature(**self, &self.path(), body)
+	}
+
+	fn build_body(&self, _derive_where: &DeriveWhere, data: &Data) -> TokenStream {
+		bytemuck::build_body(**self, data)
+	}
+}
+
+impl Pod {
+	/// Check that the item has a layout supported by [`Pod`]. Items with
+	/// generics have to be `#[repr(transparent)]` or `#[repr(packed)]` without
+	/// an alignment above `1`, because their padding can't be checked.
+	pub fn check_layout(span: Span, attrs: &[Attribute], generics: &Generics) -> Result<()> {
+		// A defined layout was found.
+		let mut defined = false;
+		// The layout can't contain any padding.
+		let mut no_padding = false;
+		// The alignment was raised by `align(..)`, which adds padding.
+		let mut align = false;
+
+		for attr in attrs {
+			if !attr.path().is_ident("repr") {
+				continue;
+			}
+
+			let list = attr
+				.meta
+				.require_list()?
+				.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+			for meta in list {
+				if meta.path().is_ident("C") {
+					defined = true;
+				} else if meta.path().is_ident("transparent") {
+					defined = true;
+					no_padding = true;
+				} else if meta.path().is_ident("packed") {
+					defined = true;
+
+					// Only `packed(1)` removes all padding.
+					no_padding |= match &meta {
+						Meta::List(list) => {
+							list.parse_args::<LitInt>()?.base10_parse::<u64>()? == 1
+						}
+						_ => true,
+					};
+				} else if meta.path().is_ident("align") {
+					align = true;
+				}
+			}
+		}
+
+		if !defined {
+			Err(Error::layout_repr(span, Self::as_str()))
+		} else if !generics.params.is_empty() && (align || !no_padding) {
+			Err(Error::pod_generics(span))
+		} else {
+			Ok(())
+		}
+	}
+}
+
+impl Deref for Pod {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::Pod
+	}
+}
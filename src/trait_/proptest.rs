@@ -0,0 +1,209 @@
+//! [`Arbitrary`](https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html)
+//! implementation from `proptest`.
+
+use std::ops::Deref;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+
+use crate::{Data, DeriveTrait, DeriveWhere, Either, Item, SplitGenerics, Trait, TraitImpl};
+
+/// Maximum number of strategies supported by a single
+/// [`TupleUnion`](https://docs.rs/proptest/latest/proptest/strategy/struct.TupleUnion.html).
+const MAX_UNION: usize = 10;
+
+/// [`TraitImpl`] for [`Arbitrary`](https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html)
+/// from `proptest`.
+pub struct ProptestArbitrary;
+
+impl TraitImpl for ProptestArbitrary {
+	fn as_str() -> &'static str {
+		"ProptestArbitrary"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::ProptestArbitrary
+	}
+
+	fn path(&self) -> Path {
+		syn::parse2(quote! { ::proptest::arbitrary::Arbitrary }).expect("failed to parse path")
+	}
+
+	fn build_signature(
+		&self,
+		_derive_where: &DeriveWhere,
+		item: &Item,
+		_generics: &SplitGenerics<'_>,
+		_body: &TokenStream,
+	) -> TokenStream {
+		let Strategy { type_, expr } = match item {
+			Item::Item(data) => build_strategy(data),
+			Item::Enum { variants, .. } => build_union(
+				variants
+					.iter()
+					.map(|data| (data.proptest_weight(), build_strategy(data)))
+					.collect(),
+			),
+		};
+
+		quote! {
+			type Parameters = ();
+			type Strategy = #type_;
+
+			fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+				#expr
+			}
+		}
+	}
+}
+
+impl Deref for ProptestArbitrary {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::ProptestArbitrary
+	}
+}
+
+/// Type and expression of a generated strategy.
+struct Strategy {
+	/// Type of the strategy.
+	type_: TokenStream,
+	/// Expression creating the strategy.
+	expr: TokenStream,
+}
+
+/// Build a strategy generating `data`. Fields are generated with `any` or
+/// their `strategy` option, skipped fields are filled with [`Default`].
+fn build_strategy(data: &Data) -> Strategy {
+	let path = &data.path;
+	let default_path = Trait::Default.default_derive_trait().path();
+
+	let fields = match data.fields() {
+		Either::Left(fields) if !data.is_empty(Trait::ProptestArbitrary) => &fields.fields,
+		// Without any fields to generate, the value is constructed lazily.
+		_ => {
+			let constructor = match data.fields() {
+				Either::Left(fields) => {
+					let fields = fields.fields.iter().map(|field| {
+						let member = &field.member;
+						quote! { #member: #default_path::default() }
+					});
+
+					quote! { #path { #(#fields),* } }
+				}
+				Either::Right(_) => quote! { #path },
+			};
+
+			return Strategy {
+				type_: quote! { ::proptest::strategy::LazyJust<Self, fn() -> Self> },
+				expr: quote! {
+					::proptest::strategy::LazyJust::new((|| #constructor) as fn() -> Self)
+				},
+			};
+		}
+	};
+
+	// Strategies, value types and patterns of all fields not skipped, which are
+	// nested in pairs to support any number of fields.
+	let mut strategies = data
+		.iter_fields(Trait::ProptestArbitrary)
+		.map(|field| {
+			let type_ = field.type_;
+			let ident = &field.self_ident;
+
+			let strategy = if let Some(strategy) = &field.attr.proptest_strategy.0 {
+				Strategy {
+					type_: quote! { ::proptest::strategy::BoxedStrategy<#type_> },
+					expr: quote! { ::proptest::strategy::Strategy::boxed(#strategy) },
+				}
+			} else {
+				Strategy {
+					type_: quote! { <#type_ as ::proptest::arbitrary::Arbitrary>::Strategy },
+					expr: quote! { ::proptest::arbitrary::any::<#type_>() },
+				}
+			};
+
+			(strategy, quote! { #type_ }, quote! { #ident })
+		})
+		.rev();
+
+	let (last, last_value, last_pattern) = strategies.next().expect("no fields found");
+	let (strategy, value, pattern) = strategies.fold(
+		(last, last_value, last_pattern),
+		|(rest, rest_value, rest_pattern), (strategy, value, pattern)| {
+			let Strategy {
+				type_: rest_type,
+				expr: rest_expr,
+			} = rest;
+			let Strategy { type_, expr } = strategy;
+
+			(
+				Strategy {
+					type_: quote! { (#type_, #rest_type) },
+					expr: quote! { (#expr, #rest_expr) },
+				},
+				quote! { (#value, #rest_value) },
+				quote! { (#pattern, #rest_pattern) },
+			)
+		},
+	);
+
+	let fields = fields.iter().map(|field| {
+		let member = &field.member;
+
+		if field.skip(Trait::ProptestArbitrary) || data.skip(Trait::ProptestArbitrary) {
+			quote! { #member: #default_path::default() }
+		} else {
+			let ident = &field.self_ident;
+			quote! { #member: #ident }
+		}
+	});
+
+	let Strategy { type_, expr } = strategy;
+
+	Strategy {
+		type_: quote! { ::proptest::strategy::Map<#type_, fn(#value) -> Self> },
+		expr: quote! {
+			::proptest::strategy::Strategy::prop_map(
+				#expr,
+				(|#pattern| #path { #(#fields),* }) as fn(#value) -> Self,
+			)
+		},
+	}
+}
+
+/// Build a weighted union of the given strategies, like `prop_oneof!`. If
+/// there are more strategies than a single union supports, the remaining ones
+/// are nested in the last option.
+fn build_union(mut strategies: Vec<(u32, Strategy)>) -> Strategy {
+	if strategies.len() == 1 {
+		return strategies.pop().expect("no variants found").1;
+	}
+
+	if strategies.len() > MAX_UNION {
+		let rest = strategies.split_off(MAX_UNION - 1);
+		// The sum of all weights was checked to fit in a `u32` when parsing.
+		let weight = rest
+			.iter()
+			.try_fold(0_u32, |sum, (weight, _)| sum.checked_add(*weight))
+			.expect("sum of weights overflowed");
+		strategies.push((weight, build_union(rest)));
+	}
+
+	let (types, exprs): (Vec<_>, Vec<_>) = strategies
+		.into_iter()
+		.map(|(weight, Strategy { type_, expr })| {
+			(
+				quote! { (u32, ::proptest::std_facade::Arc<#type_>) },
+				quote! { (#weight, ::proptest::std_facade::Arc::new(#expr)) },
+			)
+		})
+		.unzip();
+
+	Strategy {
+		type_: quote! { ::proptest::strategy::TupleUnion<(#(#types,)*)> },
+		expr: quote! { ::proptest::strategy::TupleUnion::new((#(#exprs,)*)) },
+	}
+}
@@ -0,0 +1,128 @@
+//! Common functionality between the
+//! [`rkyv`](https://docs.rs/rkyv/latest/rkyv/) traits.
+
+use std::borrow::Cow;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+	punctuated::Punctuated, spanned::Spanned, Attribute, DeriveInput, Meta, Path, Token,
+	WhereClause,
+};
+
+use crate::{
+	attr::{parse_crate_options, parse_path},
+	util, Data, Either, Error, Item, Result, Trait, DERIVE_WHERE,
+};
+
+/// Parse the `crate` option or `#[rkyv(crate = ..)]`.
+pub fn parse_derive_trait(
+	trait_: Trait,
+	attrs: &[Attribute],
+	list: Option<Punctuated<Meta, Token![,]>>,
+) -> Result<Option<Path>> {
+	let mut crate_ = parse_crate_options(list, trait_.as_str(), "rkyv")?;
+
+	for attr in attrs {
+		if !attr.path().is_ident("rkyv") {
+			continue;
+		}
+
+		if let Ok(nested) = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+			for meta in nested {
+				if let Meta::NameValue(name_value) = &meta {
+					if name_value.path.is_ident("crate") {
+						// Don't allow `#[rkyv(crate = ..)]` together with the `crate` option.
+						if crate_.is_some() {
+							return Err(Error::rkyv_crate(name_value.span()));
+						}
+
+						crate_ = Some(parse_path(&name_value.value)?);
+					}
+				}
+			}
+		}
+	}
+
+	Ok(crate_)
+}
+
+/// Forward the item to `rkyv`'s derive with the `where` clause generated by
+/// `derive_where`.
+pub fn impl_item(
+	derive_where: Option<&Path>,
+	rkyv: &Path,
+	trait_: Trait,
+	full_item: &DeriveInput,
+	item: &Item,
+	where_clause: &Option<Cow<'_, WhereClause>>,
+) -> TokenStream {
+	let derive_where = derive_where
+		.map(Cow::Borrowed)
+		.unwrap_or_else(|| Cow::Owned(util::path_from_strs(&[DERIVE_WHERE])));
+
+	// `rkyv` only bounds field types, so generics that only appear in
+	// `PhantomData` stay unbounded unless `derive_where` adds a bound. This also
+	// applies the bounds to the archived item.
+	let mut full_item = full_item.clone();
+	full_item.generics.where_clause = where_clause.as_deref().cloned();
+
+	// Pass the `crate` option on to `rkyv`.
+	if *rkyv != util::path_from_strs(&["rkyv"])
+		&& !full_item
+			.attrs
+			.iter()
+			.any(|attr| util::has_option(attr, "rkyv", "crate"))
+	{
+		full_item
+			.attrs
+			.push(syn::parse_quote! { #[rkyv(crate = #rkyv)] });
+	}
+
+	// Translate fields skipped by `derive_where` to `rkyv`.
+	if item.any_skip_trait(trait_) {
+		add_skip(&mut full_item, item, trait_, rkyv);
+	}
+
+	let trait_ = format_ident!(
+		"{}",
+		match trait_ {
+			Trait::RkyvDeserialize => "Deserialize",
+			Trait::RkyvSerialize => "Serialize",
+			_ => trait_.as_str(),
+		}
+	);
+
+	quote! {
+		#[::core::prelude::v1::derive(#rkyv::#trait_)]
+		#[#derive_where::derive_where_serde]
+		#full_item
+	}
+}
+
+/// Archives all fields skipped by `derive_where` for `trait_` with
+/// [`Skip`](https://docs.rs/rkyv/latest/rkyv/with/struct.Skip.html), which
+/// uses [`Default`] on deserialization.
+fn add_skip(full_item: &mut DeriveInput, item: &Item, trait_: Trait, rkyv: &Path) {
+	let add = |fields: &mut syn::Fields, data: &Data| {
+		if let Either::Left(data_fields) = data.fields() {
+			for (field, data_field) in fields.iter_mut().zip(&data_fields.fields) {
+				if data.skip(trait_) || data_field.skip(trait_) {
+					field
+						.attrs
+						.push(syn::parse_quote! { #[rkyv(with = #rkyv::with::Skip)] });
+				}
+			}
+		}
+	};
+
+	match (&mut full_item.data, item) {
+		(syn::Data::Struct(struct_), Item::Item(data)) => add(&mut struct_.fields, data),
+		(syn::Data::Enum(enum_), Item::Enum { variants, .. }) => {
+			for (variant, data) in enum_.variants.iter_mut().zip(variants) {
+				add(&mut variant.fields, data)
+			}
+		}
+		_ => (),
+	}
+}
@@ -0,0 +1,81 @@
+//! [`Deserialize`](https://docs.rs/rkyv/latest/rkyv/trait.Deserialize.html) implementation
+//! from `rkyv`.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::{Span, TokenStream};
+use syn::{
+	punctuated::Punctuated, Attribute, DeriveInput, Meta, Path, Result, Token, TypeGenerics,
+	WhereClause,
+};
+
+use super::rkyv;
+use crate::{attr::crate_root, util, DeriveTrait, Item, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`Deserialize`](https://docs.rs/rkyv/latest/rkyv/trait.Deserialize.html)
+/// from `rkyv`.
+#[derive(Eq, PartialEq)]
+pub struct RkyvDeserialize {
+	/// [`Deserialize`](https://docs.rs/rkyv/latest/rkyv/trait.Deserialize.html) path.
+	pub crate_: Option<Path>,
+}
+
+impl TraitImpl for RkyvDeserialize {
+	fn as_str() -> &'static str {
+		"RkyvDeserialize"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::RkyvDeserialize(Self { crate_: None })
+	}
+
+	fn parse_derive_trait(
+		attrs: &[Attribute],
+		_span: Span,
+		list: Option<Punctuated<Meta, Token![,]>>,
+	) -> Result<DeriveTrait> {
+		let crate_ = rkyv::parse_derive_trait(Trait::RkyvDeserialize, attrs, list)?;
+
+		Ok(DeriveTrait::RkyvDeserialize(Self { crate_ }))
+	}
+
+	// `Deserialize` is implemented for the archived item, which only requires
+	// generics to be `Archive`. `rkyv` bounds archived fields itself.
+	fn path(&self) -> Path {
+		util::path_from_root_and_strs(self.crate_(), &["Archive"])
+	}
+
+	fn impl_with_item(
+		&self,
+		crate_: Option<&Path>,
+		full_item: &DeriveInput,
+		item: &Item,
+		_: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		_: &TokenStream,
+	) -> Option<TokenStream> {
+		Some(rkyv::impl_item(
+			crate_,
+			&self.crate_(),
+			Trait::RkyvDeserialize,
+			full_item,
+			item,
+			where_clause,
+		))
+	}
+}
+
+impl RkyvDeserialize {
+	/// Returns the path to the root crate for this trait.
+	fn crate_(&self) -> Path {
+		crate_root(self.crate_.as_ref(), "rkyv")
+	}
+}
+
+impl Deref for RkyvDeserialize {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::RkyvDeserialize
+	}
+}
@@ -0,0 +1,81 @@
+//! [`Serialize`](https://docs.rs/rkyv/latest/rkyv/trait.Serialize.html) implementation
+//! from `rkyv`.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+	punctuated::Punctuated, Attribute, DeriveInput, Meta, Path, Result, Token, TypeGenerics,
+	WhereClause,
+};
+
+use super::rkyv;
+use crate::{attr::crate_root, DeriveTrait, Item, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`Serialize`](https://docs.rs/rkyv/latest/rkyv/trait.Serialize.html)
+/// from `rkyv`.
+#[derive(Eq, PartialEq)]
+pub struct RkyvSerialize {
+	/// [`Serialize`](https://docs.rs/rkyv/latest/rkyv/trait.Serialize.html) path.
+	pub crate_: Option<Path>,
+}
+
+impl TraitImpl for RkyvSerialize {
+	fn as_str() -> &'static str {
+		"RkyvSerialize"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::RkyvSerialize(Self { crate_: None })
+	}
+
+	fn parse_derive_trait(
+		attrs: &[Attribute],
+		_span: Span,
+		list: Option<Punctuated<Meta, Token![,]>>,
+	) -> Result<DeriveTrait> {
+		let crate_ = rkyv::parse_derive_trait(Trait::RkyvSerialize, attrs, list)?;
+
+		Ok(DeriveTrait::RkyvSerialize(Self { crate_ }))
+	}
+
+	fn path(&self) -> Path {
+		let crate_ = self.crate_();
+		syn::parse2::<Path>(quote! { #crate_::Serialize<__S> }).unwrap()
+	}
+
+	fn impl_with_item(
+		&self,
+		crate_: Option<&Path>,
+		full_item: &DeriveInput,
+		item: &Item,
+		_: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		_: &TokenStream,
+	) -> Option<TokenStream> {
+		Some(rkyv::impl_item(
+			crate_,
+			&self.crate_(),
+			Trait::RkyvSerialize,
+			full_item,
+			item,
+			where_clause,
+		))
+	}
+}
+
+impl RkyvSerialize {
+	/// Returns the path to the root crate for this trait.
+	fn crate_(&self) -> Path {
+		crate_root(self.crate_.as_ref(), "rkyv")
+	}
+}
+
+impl Deref for RkyvSerialize {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::RkyvSerialize
+	}
+}
@@ -0,0 +1,34 @@
+//! [`Send`](trait@std::marker::Send) implementation.
+
+use std::ops::Deref;
+
+use crate::{util, DeriveTrait, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`Send`](trait@std::marker::Send).
+pub struct Send;
+
+impl TraitImpl for Send {
+	fn as_str() -> &'static str {
+		"Send"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::Send
+	}
+
+	fn is_unsafe() -> bool {
+		true
+	}
+
+	fn path(&self) -> syn::Path {
+		util::path_from_strs(&["core", "marker", "Send"])
+	}
+}
+
+impl Deref for Send {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::Send
+	}
+}
@@ -2,29 +2,59 @@
 //! [`Deserialize`](https://docs.rs/serde/latest/serde/derive.Deserialize.html) and
 //! [`Serialize`](https://docs.rs/serde/latest/serde/derive.Serialize.html).
 
+mod attr;
+mod de;
+mod ser;
+
 use std::borrow::Cow;
 
-use proc_macro2::{Span, TokenStream};
-use quote::{quote, ToTokens};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
 use syn::{
 	punctuated::Punctuated, spanned::Spanned, Attribute, DeriveInput, Expr, ExprLit, Ident, Lit,
-	Meta, Path, Token, WhereClause,
+	Meta, Path, Token, WhereClause, WherePredicate,
 };
 
-use crate::{util, Error, Result, Trait, DERIVE_WHERE};
+pub use self::{
+	attr::{Container, Tagging},
+	de::{build as build_deserialize, de_lifetime},
+	ser::build as build_serialize,
+};
+use crate::{
+	attr::{parse_crate_path, parse_path},
+	util, Data, Either, Error, Item, Result, Trait, DERIVE_WHERE,
+};
 
-/// Parse `#[serde(crate = "...")]`.
+/// Parse the `crate` option or `#[serde(crate = "...")]` and the `bound`
+/// predicates relevant to `trait_`.
 pub fn parse_derive_trait(
 	trait_: Trait,
 	attrs: &[Attribute],
-	span: Span,
 	list: Option<Punctuated<Meta, Token![,]>>,
-) -> Result<Option<Path>> {
-	if list.is_some() {
-		return Err(Error::options(span, trait_.as_str()));
+) -> Result<(Option<Path>, Punctuated<WherePredicate, Token![,]>)> {
+	let mut crate_ = None;
+	let mut bound = Punctuated::new();
+
+	if let Some(list) = list {
+		for meta in list {
+			match &meta {
+				Meta::NameValue(name_value) if name_value.path.is_ident("crate") => {
+					// Check for duplicate `crate` option.
+					if crate_.is_none() {
+						crate_ = Some(parse_crate_path(&name_value.value, "serde")?);
+					} else {
+						return Err(Error::option_duplicate(name_value.span(), "crate"));
+					}
+				}
+				_ => return Err(Error::option_trait(meta.path().span(), trait_.as_str())),
+			}
+		}
 	}
 
-	let mut crate_ = None;
+	// Whether `crate_` was set by the `crate` option.
+	let crate_option = crate_.is_some();
+	// Whether `crate_` was set by `#[serde(crate = "...")]`.
+	let mut crate_attribute = false;
 
 	for attr in attrs {
 		if !attr.path().is_ident("serde") {
@@ -37,7 +67,8 @@ pub fn parse_derive_trait(
 			{
 				for meta in nested {
 					if meta.path().is_ident("bound") {
-						return Err(Error::serde_bound(meta.span()));
+						parse_bound(trait_, &meta, &mut bound)?;
+						continue;
 					}
 
 					if !meta.path().is_ident("crate") {
@@ -46,22 +77,16 @@ pub fn parse_derive_trait(
 
 					match &meta {
 						Meta::NameValue(name_value) => {
+							// Don't allow `#[serde(crate = "...")]` together with the `crate`
+							// option.
+							if crate_option {
+								return Err(Error::serde_crate(name_value.span()));
+							}
+
 							// Check for duplicate `crate` option.
-							if crate_.is_none() {
-								let path = match &name_value.value {
-									Expr::Lit(ExprLit {
-										lit: Lit::Str(lit_str),
-										..
-									}) => match lit_str.parse::<Path>() {
-										Ok(path) => path,
-										Err(error) => {
-											return Err(Error::path(lit_str.span(), error))
-										}
-									},
-									_ => return Err(Error::option_syntax(name_value.value.span())),
-								};
-
-								crate_ = Some(path);
+							if !crate_attribute {
+								crate_ = Some(parse_path(&name_value.value)?);
+								crate_attribute = true;
 							} else {
 								return Err(Error::option_duplicate(name_value.span(), "crate"));
 							}
@@ -75,28 +100,108 @@ pub fn parse_derive_trait(
 		}
 	}
 
-	Ok(crate_)
+	Ok((crate_, bound))
+}
+
+/// Parse `bound = "..."` or `bound(serialize = "...", deserialize = "...")`
+/// and add the predicates relevant to `trait_` to `bound`.
+fn parse_bound(
+	trait_: Trait,
+	meta: &Meta,
+	bound: &mut Punctuated<WherePredicate, Token![,]>,
+) -> Result<()> {
+	match meta {
+		Meta::NameValue(name_value) => bound.extend(parse_predicates(&name_value.value)?),
+		Meta::List(list) => {
+			let key = match trait_ {
+				Trait::Deserialize => "deserialize",
+				_ => "serialize",
+			};
+
+			for meta in list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)? {
+				match &meta {
+					Meta::NameValue(name_value) if name_value.path.is_ident(key) => {
+						bound.extend(parse_predicates(&name_value.value)?)
+					}
+					Meta::NameValue(name_value)
+						if name_value.path.is_ident("serialize")
+							|| name_value.path.is_ident("deserialize") => {}
+					_ => return Err(Error::option_syntax(meta.span())),
+				}
+			}
+		}
+		Meta::Path(path) => return Err(Error::option_syntax(path.span())),
+	}
+
+	Ok(())
+}
+
+/// Parse [`WherePredicate`]s from a string literal.
+fn parse_predicates(value: &Expr) -> Result<Punctuated<WherePredicate, Token![,]>> {
+	match value {
+		Expr::Lit(ExprLit {
+			lit: Lit::Str(lit_str),
+			..
+		}) => lit_str
+			.parse_with(Punctuated::parse_terminated)
+			.map_err(|error| Error::bound_predicates(lit_str.span(), error)),
+		_ => Err(Error::option_syntax(value.span())),
+	}
 }
 
-/// Implement Serde trait.
+/// Forward the item to `serde`'s derive if the native implementation doesn't
+/// support it.
 pub fn impl_item(
 	derive_where: Option<&Path>,
 	serde: &Path,
-	trait_: Ident,
-	bound: Ident,
+	trait_: Trait,
 	full_item: &DeriveInput,
+	item: &Item,
 	where_clause: &Option<Cow<'_, WhereClause>>,
 ) -> TokenStream {
 	let derive_where = derive_where
 		.map(Cow::Borrowed)
 		.unwrap_or_else(|| Cow::Owned(util::path_from_strs(&[DERIVE_WHERE])));
-
-	let bounds = if let Some(where_clause) = where_clause {
-		where_clause.predicates.to_token_stream().to_string()
-	} else {
-		String::new()
+	let (bound, skip) = match trait_ {
+		Trait::Deserialize => (
+			format_ident!("deserialize"),
+			format_ident!("skip_deserializing"),
+		),
+		_ => (
+			format_ident!("serialize"),
+			format_ident!("skip_serializing"),
+		),
 	};
 
+	// The `where` clause already contains user-provided `bound`s.
+	let bounds = where_clause
+		.as_ref()
+		.map(|where_clause| where_clause.predicates.to_token_stream().to_string())
+		.unwrap_or_default();
+
+	let mut full_item = without_bound(full_item);
+
+	// Pass the `crate` option on to `serde`.
+	if *serde != util::path_from_strs(&["serde"])
+		&& !full_item
+			.attrs
+			.iter()
+			.any(|attr| util::has_option(attr, "serde", "crate"))
+	{
+		let serde = serde.to_token_stream().to_string().replace(' ', "");
+		full_item
+			.to_mut()
+			.attrs
+			.push(syn::parse_quote! { #[serde(crate = #serde)] });
+	}
+
+	// Translate fields skipped by `derive_where` to `serde`.
+	if item.any_skip_trait(trait_) {
+		add_skip(full_item.to_mut(), item, trait_, &skip);
+	}
+
+	let trait_ = format_ident!("{}", trait_.as_str());
+
 	quote! {
 		#[::core::prelude::v1::derive(#serde::#trait_)]
 		#[serde(bound(#bound = #bounds))]
@@ -104,3 +209,44 @@ pub fn impl_item(
 		#full_item
 	}
 }
+
+/// Removes `bound` options from `serde` attributes on the item, as they are
+/// already combined into the forwarded `bound`.
+fn without_bound(full_item: &DeriveInput) -> Cow<'_, DeriveInput> {
+	if !full_item
+		.attrs
+		.iter()
+		.any(|attr| util::has_option(attr, "serde", "bound"))
+	{
+		return Cow::Borrowed(full_item);
+	}
+
+	let mut full_item = full_item.clone();
+	util::remove_option(&mut full_item.attrs, "serde", "bound");
+
+	Cow::Owned(full_item)
+}
+
+/// Adds `skip` to the `serde` attributes of all fields skipped by
+/// `derive_where` for `trait_`.
+fn add_skip(full_item: &mut DeriveInput, item: &Item, trait_: Trait, skip: &Ident) {
+	let add = |fields: &mut syn::Fields, data: &Data| {
+		if let Either::Left(data_fields) = data.fields() {
+			for (field, data_field) in fields.iter_mut().zip(&data_fields.fields) {
+				if attr::skipped(data, data_field, trait_) {
+					field.attrs.push(syn::parse_quote! { #[serde(#skip)] });
+				}
+			}
+		}
+	};
+
+	match (&mut full_item.data, item) {
+		(syn::Data::Struct(struct_), Item::Item(data)) => add(&mut struct_.fields, data),
+		(syn::Data::Enum(enum_), Item::Enum { variants, .. }) => {
+			for (variant, data) in enum_.variants.iter_mut().zip(variants) {
+				add(&mut variant.fields, data)
+			}
+		}
+		_ => (),
+	}
+}
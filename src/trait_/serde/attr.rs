@@ -0,0 +1,469 @@
+//! Parsing of `serde` attributes supported by the native implementation.
+//!
+//! If any unsupported `serde` attribute is found, parsing returns [`None`] and
+//! the item is forwarded to `serde`'s derive instead.
+
+use syn::{ext::IdentExt, meta::ParseNestedMeta, Attribute, ExprPath, Ident, LitStr, Result};
+
+use crate::{Data, Either, Trait};
+
+/// `serde` attributes on an item.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Container {
+	/// `rename` option.
+	pub rename: Name,
+	/// `rename_all` option.
+	pub rename_all: RenameAll,
+	/// Enum representation.
+	pub tagging: Tagging,
+	/// `default` option.
+	pub default: Default,
+	/// `deny_unknown_fields` option.
+	pub deny_unknown_fields: bool,
+}
+
+/// `serde` attributes on a variant.
+pub struct Variant {
+	/// `rename` option.
+	pub rename: Name,
+	/// `rename_all` option.
+	pub rename_all: RenameAll,
+	/// `skip` or `skip_serializing` option.
+	pub skip_serializing: bool,
+	/// `skip` or `skip_deserializing` option.
+	pub skip_deserializing: bool,
+}
+
+/// `serde` attributes on a field.
+pub struct Field {
+	/// `rename` option.
+	pub rename: Name,
+	/// `skip` or `skip_serializing` option.
+	pub skip_serializing: bool,
+	/// `skip` or `skip_deserializing` option.
+	pub skip_deserializing: bool,
+	/// `default` option.
+	pub default: Default,
+}
+
+/// Name used when serializing and deserializing.
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct Name {
+	/// Name used when serializing.
+	pub serialize: Option<String>,
+	/// Name used when deserializing.
+	pub deserialize: Option<String>,
+}
+
+/// [`RenameRule`] used when serializing and deserializing.
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct RenameAll {
+	/// [`RenameRule`] used when serializing.
+	pub serialize: Option<RenameRule>,
+	/// [`RenameRule`] used when deserializing.
+	pub deserialize: Option<RenameRule>,
+}
+
+/// Enum representation.
+#[derive(Clone, Eq, PartialEq)]
+pub enum Tagging {
+	/// Externally tagged, the default.
+	External,
+	/// Internally tagged, `#[serde(tag = "...")]`.
+	Internal(String),
+	/// Untagged, `#[serde(untagged)]`.
+	Untagged,
+}
+
+/// `default` option.
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Eq, PartialEq)]
+pub enum Default {
+	/// No `default` option.
+	None,
+	/// `#[serde(default)]`.
+	Default,
+	/// `#[serde(default = "...")]`.
+	Path(ExprPath),
+}
+
+/// Rename rule of `rename_all`.
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum RenameRule {
+	/// `lowercase`.
+	LowerCase,
+	/// `UPPERCASE`.
+	UpperCase,
+	/// `PascalCase`.
+	PascalCase,
+	/// `camelCase`.
+	CamelCase,
+	/// `snake_case`.
+	SnakeCase,
+	/// `SCREAMING_SNAKE_CASE`.
+	ScreamingSnakeCase,
+	/// `kebab-case`.
+	KebabCase,
+	/// `SCREAMING-KEBAB-CASE`.
+	ScreamingKebabCase,
+}
+
+impl Container {
+	/// Parse `serde` [`Attribute`]s on an item. Returns [`None`] if an
+	/// unsupported option was found.
+	pub fn from_attrs(attrs: &[Attribute]) -> Option<Self> {
+		let mut rename = Name::default();
+		let mut rename_all = RenameAll::default();
+		let mut tag = None;
+		let mut untagged = false;
+		let mut default = Default::None;
+		let mut deny_unknown_fields = false;
+
+		parse_attrs(attrs, |meta| {
+			if meta.path.is_ident("rename") {
+				rename.parse(&meta)
+			} else if meta.path.is_ident("rename_all") {
+				rename_all.parse(&meta)
+			} else if meta.path.is_ident("tag") {
+				tag = Some(meta.value()?.parse::<LitStr>()?.value());
+				Ok(())
+			} else if meta.path.is_ident("untagged") {
+				untagged = true;
+				Ok(())
+			} else if meta.path.is_ident("default") {
+				default = Default::parse(&meta)?;
+				Ok(())
+			} else if meta.path.is_ident("deny_unknown_fields") {
+				deny_unknown_fields = true;
+				Ok(())
+			} else if meta.path.is_ident("crate") {
+				// Parsed separately in `serde::parse_derive_trait()`.
+				meta.value()?.parse::<LitStr>().map(|_| ())
+			} else if meta.path.is_ident("bound") {
+				// Parsed separately in `serde::parse_derive_trait()` and added to the
+				// `where` clause.
+				if meta.input.peek(syn::Token![=]) {
+					meta.value()?.parse::<LitStr>().map(|_| ())
+				} else {
+					meta.parse_nested_meta(|meta| meta.value()?.parse::<LitStr>().map(|_| ()))
+				}
+			} else {
+				Err(meta.error("unsupported"))
+			}
+		})?;
+
+		let tagging = match (tag, untagged) {
+			(None, false) => Tagging::External,
+			(Some(tag), false) => Tagging::Internal(tag),
+			(None, true) => Tagging::Untagged,
+			// Let `serde` report the conflict.
+			(Some(_), true) => return None,
+		};
+
+		Some(Self {
+			rename,
+			rename_all,
+			tagging,
+			default,
+			deny_unknown_fields,
+		})
+	}
+}
+
+impl Variant {
+	/// Parse `serde` [`Attribute`]s on a variant. Returns [`None`] if an
+	/// unsupported option was found.
+	pub fn from_attrs(attrs: &[Attribute]) -> Option<Self> {
+		let mut rename = Name::default();
+		let mut rename_all = RenameAll::default();
+		let mut skip_serializing = false;
+		let mut skip_deserializing = false;
+
+		parse_attrs(attrs, |meta| {
+			if meta.path.is_ident("rename") {
+				rename.parse(&meta)
+			} else if meta.path.is_ident("rename_all") {
+				rename_all.parse(&meta)
+			} else if meta.path.is_ident("skip") {
+				skip_serializing = true;
+				skip_deserializing = true;
+				Ok(())
+			} else if meta.path.is_ident("skip_serializing") {
+				skip_serializing = true;
+				Ok(())
+			} else if meta.path.is_ident("skip_deserializing") {
+				skip_deserializing = true;
+				Ok(())
+			} else {
+				Err(meta.error("unsupported"))
+			}
+		})?;
+
+		Some(Self {
+			rename,
+			rename_all,
+			skip_serializing,
+			skip_deserializing,
+		})
+	}
+}
+
+impl Field {
+	/// Parse `serde` [`Attribute`]s on a field. Returns [`None`] if an
+	/// unsupported option was found.
+	pub fn from_attrs(attrs: &[Attribute]) -> Option<Self> {
+		let mut rename = Name::default();
+		let mut skip_serializing = false;
+		let mut skip_deserializing = false;
+		let mut default = Default::None;
+
+		parse_attrs(attrs, |meta| {
+			if meta.path.is_ident("rename") {
+				rename.parse(&meta)
+			} else if meta.path.is_ident("skip") {
+				skip_serializing = true;
+				skip_deserializing = true;
+				Ok(())
+			} else if meta.path.is_ident("skip_serializing") {
+				skip_serializing = true;
+				Ok(())
+			} else if meta.path.is_ident("skip_deserializing") {
+				skip_deserializing = true;
+				Ok(())
+			} else if meta.path.is_ident("default") {
+				default = Default::parse(&meta)?;
+				Ok(())
+			} else {
+				Err(meta.error("unsupported"))
+			}
+		})?;
+
+		Some(Self {
+			rename,
+			skip_serializing,
+			skip_deserializing,
+			default,
+		})
+	}
+}
+
+impl Name {
+	/// Parse `rename = "..."` or `rename(serialize = "...", deserialize =
+	/// "...")`.
+	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
+		if meta.input.peek(syn::Token![=]) {
+			let name = meta.value()?.parse::<LitStr>()?.value();
+			self.serialize = Some(name.clone());
+			self.deserialize = Some(name);
+		} else {
+			meta.parse_nested_meta(|meta| {
+				if meta.path.is_ident("serialize") {
+					self.serialize = Some(meta.value()?.parse::<LitStr>()?.value());
+					Ok(())
+				} else if meta.path.is_ident("deserialize") {
+					self.deserialize = Some(meta.value()?.parse::<LitStr>()?.value());
+					Ok(())
+				} else {
+					Err(meta.error("unsupported"))
+				}
+			})?;
+		}
+
+		Ok(())
+	}
+
+	/// Returns the name used when serializing, falling back to `default`.
+	pub fn serialize_or(&self, default: impl FnOnce() -> String) -> String {
+		self.serialize.clone().unwrap_or_else(default)
+	}
+
+	/// Returns the name used when deserializing, falling back to `default`.
+	pub fn deserialize_or(&self, default: impl FnOnce() -> String) -> String {
+		self.deserialize.clone().unwrap_or_else(default)
+	}
+}
+
+impl RenameAll {
+	/// Parse `rename_all = "..."` or `rename_all(serialize = "...", deserialize
+	/// = "...")`.
+	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
+		if meta.input.peek(syn::Token![=]) {
+			let rule = RenameRule::parse(meta)?;
+			self.serialize = Some(rule);
+			self.deserialize = Some(rule);
+		} else {
+			meta.parse_nested_meta(|meta| {
+				if meta.path.is_ident("serialize") {
+					self.serialize = Some(RenameRule::parse(&meta)?);
+					Ok(())
+				} else if meta.path.is_ident("deserialize") {
+					self.deserialize = Some(RenameRule::parse(&meta)?);
+					Ok(())
+				} else {
+					Err(meta.error("unsupported"))
+				}
+			})?;
+		}
+
+		Ok(())
+	}
+}
+
+impl Default {
+	/// Parse `default` or `default = "..."`.
+	fn parse(meta: &ParseNestedMeta) -> Result<Self> {
+		if meta.input.peek(syn::Token![=]) {
+			meta.value()?.parse::<LitStr>()?.parse().map(Default::Path)
+		} else {
+			Ok(Default::Default)
+		}
+	}
+}
+
+impl RenameRule {
+	/// Parse the value of a `rename_all` option.
+	fn parse(meta: &ParseNestedMeta) -> Result<Self> {
+		let rule = meta.value()?.parse::<LitStr>()?;
+
+		Ok(match rule.value().as_str() {
+			"lowercase" => RenameRule::LowerCase,
+			"UPPERCASE" => RenameRule::UpperCase,
+			"PascalCase" => RenameRule::PascalCase,
+			"camelCase" => RenameRule::CamelCase,
+			"snake_case" => RenameRule::SnakeCase,
+			"SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnakeCase,
+			"kebab-case" => RenameRule::KebabCase,
+			"SCREAMING-KEBAB-CASE" => RenameRule::ScreamingKebabCase,
+			// Let `serde` report unknown rules.
+			_ => return Err(meta.error("unsupported")),
+		})
+	}
+
+	/// Apply this rule to a variant name.
+	pub fn apply_to_variant(self, variant: &str) -> String {
+		match self {
+			RenameRule::PascalCase => variant.to_owned(),
+			RenameRule::LowerCase => variant.to_ascii_lowercase(),
+			RenameRule::UpperCase => variant.to_ascii_uppercase(),
+			RenameRule::CamelCase => lowercase_first(variant),
+			RenameRule::SnakeCase => {
+				let mut snake = String::new();
+
+				for (index, char) in variant.char_indices() {
+					if index > 0 && char.is_uppercase() {
+						snake.push('_');
+					}
+
+					snake.push(char.to_ascii_lowercase());
+				}
+
+				snake
+			}
+			RenameRule::ScreamingSnakeCase => RenameRule::SnakeCase
+				.apply_to_variant(variant)
+				.to_ascii_uppercase(),
+			RenameRule::KebabCase => RenameRule::SnakeCase
+				.apply_to_variant(variant)
+				.replace('_', "-"),
+			RenameRule::ScreamingKebabCase => RenameRule::ScreamingSnakeCase
+				.apply_to_variant(variant)
+				.replace('_', "-"),
+		}
+	}
+
+	/// Apply this rule to a field name.
+	pub fn apply_to_field(self, field: &str) -> String {
+		match self {
+			RenameRule::LowerCase | RenameRule::SnakeCase => field.to_owned(),
+			RenameRule::UpperCase | RenameRule::ScreamingSnakeCase => field.to_ascii_uppercase(),
+			RenameRule::PascalCase => {
+				let mut pascal = String::new();
+				let mut capitalize = true;
+
+				for char in field.chars() {
+					if char == '_' {
+						capitalize = true;
+					} else if capitalize {
+						pascal.push(char.to_ascii_uppercase());
+						capitalize = false;
+					} else {
+						pascal.push(char);
+					}
+				}
+
+				pascal
+			}
+			RenameRule::CamelCase => lowercase_first(&RenameRule::PascalCase.apply_to_field(field)),
+			RenameRule::KebabCase => field.replace('_', "-"),
+			RenameRule::ScreamingKebabCase => field.to_ascii_uppercase().replace('_', "-"),
+		}
+	}
+}
+
+/// Lowercases the first character of `name`. Only ASCII characters are
+/// lowercased, same as `serde`.
+fn lowercase_first(name: &str) -> String {
+	let mut chars = name.chars();
+
+	match chars.next() {
+		Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+		None => String::new(),
+	}
+}
+
+/// Returns the name of a variant or field [`struct@Ident`] as used by `serde`.
+pub fn ident_name(ident: &Ident) -> String {
+	ident.unraw().to_string()
+}
+
+/// Returns the name of a variant, applying `rule` if present.
+pub fn variant_name(rule: Option<RenameRule>, ident: &Ident) -> String {
+	let name = ident_name(ident);
+	rule.map_or(name.clone(), |rule| rule.apply_to_variant(&name))
+}
+
+/// Returns the name of a field, applying `rule` if present.
+pub fn field_name(rule: Option<RenameRule>, ident: &Ident) -> String {
+	let name = ident_name(ident);
+	rule.map_or(name.clone(), |rule| rule.apply_to_field(&name))
+}
+
+/// Parse `serde` [`Attribute`]s of all fields in [`Data`], taking fields
+/// skipped by `derive_where` into account. Returns [`None`] if an unsupported
+/// option was found.
+pub fn fields<'a>(data: &'a Data) -> Option<Vec<(&'a crate::Field<'a>, Field)>> {
+	match data.fields() {
+		Either::Left(fields) => fields
+			.fields
+			.iter()
+			.map(|field| {
+				let mut attr = Field::from_attrs(field.attrs)?;
+				attr.skip_serializing |= skipped(data, field, Trait::Serialize);
+				attr.skip_deserializing |= skipped(data, field, Trait::Deserialize);
+				Some((field, attr))
+			})
+			.collect(),
+		Either::Right(_) => Some(Vec::new()),
+	}
+}
+
+/// Returns `true` if `field` is skipped by `derive_where` for `trait_`.
+pub fn skipped(data: &Data, field: &crate::Field, trait_: Trait) -> bool {
+	data.skip(trait_) || field.skip(trait_)
+}
+
+/// Parse all `serde` [`Attribute`]s with `parser`. Returns [`None`] if any
+/// option failed to parse.
+fn parse_attrs(
+	attrs: &[Attribute],
+	mut parser: impl FnMut(ParseNestedMeta) -> Result<()>,
+) -> Option<()> {
+	for attr in attrs {
+		if attr.path().is_ident("serde") && attr.parse_nested_meta(&mut parser).is_err() {
+			return None;
+		}
+	}
+
+	Some(())
+}
@@ -0,0 +1,833 @@
+//! Native [`Deserialize`](https://docs.rs/serde/latest/serde/trait.Deserialize.html)
+//! implementation.
+
+use std::{cell::Cell, slice};
+
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+	GenericParam, Generics, Ident, Lifetime, LifetimeParam, Path, Type, TypeReference, WhereClause,
+};
+
+use super::attr::{self, Container, RenameRule, Tagging};
+use crate::{data::Member, Data, Field, Item, SimpleType, Trait};
+
+/// Build the `deserialize` method. Returns [`None`] if the item or its `serde`
+/// attributes aren't supported.
+pub fn build(
+	serde: &Path,
+	container: &Container,
+	item: &Item,
+	generics: &Generics,
+	where_clause: Option<&WhereClause>,
+) -> Option<TokenStream> {
+	debug_assert!(
+		container.tagging == Tagging::External,
+		"unexpected tagging for `Deserialize`"
+	);
+
+	// Container defaults on anything but structs with named fields are reported
+	// by `serde`.
+	if container.default != attr::Default::None {
+		match item {
+			Item::Item(data) if matches!(data.simple_type(), SimpleType::Struct(_)) => (),
+			_ => return None,
+		}
+	}
+
+	let mut de_generics = generics.clone();
+	de_generics
+		.params
+		.insert(0, GenericParam::Lifetime(de_lifetime(item)));
+	let (de_imp, de_ty, _) = de_generics.split_for_impl();
+	let (_, ty, _) = generics.split_for_impl();
+
+	let context = Context {
+		serde,
+		container,
+		ident: item.ident(),
+		type_name: container
+			.rename
+			.deserialize_or(|| attr::ident_name(item.ident())),
+		de_imp: de_imp.into_token_stream(),
+		de_ty: de_ty.into_token_stream(),
+		ty: ty.into_token_stream(),
+		where_clause,
+		missing_field: Cell::new(false),
+	};
+
+	let body = match item {
+		Item::Item(data) => context.build_struct(data)?,
+		Item::Enum { variants, .. } => context.build_enum(variants)?,
+	};
+
+	let missing_field = context.missing_field.get().then(|| {
+		quote! {
+			fn __missing_field<'de, __V, __E>(__field: &'static str) -> ::core::result::Result<__V, __E>
+			where
+				__V: #serde::Deserialize<'de>,
+				__E: #serde::de::Error,
+			{
+				struct __MissingFieldDeserializer<__E>(&'static str, ::core::marker::PhantomData<__E>);
+
+				impl<'de, __E> #serde::Deserializer<'de> for __MissingFieldDeserializer<__E>
+				where
+					__E: #serde::de::Error,
+				{
+					type Error = __E;
+
+					fn deserialize_any<__V>(self, _: __V) -> ::core::result::Result<__V::Value, __E>
+					where
+						__V: #serde::de::Visitor<'de>,
+					{
+						::core::result::Result::Err(<__E as #serde::de::Error>::missing_field(self.0))
+					}
+
+					fn deserialize_option<__V>(self, __visitor: __V) -> ::core::result::Result<__V::Value, __E>
+					where
+						__V: #serde::de::Visitor<'de>,
+					{
+						#serde::de::Visitor::visit_none(__visitor)
+					}
+
+					#serde::forward_to_deserialize_any! {
+						bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+						bytes byte_buf unit unit_struct newtype_struct seq tuple
+						tuple_struct map struct enum identifier ignored_any
+					}
+				}
+
+				<__V as #serde::Deserialize>::deserialize(__MissingFieldDeserializer(__field, ::core::marker::PhantomData))
+			}
+		}
+	});
+
+	Some(quote! {
+		fn deserialize<__D>(__deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+		where
+			__D: #serde::Deserializer<'de>,
+		{
+			#missing_field
+			#body
+		}
+	})
+}
+
+/// Returns the `'de` lifetime. Like in `serde`, it outlives all lifetimes
+/// borrowed by `&str` and `&[u8]` fields.
+pub fn de_lifetime(item: &Item) -> LifetimeParam {
+	let mut de = LifetimeParam::new(Lifetime::new("'de", Span::call_site()));
+
+	let variants = match item {
+		Item::Item(data) => slice::from_ref(data),
+		Item::Enum { variants, .. } => variants,
+	};
+
+	for field in variants
+		.iter()
+		.flat_map(|data| data.iter_fields(Trait::Deserialize))
+	{
+		if let Type::Reference(TypeReference {
+			lifetime: Some(lifetime),
+			elem,
+			..
+		}) = field.type_
+		{
+			let borrowed = match &**elem {
+				Type::Path(path) => path.qself.is_none() && path.path.is_ident("str"),
+				Type::Slice(slice) => matches!(
+					&*slice.elem,
+					Type::Path(path) if path.qself.is_none() && path.path.is_ident("u8")
+				),
+				_ => false,
+			};
+
+			if borrowed && !de.bounds.iter().any(|bound| bound == lifetime) {
+				de.bounds.push(lifetime.clone());
+			}
+		}
+	}
+
+	de
+}
+
+/// Shared state for building the `deserialize` method.
+struct Context<'a> {
+	/// Path to the `serde` crate.
+	serde: &'a Path,
+	/// `serde` attributes on the item.
+	container: &'a Container,
+	/// [`struct@Ident`] of the item.
+	ident: &'a Ident,
+	/// Name of the item used when deserializing.
+	type_name: String,
+	/// Generics of the item with an added `'de` lifetime.
+	de_imp: TokenStream,
+	/// Type generics of the item with an added `'de` lifetime.
+	de_ty: TokenStream,
+	/// Type generics of the item.
+	ty: TokenStream,
+	/// `where` clause of the implementation.
+	where_clause: Option<&'a WhereClause>,
+	/// If the `__missing_field` helper function is required.
+	missing_field: Cell<bool>,
+}
+
+/// How to handle unknown identifiers.
+enum Unknown {
+	/// Ignore unknown fields.
+	Ignore,
+	/// Return an error for unknown fields.
+	Field,
+	/// Return an error for unknown variants.
+	Variant,
+}
+
+impl Context<'_> {
+	/// Build body for a struct.
+	fn build_struct(&self, data: &Data) -> Option<TokenStream> {
+		let serde = self.serde;
+		let type_name = &self.type_name;
+		let fields = attr::fields(data)?;
+
+		match data.simple_type() {
+			SimpleType::Struct(_) => Some(self.build_named(
+				data,
+				&fields,
+				self.container.rename_all.deserialize,
+				&format!("struct {}", self.ident),
+				|visitor| {
+					quote! {
+						#serde::Deserializer::deserialize_struct(__deserializer, #type_name, FIELDS, #visitor)
+					}
+				},
+			)),
+			SimpleType::Tuple(_) => match fields.as_slice() {
+				// Skipping the field of a newtype struct isn't supported.
+				[(_, attr)] if attr.skip_deserializing => None,
+				[(field, _)] => {
+					let path = &data.path;
+					let self_ident = &field.self_ident;
+					let type_ = field.type_;
+					let expecting = format!("tuple struct {}", self.ident);
+
+					let visit_newtype_struct = quote! {
+						fn visit_newtype_struct<__E>(self, __e: __E) -> ::core::result::Result<Self::Value, __E::Error>
+						where
+							__E: #serde::Deserializer<'de>,
+						{
+							let #self_ident = <#type_ as #serde::Deserialize>::deserialize(__e)?;
+							::core::result::Result::Ok(#path(#self_ident))
+						}
+					};
+					let visit_seq = self.build_visit_seq(data, &fields, &expecting);
+					let visitor = self.build_visitor(
+						&expecting,
+						quote! {
+							#visit_newtype_struct
+							#visit_seq
+						},
+					);
+					let visitor_expr = self.visitor_expr();
+
+					Some(quote! {
+						#visitor
+						#serde::Deserializer::deserialize_newtype_struct(__deserializer, #type_name, #visitor_expr)
+					})
+				}
+				_ => {
+					let expecting = format!("tuple struct {}", self.ident);
+					let len = deserialized(&fields).count();
+					let visitor = self
+						.build_visitor(&expecting, self.build_visit_seq(data, &fields, &expecting));
+					let visitor_expr = self.visitor_expr();
+
+					Some(quote! {
+						#visitor
+						#serde::Deserializer::deserialize_tuple_struct(__deserializer, #type_name, #len, #visitor_expr)
+					})
+				}
+			},
+			SimpleType::Unit(_) => {
+				let path = &data.path;
+				let visitor = self.build_visitor(
+					&format!("unit struct {}", self.ident),
+					quote! {
+						fn visit_unit<__E>(self) -> ::core::result::Result<Self::Value, __E>
+						where
+							__E: #serde::de::Error,
+						{
+							::core::result::Result::Ok(#path)
+						}
+					},
+				);
+				let visitor_expr = self.visitor_expr();
+
+				Some(quote! {
+					#visitor
+					#serde::Deserializer::deserialize_unit_struct(__deserializer, #type_name, #visitor_expr)
+				})
+			}
+			SimpleType::Union => None,
+		}
+	}
+
+	/// Build body for an enum.
+	fn build_enum(&self, variants: &[Data]) -> Option<TokenStream> {
+		let serde = self.serde;
+		let type_name = &self.type_name;
+
+		let mut idents = Vec::new();
+		let mut arms = Vec::new();
+
+		for data in variants {
+			let variant = attr::Variant::from_attrs(data.attrs)?;
+			let fields = attr::fields(data)?;
+
+			if variant.skip_deserializing {
+				continue;
+			}
+
+			let ident = format_ident!("__variant_{}", data.ident);
+			let name = variant.rename.deserialize_or(|| {
+				attr::variant_name(self.container.rename_all.deserialize, data.ident)
+			});
+			let arm = self.build_variant(data, &fields, variant.rename_all.deserialize);
+
+			arms.push(quote! {
+				(__Variant::#ident, __variant) => #arm
+			});
+			idents.push((ident, name));
+		}
+
+		let names = idents.iter().map(|(_, name)| name);
+		let identifier = self.build_identifier(
+			&format_ident!("__Variant"),
+			"variant identifier",
+			&idents,
+			Unknown::Variant,
+		);
+
+		let visit_enum = if arms.is_empty() {
+			quote! {
+				::core::result::Result::map(
+					#serde::de::EnumAccess::variant::<__Variant>(__data),
+					|(__impossible, _)| match __impossible {},
+				)
+			}
+		} else {
+			quote! {
+				match #serde::de::EnumAccess::variant(__data)? {
+					#(#arms)*
+				}
+			}
+		};
+
+		let visitor = self.build_visitor(
+			&format!("enum {}", self.ident),
+			quote! {
+				fn visit_enum<__A>(self, __data: __A) -> ::core::result::Result<Self::Value, __A::Error>
+				where
+					__A: #serde::de::EnumAccess<'de>,
+				{
+					#visit_enum
+				}
+			},
+		);
+		let visitor_expr = self.visitor_expr();
+
+		Some(quote! {
+			const VARIANTS: &[&str] = &[#(#names),*];
+			#identifier
+			#visitor
+			#serde::Deserializer::deserialize_enum(__deserializer, #type_name, VARIANTS, #visitor_expr)
+		})
+	}
+
+	/// Build `match` arm for a variant.
+	fn build_variant(
+		&self,
+		data: &Data,
+		fields: &[(&Field, attr::Field)],
+		rename_all: Option<RenameRule>,
+	) -> TokenStream {
+		let serde = self.serde;
+		let path = &data.path;
+
+		match data.simple_type() {
+			SimpleType::Struct(_) => {
+				let block = self.build_named(
+					data,
+					fields,
+					rename_all,
+					&format!("struct variant {}::{}", self.ident, data.ident),
+					|visitor| {
+						quote! {
+							#serde::de::VariantAccess::struct_variant(__variant, FIELDS, #visitor)
+						}
+					},
+				);
+
+				quote! { { #block } }
+			}
+			SimpleType::Tuple(_) => match fields {
+				// Newtype variants with a skipped field are deserialized as unit variants.
+				[(field, attr)] if attr.skip_deserializing => {
+					let default = self.build_missing(field, attr, true);
+
+					quote! {
+						{
+							#serde::de::VariantAccess::unit_variant(__variant)?;
+							::core::result::Result::Ok(#path(#default))
+						}
+					}
+				}
+				[(field, _)] => {
+					let type_ = field.type_;
+
+					quote! {
+						::core::result::Result::map(
+							#serde::de::VariantAccess::newtype_variant::<#type_>(__variant),
+							#path,
+						),
+					}
+				}
+				_ => {
+					let expecting = format!("tuple variant {}::{}", self.ident, data.ident);
+					let len = deserialized(fields).count();
+					let visitor = self
+						.build_visitor(&expecting, self.build_visit_seq(data, fields, &expecting));
+					let visitor_expr = self.visitor_expr();
+
+					quote! {
+						{
+							#visitor
+							#serde::de::VariantAccess::tuple_variant(__variant, #len, #visitor_expr)
+						}
+					}
+				}
+			},
+			SimpleType::Unit(_) | SimpleType::Union => quote! {
+				{
+					#serde::de::VariantAccess::unit_variant(__variant)?;
+					::core::result::Result::Ok(#path)
+				}
+			},
+		}
+	}
+
+	/// Build statements deserializing a struct or struct variant with named
+	/// fields. `dispatch` receives the visitor expression.
+	fn build_named(
+		&self,
+		data: &Data,
+		fields: &[(&Field, attr::Field)],
+		rename_all: Option<RenameRule>,
+		expecting: &str,
+		dispatch: impl FnOnce(TokenStream) -> TokenStream,
+	) -> TokenStream {
+		let idents: Vec<_> = deserialized(fields)
+			.map(|(field, attr)| {
+				let name = match field.member {
+					Member::Named(ident) => attr
+						.rename
+						.deserialize_or(|| attr::field_name(rename_all, ident)),
+					Member::Unnamed(_) => unreachable!("unexpected unnamed field"),
+				};
+
+				(field.self_ident.clone(), name)
+			})
+			.collect();
+		let names = idents.iter().map(|(_, name)| name);
+
+		let unknown = if self.container.deny_unknown_fields {
+			Unknown::Field
+		} else {
+			Unknown::Ignore
+		};
+		let identifier = self.build_identifier(
+			&format_ident!("__Field"),
+			"field identifier",
+			&idents,
+			unknown,
+		);
+
+		let visit_seq = self.build_visit_seq(data, fields, expecting);
+		let visit_map = self.build_visit_map(data, fields, &idents);
+		let visitor = self.build_visitor(
+			expecting,
+			quote! {
+				#visit_seq
+				#visit_map
+			},
+		);
+		let dispatch = dispatch(self.visitor_expr());
+
+		quote! {
+			const FIELDS: &[&str] = &[#(#names),*];
+			#identifier
+			#visitor
+			#dispatch
+		}
+	}
+
+	/// Build the `visit_seq` method.
+	fn build_visit_seq(
+		&self,
+		data: &Data,
+		fields: &[(&Field, attr::Field)],
+		expecting: &str,
+	) -> TokenStream {
+		let serde = self.serde;
+
+		let len = deserialized(fields).count();
+		let expecting = if len == 1 {
+			format!("{} with 1 element", expecting)
+		} else {
+			format!("{} with {} elements", expecting, len)
+		};
+
+		let mut index = 0_usize;
+		let fields = fields.iter().map(|(field, attr)| {
+			let self_ident = &field.self_ident;
+
+			if attr.skip_deserializing {
+				let default = self.build_missing(field, attr, true);
+
+				quote! {
+					let #self_ident = #default;
+				}
+			} else {
+				let type_ = field.type_;
+				let missing = match (&attr.default, &self.container.default) {
+					(attr::Default::None, attr::Default::None) => quote! {
+						return ::core::result::Result::Err(<__A::Error as #serde::de::Error>::invalid_length(#index, &#expecting))
+					},
+					_ => self.build_missing(field, attr, false),
+				};
+				index += 1;
+
+				quote! {
+					let #self_ident = match #serde::de::SeqAccess::next_element::<#type_>(&mut __seq)? {
+						::core::option::Option::Some(__value) => __value,
+						::core::option::Option::None => #missing,
+					};
+				}
+			}
+		});
+		let fields: Vec<_> = fields.collect();
+
+		let seq = if len == 0 {
+			quote! { _ }
+		} else {
+			quote! { mut __seq }
+		};
+		let default = self.build_default();
+		let construct = construct(data);
+
+		quote! {
+			fn visit_seq<__A>(self, #seq: __A) -> ::core::result::Result<Self::Value, __A::Error>
+			where
+				__A: #serde::de::SeqAccess<'de>,
+			{
+				#default
+				#(#fields)*
+				::core::result::Result::Ok(#construct)
+			}
+		}
+	}
+
+	/// Build the `visit_map` method.
+	fn build_visit_map(
+		&self,
+		data: &Data,
+		fields: &[(&Field, attr::Field)],
+		idents: &[(Ident, String)],
+	) -> TokenStream {
+		let serde = self.serde;
+
+		let types = deserialized(fields).map(|(field, _)| field.type_);
+		let options = idents.iter().zip(types.clone()).map(|((ident, _), type_)| {
+			quote! {
+				let mut #ident: ::core::option::Option<#type_> = ::core::option::Option::None;
+			}
+		});
+		let arms = idents.iter().zip(types).map(|((ident, name), type_)| {
+			quote! {
+				__Field::#ident => {
+					if ::core::option::Option::is_some(&#ident) {
+						return ::core::result::Result::Err(<__A::Error as #serde::de::Error>::duplicate_field(#name));
+					}
+
+					#ident = ::core::option::Option::Some(#serde::de::MapAccess::next_value::<#type_>(&mut __map)?);
+				}
+			}
+		});
+		let ignore = (!self.container.deny_unknown_fields).then(|| {
+			quote! {
+				_ => {
+					let _ = #serde::de::MapAccess::next_value::<#serde::de::IgnoredAny>(&mut __map)?;
+				}
+			}
+		});
+		let fields = fields.iter().map(|(field, attr)| {
+			let self_ident = &field.self_ident;
+			let missing = self.build_missing(field, attr, attr.skip_deserializing);
+
+			if attr.skip_deserializing {
+				quote! {
+					let #self_ident = #missing;
+				}
+			} else {
+				quote! {
+					let #self_ident = match #self_ident {
+						::core::option::Option::Some(__value) => __value,
+						::core::option::Option::None => #missing,
+					};
+				}
+			}
+		});
+		let fields: Vec<_> = fields.collect();
+		let default = self.build_default();
+		let construct = construct(data);
+
+		quote! {
+			fn visit_map<__A>(self, mut __map: __A) -> ::core::result::Result<Self::Value, __A::Error>
+			where
+				__A: #serde::de::MapAccess<'de>,
+			{
+				#(#options)*
+
+				while let ::core::option::Option::Some(__key) = #serde::de::MapAccess::next_key::<__Field>(&mut __map)? {
+					match __key {
+						#(#arms)*
+						#ignore
+					}
+				}
+
+				#default
+				#(#fields)*
+				::core::result::Result::Ok(#construct)
+			}
+		}
+	}
+
+	/// Build the expression used for a missing field. Skipped fields fall back
+	/// to [`Default`].
+	fn build_missing(&self, field: &Field, attr: &attr::Field, skipped: bool) -> TokenStream {
+		match (&attr.default, &self.container.default) {
+			(attr::Default::Default, _) => quote! { ::core::default::Default::default() },
+			(attr::Default::Path(path), _) => quote! { #path() },
+			(attr::Default::None, attr::Default::Default | attr::Default::Path(_)) => {
+				let member = &field.member;
+				quote! { __default.#member }
+			}
+			(attr::Default::None, attr::Default::None) if skipped => {
+				quote! { ::core::default::Default::default() }
+			}
+			(attr::Default::None, attr::Default::None) => {
+				let name = match field.member {
+					Member::Named(ident) => attr.rename.deserialize_or(|| {
+						attr::field_name(self.container.rename_all.deserialize, ident)
+					}),
+					Member::Unnamed(_) => unreachable!("unexpected unnamed field"),
+				};
+				self.missing_field.set(true);
+
+				quote! { __missing_field::<_, __A::Error>(#name)? }
+			}
+		}
+	}
+
+	/// Build the container default value, if any.
+	fn build_default(&self) -> Option<TokenStream> {
+		match &self.container.default {
+			attr::Default::None => None,
+			attr::Default::Default => Some(quote! {
+				let __default: Self::Value = ::core::default::Default::default();
+			}),
+			attr::Default::Path(path) => Some(quote! {
+				let __default: Self::Value = #path();
+			}),
+		}
+	}
+
+	/// Build an identifier enum named `ident` with the given variants and their
+	/// names.
+	fn build_identifier(
+		&self,
+		ident: &Ident,
+		expecting: &str,
+		variants: &[(Ident, String)],
+		unknown: Unknown,
+	) -> TokenStream {
+		let serde = self.serde;
+		let visitor = format_ident!("{}Visitor", ident);
+
+		let idents: Vec<_> = variants.iter().map(|(ident, _)| ident).collect();
+		let indices = 0_u64..;
+		let names = variants.iter().map(|(_, name)| name);
+		let bytes = variants
+			.iter()
+			.map(|(_, name)| Literal::byte_string(name.as_bytes()));
+
+		let (ignore, fallback_u64, fallback_str, fallback_bytes) = match unknown {
+			Unknown::Ignore => (
+				Some(quote! { __ignore, }),
+				quote! { ::core::result::Result::Ok(#ident::__ignore) },
+				quote! { ::core::result::Result::Ok(#ident::__ignore) },
+				quote! { ::core::result::Result::Ok(#ident::__ignore) },
+			),
+			Unknown::Field | Unknown::Variant => {
+				let (kind, list, error) = if let Unknown::Field = unknown {
+					("field", quote! { FIELDS }, quote! { unknown_field })
+				} else {
+					("variant", quote! { VARIANTS }, quote! { unknown_variant })
+				};
+				let index = format!("{} index 0 <= i < {}", kind, variants.len());
+
+				(
+					None,
+					quote! {
+						::core::result::Result::Err(<__E as #serde::de::Error>::invalid_value(#serde::de::Unexpected::Unsigned(__value), &#index))
+					},
+					quote! {
+						::core::result::Result::Err(<__E as #serde::de::Error>::#error(__value, #list))
+					},
+					quote! {
+						match ::core::str::from_utf8(__value) {
+							::core::result::Result::Ok(__value) => ::core::result::Result::Err(<__E as #serde::de::Error>::#error(__value, #list)),
+							::core::result::Result::Err(_) => ::core::result::Result::Err(<__E as #serde::de::Error>::invalid_value(#serde::de::Unexpected::Bytes(__value), &self)),
+						}
+					},
+				)
+			}
+		};
+
+		quote! {
+			#[allow(non_camel_case_types)]
+			enum #ident {
+				#(#idents,)*
+				#ignore
+			}
+
+			struct #visitor;
+
+			impl<'de> #serde::de::Visitor<'de> for #visitor {
+				type Value = #ident;
+
+				fn expecting(&self, __formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+					::core::fmt::Formatter::write_str(__formatter, #expecting)
+				}
+
+				fn visit_u64<__E>(self, __value: u64) -> ::core::result::Result<Self::Value, __E>
+				where
+					__E: #serde::de::Error,
+				{
+					match __value {
+						#(#indices => ::core::result::Result::Ok(#ident::#idents),)*
+						_ => #fallback_u64,
+					}
+				}
+
+				fn visit_str<__E>(self, __value: &str) -> ::core::result::Result<Self::Value, __E>
+				where
+					__E: #serde::de::Error,
+				{
+					match __value {
+						#(#names => ::core::result::Result::Ok(#ident::#idents),)*
+						_ => #fallback_str,
+					}
+				}
+
+				fn visit_bytes<__E>(self, __value: &[u8]) -> ::core::result::Result<Self::Value, __E>
+				where
+					__E: #serde::de::Error,
+				{
+					match __value {
+						#(#bytes => ::core::result::Result::Ok(#ident::#idents),)*
+						_ => #fallback_bytes,
+					}
+				}
+			}
+
+			impl<'de> #serde::Deserialize<'de> for #ident {
+				fn deserialize<__D>(__deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+				where
+					__D: #serde::Deserializer<'de>,
+				{
+					#serde::Deserializer::deserialize_identifier(__deserializer, #visitor)
+				}
+			}
+		}
+	}
+
+	/// Build the `__Visitor` type implementing `Visitor` with the given
+	/// methods.
+	fn build_visitor(&self, expecting: &str, methods: TokenStream) -> TokenStream {
+		let Context {
+			serde,
+			ident,
+			de_imp,
+			de_ty,
+			ty,
+			where_clause,
+			..
+		} = self;
+
+		quote! {
+			struct __Visitor #de_imp #where_clause {
+				marker: ::core::marker::PhantomData<#ident #ty>,
+				lifetime: ::core::marker::PhantomData<&'de ()>,
+			}
+
+			impl #de_imp #serde::de::Visitor<'de> for __Visitor #de_ty #where_clause {
+				type Value = #ident #ty;
+
+				fn expecting(&self, __formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+					::core::fmt::Formatter::write_str(__formatter, #expecting)
+				}
+
+				#methods
+			}
+		}
+	}
+
+	/// Build expression constructing `__Visitor`.
+	fn visitor_expr(&self) -> TokenStream {
+		quote! {
+			__Visitor {
+				marker: ::core::marker::PhantomData,
+				lifetime: ::core::marker::PhantomData,
+			}
+		}
+	}
+}
+
+/// Returns all fields that aren't skipped.
+fn deserialized<'a, 'b>(
+	fields: &'b [(&'a Field<'a>, attr::Field)],
+) -> impl Iterator<Item = &'b (&'a Field<'a>, attr::Field)> + Clone {
+	fields.iter().filter(|(_, attr)| !attr.skip_deserializing)
+}
+
+/// Build expression constructing the struct or variant from the temporary
+/// variables of all fields.
+fn construct(data: &Data) -> TokenStream {
+	let path = &data.path;
+
+	match data.fields() {
+		crate::Either::Left(fields) => {
+			let self_idents = fields.fields.iter().map(|field| &field.self_ident);
+
+			match data.simple_type() {
+				SimpleType::Struct(_) => {
+					let members = fields.fields.iter().map(|field| &field.member);
+					quote! { #path { #(#members: #self_idents),* } }
+				}
+				_ => quote! { #path(#(#self_idents),*) },
+			}
+		}
+		crate::Either::Right(_) => path.to_token_stream(),
+	}
+}
@@ -0,0 +1,324 @@
+//! Native [`Serialize`](https://docs.rs/serde/latest/serde/trait.Serialize.html)
+//! implementation.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Path;
+
+use super::attr::{self, Container, RenameRule, Tagging};
+use crate::{data::Member, Data, Field, Item, SimpleType};
+
+/// Build the `serialize` method. Returns [`None`] if the item or its `serde`
+/// attributes aren't supported.
+pub fn build(serde: &Path, container: &Container, item: &Item) -> Option<TokenStream> {
+	let type_name = container
+		.rename
+		.serialize_or(|| attr::ident_name(item.ident()));
+
+	let arms = match item {
+		// Tagged structs and unions aren't supported.
+		Item::Item(data) => match container.tagging {
+			Tagging::External => vec![build_struct(serde, container, &type_name, data)?],
+			Tagging::Internal(_) | Tagging::Untagged => return None,
+		},
+		Item::Enum { variants, .. } => (0_u32..)
+			.zip(variants)
+			.map(|(index, data)| {
+				build_variant(serde, container, item.ident(), &type_name, index, data)
+			})
+			.collect::<Option<_>>()?,
+	};
+
+	Some(quote! {
+		fn serialize<__S>(&self, __serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+		where
+			__S: #serde::Serializer,
+		{
+			match self {
+				#(#arms)*
+			}
+		}
+	})
+}
+
+/// Build `match` arm for a struct.
+fn build_struct(
+	serde: &Path,
+	container: &Container,
+	type_name: &str,
+	data: &Data,
+) -> Option<TokenStream> {
+	let pattern = data.self_pattern();
+	let fields = attr::fields(data)?;
+
+	let body = match data.simple_type() {
+		SimpleType::Struct(_) => {
+			let (len, fields) = build_fields(
+				serde,
+				&fields,
+				container.rename_all.serialize,
+				quote! { SerializeStruct },
+				quote! { serialize_field },
+			);
+			let mut_ = mut_(len);
+
+			quote! {
+				let #mut_ __state = #serde::Serializer::serialize_struct(__serializer, #type_name, #len)?;
+				#(#fields)*
+				#serde::ser::SerializeStruct::end(__state)
+			}
+		}
+		SimpleType::Tuple(_) => match fields.as_slice() {
+			// Skipping the field of a newtype struct isn't supported.
+			[(_, attr)] if attr.skip_serializing => return None,
+			[(field, _)] => {
+				let field = &field.self_ident;
+
+				quote! {
+					#serde::Serializer::serialize_newtype_struct(__serializer, #type_name, #field)
+				}
+			}
+			_ => {
+				let (len, fields) = build_fields(
+					serde,
+					&fields,
+					None,
+					quote! { SerializeTupleStruct },
+					quote! { serialize_field },
+				);
+				let mut_ = mut_(len);
+
+				quote! {
+					let #mut_ __state = #serde::Serializer::serialize_tuple_struct(__serializer, #type_name, #len)?;
+					#(#fields)*
+					#serde::ser::SerializeTupleStruct::end(__state)
+				}
+			}
+		},
+		SimpleType::Unit(_) => quote! {
+			#serde::Serializer::serialize_unit_struct(__serializer, #type_name)
+		},
+		SimpleType::Union => return None,
+	};
+
+	Some(quote! {
+		#pattern => {
+			#body
+		}
+	})
+}
+
+/// Build `match` arm for a variant.
+fn build_variant(
+	serde: &Path,
+	container: &Container,
+	item_ident: &syn::Ident,
+	type_name: &str,
+	index: u32,
+	data: &Data,
+) -> Option<TokenStream> {
+	let pattern = data.self_pattern();
+	let variant = attr::Variant::from_attrs(data.attrs)?;
+
+	if variant.skip_serializing {
+		let message = format!(
+			"the enum variant {}::{} cannot be serialized",
+			item_ident, data.ident
+		);
+
+		return Some(quote! {
+			#pattern => ::core::result::Result::Err(<__S::Error as #serde::ser::Error>::custom(#message)),
+		});
+	}
+
+	let variant_name = variant
+		.rename
+		.serialize_or(|| attr::variant_name(container.rename_all.serialize, data.ident));
+	let fields = attr::fields(data)?;
+	let rename_all = variant.rename_all.serialize;
+
+	let body = match (Style::new(data, &fields), &container.tagging) {
+		(Style::Unit, Tagging::External) => quote! {
+			#serde::Serializer::serialize_unit_variant(__serializer, #type_name, #index, #variant_name)
+		},
+		(Style::Unit, Tagging::Internal(tag)) => quote! {
+			let mut __state = #serde::Serializer::serialize_struct(__serializer, #type_name, 1)?;
+			#serde::ser::SerializeStruct::serialize_field(&mut __state, #tag, #variant_name)?;
+			#serde::ser::SerializeStruct::end(__state)
+		},
+		(Style::Unit, Tagging::Untagged) => quote! {
+			#serde::Serializer::serialize_unit(__serializer)
+		},
+		(Style::Newtype(field), Tagging::External) => {
+			let field = &field.self_ident;
+
+			quote! {
+				#serde::Serializer::serialize_newtype_variant(__serializer, #type_name, #index, #variant_name, #field)
+			}
+		}
+		(Style::Newtype(field), Tagging::Untagged) => {
+			let field = &field.self_ident;
+
+			quote! {
+				#serde::Serialize::serialize(#field, __serializer)
+			}
+		}
+		(Style::Tuple, Tagging::External) => {
+			let (len, fields) = build_fields(
+				serde,
+				&fields,
+				None,
+				quote! { SerializeTupleVariant },
+				quote! { serialize_field },
+			);
+			let mut_ = mut_(len);
+
+			quote! {
+				let #mut_ __state = #serde::Serializer::serialize_tuple_variant(__serializer, #type_name, #index, #variant_name, #len)?;
+				#(#fields)*
+				#serde::ser::SerializeTupleVariant::end(__state)
+			}
+		}
+		(Style::Tuple, Tagging::Untagged) => {
+			let (len, fields) = build_fields(
+				serde,
+				&fields,
+				None,
+				quote! { SerializeTuple },
+				quote! { serialize_element },
+			);
+			let mut_ = mut_(len);
+
+			quote! {
+				let #mut_ __state = #serde::Serializer::serialize_tuple(__serializer, #len)?;
+				#(#fields)*
+				#serde::ser::SerializeTuple::end(__state)
+			}
+		}
+		// Internally tagged newtype variants require `serde`'s private API and tuple
+		// variants aren't supported by `serde`.
+		(Style::Newtype(_) | Style::Tuple, Tagging::Internal(_)) => return None,
+		(Style::Struct, Tagging::External) => {
+			let (len, fields) = build_fields(
+				serde,
+				&fields,
+				rename_all,
+				quote! { SerializeStructVariant },
+				quote! { serialize_field },
+			);
+			let mut_ = mut_(len);
+
+			quote! {
+				let #mut_ __state = #serde::Serializer::serialize_struct_variant(__serializer, #type_name, #index, #variant_name, #len)?;
+				#(#fields)*
+				#serde::ser::SerializeStructVariant::end(__state)
+			}
+		}
+		(Style::Struct, Tagging::Internal(tag)) => {
+			let (len, fields) = build_fields(
+				serde,
+				&fields,
+				rename_all,
+				quote! { SerializeStruct },
+				quote! { serialize_field },
+			);
+			let len = len + 1;
+
+			quote! {
+				let mut __state = #serde::Serializer::serialize_struct(__serializer, #type_name, #len)?;
+				#serde::ser::SerializeStruct::serialize_field(&mut __state, #tag, #variant_name)?;
+				#(#fields)*
+				#serde::ser::SerializeStruct::end(__state)
+			}
+		}
+		(Style::Struct, Tagging::Untagged) => {
+			let (len, fields) = build_fields(
+				serde,
+				&fields,
+				rename_all,
+				quote! { SerializeStruct },
+				quote! { serialize_field },
+			);
+			let mut_ = mut_(len);
+
+			quote! {
+				let #mut_ __state = #serde::Serializer::serialize_struct(__serializer, #variant_name, #len)?;
+				#(#fields)*
+				#serde::ser::SerializeStruct::end(__state)
+			}
+		}
+	};
+
+	Some(quote! {
+		#pattern => {
+			#body
+		}
+	})
+}
+
+/// Style of a variant, taking skipped fields into account.
+enum Style<'a> {
+	/// Unit variant or newtype variant with a skipped field.
+	Unit,
+	/// Tuple variant with a single field.
+	Newtype(&'a Field<'a>),
+	/// Tuple variant.
+	Tuple,
+	/// Struct variant.
+	Struct,
+}
+
+impl<'a> Style<'a> {
+	/// Determine the [`Style`] of a variant.
+	fn new(data: &Data, fields: &[(&'a Field<'a>, attr::Field)]) -> Self {
+		match data.simple_type() {
+			SimpleType::Struct(_) => Style::Struct,
+			SimpleType::Tuple(_) => match fields {
+				[(_, attr)] if attr.skip_serializing => Style::Unit,
+				[(field, _)] => Style::Newtype(field),
+				_ => Style::Tuple,
+			},
+			SimpleType::Unit(_) | SimpleType::Union => Style::Unit,
+		}
+	}
+}
+
+/// Build statements serializing all fields that aren't skipped. Returns the
+/// number of serialized fields.
+fn build_fields(
+	serde: &Path,
+	fields: &[(&Field, attr::Field)],
+	rename_all: Option<RenameRule>,
+	trait_: TokenStream,
+	method: TokenStream,
+) -> (usize, Vec<TokenStream>) {
+	let fields: Vec<_> = fields
+		.iter()
+		.filter(|(_, attr)| !attr.skip_serializing)
+		.map(|(field, attr)| {
+			let self_ident = &field.self_ident;
+
+			match field.member {
+				Member::Named(ident) => {
+					let name = attr
+						.rename
+						.serialize_or(|| attr::field_name(rename_all, ident));
+
+					quote! {
+						#serde::ser::#trait_::#method(&mut __state, #name, #self_ident)?;
+					}
+				}
+				Member::Unnamed(_) => quote! {
+					#serde::ser::#trait_::#method(&mut __state, #self_ident)?;
+				},
+			}
+		})
+		.collect();
+
+	(fields.len(), fields)
+}
+
+/// Returns `mut` if any field is serialized.
+fn mut_(len: usize) -> Option<syn::Ident> {
+	(len > 0).then(|| format_ident!("mut"))
+}
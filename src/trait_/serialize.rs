@@ -3,20 +3,24 @@
 use std::{borrow::Cow, ops::Deref};
 
 use proc_macro2::{Span, TokenStream};
-use quote::format_ident;
 use syn::{
-	punctuated::Punctuated, Attribute, DeriveInput, Ident, ImplGenerics, Meta, Path, Result,
-	TypeGenerics, WhereClause,
+	punctuated::Punctuated, Attribute, DeriveInput, Meta, Path, Result, Token, TypeGenerics,
+	WhereClause, WherePredicate,
 };
 
-use super::serde;
-use crate::{util, DeriveTrait, Trait, TraitImpl};
+use super::serde::{self, Container};
+use crate::{util, DeriveTrait, DeriveWhere, Item, SplitGenerics, Trait, TraitImpl};
 
 /// [`TraitImpl`] for [`Serialize`](https://docs.rs/serde/latest/serde/derive.Serialize.html).
-#[derive(Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq)]
 pub struct Serialize {
 	/// [`Serialize`](https://docs.rs/serde/latest/serde/derive.Serialize.html) path.
 	pub crate_: Option<Path>,
+	/// User-provided `#[serde(bound)]` predicates for this trait.
+	pub bound: Punctuated<WherePredicate, Token![,]>,
+	/// `serde` attributes on the item, [`None`] if they aren't supported by the
+	/// native implementation.
+	pub container: Option<Container>,
 }
 
 impl TraitImpl for Serialize {
@@ -31,19 +35,27 @@ impl TraitImpl for Serialize {
 	where
 		Self: Sized,
 	{
-		DeriveTrait::Serialize(Self { crate_: None })
+		DeriveTrait::Serialize(Self {
+			crate_: None,
+			bound: Punctuated::new(),
+			container: None,
+		})
 	}
 
 	fn parse_derive_trait(
 		attrs: &[Attribute],
-		span: Span,
+		_span: Span,
 		list: Option<Punctuated<Meta, syn::Token![,]>>,
 	) -> Result<DeriveTrait>
 	where
 		Self: Sized,
 	{
+		let (crate_, bound) = serde::parse_derive_trait(Trait::Serialize, attrs, list)?;
+
 		Ok(DeriveTrait::Serialize(Self {
-			crate_: serde::parse_derive_trait(Trait::Serialize, attrs, span, list)?,
+			crate_,
+			bound,
+			container: Container::from_attrs(attrs),
 		}))
 	}
 
@@ -51,24 +63,39 @@ impl TraitImpl for Serialize {
 		util::path_from_root_and_strs(self.crate_(), &["Serialize"])
 	}
 
-	fn impl_item(
+	fn impl_with_item(
 		&self,
 		crate_: Option<&Path>,
 		full_item: &DeriveInput,
-		_: &ImplGenerics<'_>,
-		_: &Ident,
+		item: &Item,
 		_: &TypeGenerics<'_>,
 		where_clause: &Option<Cow<'_, WhereClause>>,
-		_: TokenStream,
+		body: &TokenStream,
+	) -> Option<TokenStream> {
+		// Forward to `serde` if the native implementation isn't supported.
+		body.is_empty().then(|| {
+			serde::impl_item(
+				crate_,
+				&self.crate_(),
+				Trait::Serialize,
+				full_item,
+				item,
+				where_clause,
+			)
+		})
+	}
+
+	fn build_signature(
+		&self,
+		_derive_where: &DeriveWhere,
+		item: &Item,
+		_generics: &SplitGenerics<'_>,
+		_body: &TokenStream,
 	) -> TokenStream {
-		serde::impl_item(
-			crate_,
-			&self.crate_(),
-			format_ident!("Serialize"),
-			format_ident!("serialize"),
-			full_item,
-			where_clause,
-		)
+		self.container
+			.as_ref()
+			.and_then(|container| serde::build_serialize(&self.crate_(), container, item))
+			.unwrap_or_default()
 	}
 }
 
@@ -0,0 +1,34 @@
+//! [`Sync`](trait@std::marker::Sync) implementation.
+
+use std::ops::Deref;
+
+use crate::{util, DeriveTrait, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`Sync`](trait@std::marker::Sync).
+pub struct Sync;
+
+impl TraitImpl for Sync {
+	fn as_str() -> &'static str {
+		"Sync"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::Sync
+	}
+
+	fn is_unsafe() -> bool {
+		true
+	}
+
+	fn path(&self) -> syn::Path {
+		util::path_from_strs(&["core", "marker", "Sync"])
+	}
+}
+
+impl Deref for Sync {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::Sync
+	}
+}
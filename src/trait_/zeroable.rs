@@ -0,0 +1,65 @@
+//! [`Zeroable`](https://docs.rs/bytemuck/latest/bytemuck/trait.Zeroable.html) implementation.
+
+use std::{borrow::Cow, ops::Deref};
+
+use proc_macro2::TokenStream;
+use syn::{DeriveInput, Ident, ImplGenerics, Path, TypeGenerics, WhereClause};
+
+use super::bytemuck;
+use crate::{util, Data, DeriveTrait, DeriveWhere, Item, SplitGenerics, Trait, TraitImpl};
+
+/// [`TraitImpl`] for [`Zeroable`](https://docs.rs/bytemuck/latest/bytemuck/trait.Zeroable.html).
+pub struct Zeroable;
+
+impl TraitImpl for Zeroable {
+	fn as_str() -> &'static str {
+		"Zeroable"
+	}
+
+	fn default_derive_trait() -> DeriveTrait {
+		DeriveTrait::Zeroable
+	}
+
+	fn supports_enum() -> bool {
+		false
+	}
+
+	fn path(&self) -> Path {
+		util::path_from_strs(&["bytemuck", "Zeroable"])
+	}
+
+	fn impl_item(
+		&self,
+		_: Option<&Path>,
+		_: &DeriveInput,
+		imp: &ImplGenerics<'_>,
+		ident: &Ident,
+		ty: &TypeGenerics<'_>,
+		where_clause: &Option<Cow<'_, WhereClause>>,
+		body: TokenStream,
+	) -> TokenStream {
+		bytemuck::impl_item(**self, &self.path(), imp, ident, ty, where_clause, body)
+	}
+
+	fn build_signature(
+		&self,
+		_derive_where: &DeriveWhere,
+		_item: &Item,
+		_generics: &SplitGenerics<'_>,
+		body: &TokenStream,
+	) -> TokenStream {
+		bytemuck::build_signature(**self, &self.path(), body)
+	}
+
+	fn build_body(&self, _derive_where: &DeriveWhere, data: &Data) -> TokenStream {
+		bytemuck::build_body(**self, data)
+	}
+}
+
+impl Deref for Zeroable {
+	type Target = Trait;
+
+	fn deref(&self) -> &Self::Target {
+		&Trait::Zeroable
+	}
+}
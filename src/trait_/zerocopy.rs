@@ -0,0 +1,63 @@
+//! Common functionality between the
+//! [`zerocopy`](https://docs.rs/zerocopy/latest/zerocopy/) traits.
+
+use std::borrow::Cow;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, DeriveInput, Path, Result, WhereClause};
+
+use crate::{
+	item::{self, Representation},
+	util, Error, Trait, DERIVE_WHERE,
+};
+
+/// Check that the item has the representation `zerocopy` requires to
+/// implement `trait_`. Padding is checked by `zerocopy` itself.
+pub fn check_repr(trait_: Trait, span: Span, attrs: &[Attribute], data: &syn::Data) -> Result<()> {
+	match (trait_, data) {
+		// Structs need a defined layout to be converted into bytes.
+		(Trait::IntoBytes, syn::Data::Struct(_))
+			if !item::repr(attrs)?
+				.iter()
+				.any(|repr| repr == "C" || repr == "transparent" || repr == "packed") =>
+		{
+			Err(Error::layout_repr(span, trait_.as_str()))
+		}
+		// Enums need a defined discriminant to be converted from or into bytes.
+		(Trait::FromBytes | Trait::FromZeros | Trait::IntoBytes, syn::Data::Enum(_))
+			if !item::repr(attrs)?
+				.iter()
+				.any(|repr| repr == "C" || Representation::parse(repr).is_some()) =>
+		{
+			Err(Error::zerocopy_repr_enum(span, trait_.as_str()))
+		}
+		_ => Ok(()),
+	}
+}
+
+/// Forward the item to `zerocopy`'s derive with the `where` clause generated
+/// by `derive_where`.
+pub fn impl_item(
+	derive_where: Option<&Path>,
+	trait_: Trait,
+	full_item: &DeriveInput,
+	where_clause: &Option<Cow<'_, WhereClause>>,
+) -> TokenStream {
+	let derive_where = derive_where
+		.map(Cow::Borrowed)
+		.unwrap_or_else(|| Cow::Owned(util::path_from_strs(&[DERIVE_WHERE])));
+
+	// `zerocopy` only bounds field types, so generics that only appear in
+	// `PhantomData` stay unbounded unless `derive_where` adds a bound.
+	let mut full_item = full_item.clone();
+	full_item.generics.where_clause = where_clause.as_deref().cloned();
+
+	let trait_ = format_ident!("{}", trait_.as_str());
+
+	quote! {
+		#[::core::prelude::v1::derive(::zerocopy::#trait_)]
+		#[#derive_where::derive_where_serde]
+		#full_item
+	}
+}
@@ -4,13 +4,12 @@ use std::ops::Deref;
 
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{
-	punctuated::Punctuated, spanned::Spanned, Attribute, Expr, ExprLit, ExprPath, Lit, Meta, Path,
-	Result, Token,
-};
+use syn::{punctuated::Punctuated, spanned::Spanned, Attribute, Meta, Path, Result, Token};
 
 use crate::{
-	util, Data, DeriveTrait, DeriveWhere, Error, Item, SimpleType, SplitGenerics, Trait, TraitImpl,
+	attr::{crate_root, parse_crate_option},
+	util, Data, DeriveTrait, DeriveWhere, Either, Error, Field, Item, SimpleType, SplitGenerics,
+	Trait, TraitImpl,
 };
 
 /// [`TraitImpl`] for [`Zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html).
@@ -18,6 +17,8 @@ use crate::{
 pub struct Zeroize {
 	/// [`Zeroize`](https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html) path.
 	pub crate_: Option<Path>,
+	/// If this is implemented for a union.
+	pub union_: bool,
 }
 
 impl TraitImpl for Zeroize {
@@ -26,7 +27,14 @@ impl TraitImpl for Zeroize {
 	}
 
 	fn default_derive_trait() -> DeriveTrait {
-		DeriveTrait::Zeroize(Self { crate_: None })
+		DeriveTrait::Zeroize(Self {
+			crate_: None,
+			union_: false,
+		})
+	}
+
+	fn supports_union() -> bool {
+		true
 	}
 
 	fn parse_derive_trait(
@@ -41,40 +49,27 @@ impl TraitImpl for Zeroize {
 		};
 
 		let mut crate_ = None;
+		let mut union_ = false;
 
 		for meta in list {
 			match &meta {
 				Meta::Path(path) => {
 					if path.is_ident("drop") {
 						return Err(Error::deprecated_zeroize_drop(path.span()));
+					} else if path.is_ident("union") {
+						// Check for duplicate `union` option.
+						if !union_ {
+							union_ = true;
+						} else {
+							return Err(Error::option_duplicate(path.span(), "union"));
+						}
 					} else {
 						return Err(Error::option_trait(path.span(), Self::as_str()));
 					}
 				}
 				Meta::NameValue(name_value) => {
 					if name_value.path.is_ident("crate") {
-						// Check for duplicate `crate` option.
-						if crate_.is_none() {
-							let path = match &name_value.value {
-								Expr::Lit(ExprLit {
-									lit: Lit::Str(lit_str),
-									..
-								}) => match lit_str.parse::<Path>() {
-									Ok(path) => path,
-									Err(error) => return Err(Error::path(lit_str.span(), error)),
-								},
-								Expr::Path(ExprPath { path, .. }) => path.clone(),
-								_ => return Err(Error::option_syntax(name_value.value.span())),
-							};
-
-							if path == util::path_from_strs(&["zeroize"]) {
-								return Err(Error::path_unnecessary(path.span(), "::zeroize"));
-							}
-
-							crate_ = Some(path);
-						} else {
-							return Err(Error::option_duplicate(name_value.span(), "crate"));
-						}
+						parse_crate_option(&mut crate_, name_value, "zeroize")?;
 					} else {
 						return Err(Error::option_trait(name_value.path.span(), Self::as_str()));
 					}
@@ -85,7 +80,7 @@ impl TraitImpl for Zeroize {
 			}
 		}
 
-		Ok(DeriveTrait::Zeroize(Self { crate_ }))
+		Ok(DeriveTrait::Zeroize(Self { crate_, union_ }))
 	}
 
 	fn path(&self) -> syn::Path {
@@ -100,6 +95,15 @@ impl TraitImpl for Zeroize {
 		body: &TokenStream,
 	) -> TokenStream {
 		match item {
+			Item::Item(data) if self.union_ => {
+				let body = union_body(data);
+
+				quote! {
+					fn zeroize(&mut self) {
+						#body
+					}
+				}
+			}
 			Item::Item(data) if data.is_empty(**self) => quote! {
 				fn zeroize(&mut self) { }
 			},
@@ -124,16 +128,17 @@ impl TraitImpl for Zeroize {
 				let trait_path = self.path();
 				let self_pattern = fields.self_pattern_mut();
 
-				let body = data
-					.iter_fields(**self)
-					.zip(data.iter_self_ident(**self))
-					.map(|(field, self_ident)| {
-						if field.attr.zeroize_fqs.0 {
-							quote! { #trait_path::zeroize(#self_ident); }
-						} else {
-							quote! { #self_ident.zeroize(); }
-						}
-					});
+				let body = ordered_fields(data, **self).into_iter().map(|field| {
+					let self_ident = &field.self_ident;
+
+					if let Some(with) = &field.attr.zeroize.with {
+						quote! { #with(#self_ident); }
+					} else if field.attr.zeroize.fqs {
+						quote! { #trait_path::zeroize(#self_ident); }
+					} else {
+						quote! { #self_ident.zeroize(); }
+					}
+				});
 
 				quote! {
 					#self_pattern => {
@@ -141,8 +146,9 @@ impl TraitImpl for Zeroize {
 					}
 				}
 			}
-			SimpleType::Unit(_) => TokenStream::new(),
-			SimpleType::Union => unreachable!("unexpected trait for union"),
+			SimpleType::Unit(pattern) => quote! { #pattern => { } },
+			// Unions are zeroized in `build_signature`.
+			SimpleType::Union => TokenStream::new(),
 		}
 	}
 }
@@ -150,11 +156,40 @@ impl TraitImpl for Zeroize {
 impl Zeroize {
 	/// Returns the path to the root crate for this trait.
 	fn crate_(&self) -> Path {
-		if let Some(crate_) = &self.crate_ {
-			crate_.clone()
-		} else {
-			util::path_from_strs(&["zeroize"])
+		crate_root(self.crate_.as_ref(), "zeroize")
+	}
+}
+
+/// Returns the [`Field`]s to zeroize, sorted by their `Zeroize(order = ..)`.
+/// Fields with the same order keep their declaration order.
+pub fn ordered_fields<'a>(data: &'a Data<'_>, trait_: Trait) -> Vec<&'a Field<'a>> {
+	let mut fields: Vec<_> = data.iter_fields(trait_).collect();
+	fields.sort_by_key(|field| field.attr.zeroize.order());
+	fields
+}
+
+/// Builds a body overwriting the whole union with zeroes. Only unions with
+/// fields that are all [`Copy`] are supported, as anything else could hold data
+/// outside of the union itself.
+pub fn union_body(data: &Data) -> TokenStream {
+	let types = match data.fields() {
+		Either::Left(fields) => fields.fields.iter().map(|field| field.type_),
+		Either::Right(_) => unreachable!("unexpected unit union"),
+	};
+
+	quote! {
+		struct __AssertCopy<__T: ::core::marker::Copy + ?::core::marker::Sized>(::core::marker::PhantomData<__T>);
+		#(let _: __AssertCopy<#types>;)*
+
+		let __ptr = (self as *mut Self).cast::<::core::primitive::u8>();
+
+		for __index in 0..::core::mem::size_of::<Self>() {
+			// SAFETY: `__ptr` is valid for `size_of::<Self>()` bytes and every field is
+			// `Copy`, so overwriting them doesn't skip any `Drop`.
+			unsafe { ::core::ptr::write_volatile(__ptr.add(__index), 0) };
 		}
+
+		::core::sync::atomic::compiler_fence(::core::sync::atomic::Ordering::SeqCst);
 	}
 }
 
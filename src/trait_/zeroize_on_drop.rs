@@ -5,11 +5,15 @@ use std::{borrow::Cow, iter, ops::Deref};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{
-	punctuated::Punctuated, spanned::Spanned, Attribute, DeriveInput, Expr, ExprLit, ExprPath,
-	Ident, ImplGenerics, Lit, Meta, Path, Result, Token, TypeGenerics, WhereClause,
+	punctuated::Punctuated, spanned::Spanned, Attribute, DeriveInput, Expr, ExprPath, Ident,
+	ImplGenerics, Meta, Path, Result, Token, TypeGenerics, WhereClause,
 };
 
-use crate::{util, DeriveTrait, DeriveWhere, Error, Item, SplitGenerics, Trait, TraitImpl};
+use super::zeroize;
+use crate::{
+	attr::{crate_root, parse_crate_option},
+	util, DeriveTrait, DeriveWhere, Either, Error, Item, SplitGenerics, Trait, TraitImpl,
+};
 #[cfg(feature = "zeroize-on-drop")]
 use crate::{Data, SimpleType};
 
@@ -20,6 +24,10 @@ pub struct ZeroizeOnDrop {
 	pub crate_: Option<Path>,
 	/// If `Drop` should be implemented.
 	pub no_drop: bool,
+	/// Function to call before zeroizing on [`Drop`].
+	pub on_drop: Option<Path>,
+	/// If this is implemented for a union.
+	pub union_: bool,
 }
 
 impl TraitImpl for ZeroizeOnDrop {
@@ -31,12 +39,18 @@ impl TraitImpl for ZeroizeOnDrop {
 		DeriveTrait::ZeroizeOnDrop(Self {
 			crate_: None,
 			no_drop: false,
+			on_drop: None,
+			union_: false,
 		})
 	}
 
+	fn supports_union() -> bool {
+		true
+	}
+
 	fn parse_derive_trait(
 		_: &[Attribute],
-		_: Span,
+		#[cfg_attr(not(feature = "zeroize-on-drop"), allow(unused_variables))] span: Span,
 		list: Option<Punctuated<Meta, Token![,]>>,
 	) -> Result<DeriveTrait> {
 		let list = if let Some(list) = list {
@@ -48,6 +62,8 @@ impl TraitImpl for ZeroizeOnDrop {
 		let mut crate_ = None;
 		#[cfg_attr(not(feature = "zeroize-on-drop"), allow(unused_mut))]
 		let mut no_drop = false;
+		let mut on_drop = None;
+		let mut union_ = false;
 
 		for meta in list {
 			match &meta {
@@ -66,31 +82,31 @@ impl TraitImpl for ZeroizeOnDrop {
 						return Err(Error::zeroize_on_drop_feature(path.span()));
 					}
 
+					if path.is_ident("union") {
+						// Check for duplicate `union` option.
+						if !union_ {
+							union_ = true;
+							continue;
+						} else {
+							return Err(Error::option_duplicate(path.span(), "union"));
+						}
+					}
+
 					return Err(Error::option_trait(path.span(), Self::as_str()));
 				}
 				Meta::NameValue(name_value) => {
 					if name_value.path.is_ident("crate") {
-						// Check for duplicate `crate` option.
-						if crate_.is_none() {
-							let path = match &name_value.value {
-								Expr::Lit(ExprLit {
-									lit: Lit::Str(lit_str),
-									..
-								}) => match lit_str.parse::<Path>() {
-									Ok(path) => path,
-									Err(error) => return Err(Error::path(lit_str.span(), error)),
-								},
-								Expr::Path(ExprPath { path, .. }) => path.clone(),
-								_ => return Err(Error::option_syntax(name_value.value.span())),
-							};
-
-							if path == util::path_from_strs(&["zeroize"]) {
-								return Err(Error::path_unnecessary(path.span(), "::zeroize"));
+						parse_crate_option(&mut crate_, name_value, "zeroize")?;
+					} else if name_value.path.is_ident("on_drop") {
+						// Check for duplicate `on_drop` option.
+						if on_drop.is_none() {
+							if let Expr::Path(ExprPath { path, .. }) = &name_value.value {
+								on_drop = Some(path.clone());
+							} else {
+								return Err(Error::option_syntax(name_value.value.span()));
 							}
-
-							crate_ = Some(path);
 						} else {
-							return Err(Error::option_duplicate(name_value.span(), "crate"));
+							return Err(Error::option_duplicate(name_value.span(), "on_drop"));
 						}
 					} else {
 						return Err(Error::option_trait(name_value.path.span(), Self::as_str()));
@@ -102,14 +118,34 @@ impl TraitImpl for ZeroizeOnDrop {
 			}
 		}
 
-		Ok(DeriveTrait::ZeroizeOnDrop(Self { crate_, no_drop }))
+		#[cfg(feature = "zeroize-on-drop")]
+		if no_drop {
+			// Unions are zeroized in place, their fields can't implement `ZeroizeOnDrop`.
+			if union_ {
+				return Err(Error::zeroize_option_combination(span, "union", "no_drop"));
+			}
+
+			// Without a `Drop` implementation there is nowhere to call the hook.
+			if on_drop.is_some() {
+				return Err(Error::zeroize_option_combination(
+					span, "on_drop", "no_drop",
+				));
+			}
+		}
+
+		Ok(DeriveTrait::ZeroizeOnDrop(Self {
+			crate_,
+			no_drop,
+			on_drop,
+			union_,
+		}))
 	}
 
 	fn path(&self) -> syn::Path {
 		util::path_from_root_and_strs(self.crate_(), &["ZeroizeOnDrop"])
 	}
 
-	fn additional_impl(&self) -> Option<(Path, TokenStream)> {
+	fn additional_impl(&self, _: &DeriveWhere, _: &Item) -> Option<(Path, TokenStream)> {
 		#[cfg(feature = "zeroize-on-drop")]
 		return Some((self.path(), quote! {}));
 		#[cfg(not(feature = "zeroize-on-drop"))]
@@ -170,9 +206,28 @@ impl TraitImpl for ZeroizeOnDrop {
 		_generics: &SplitGenerics<'_>,
 		body: &TokenStream,
 	) -> TokenStream {
+		// The hook runs before any field is zeroized.
+		let on_drop = self
+			.on_drop
+			.as_ref()
+			.map(|on_drop| quote! { #on_drop(self); });
+		let zeroize_to = zeroize_to(item);
+
 		match item {
+			Item::Item(data) if self.union_ => {
+				let body = zeroize::union_body(data);
+
+				quote! {
+					fn drop(&mut self) {
+						#on_drop
+						#body
+					}
+				}
+			}
 			Item::Item(data) if data.is_empty(**self) => quote! {
-				fn drop(&mut self) { }
+				fn drop(&mut self) {
+					#on_drop
+				}
 			},
 			#[cfg(feature = "zeroize-on-drop")]
 			_ => {
@@ -218,9 +273,13 @@ impl TraitImpl for ZeroizeOnDrop {
 								}
 							}
 
+							#on_drop
+
 							match self {
 								#body
 							}
+
+							#zeroize_to
 						}
 					}
 				}
@@ -234,7 +293,9 @@ impl TraitImpl for ZeroizeOnDrop {
 
 				quote! {
 					fn drop(&mut self) {
+						#on_drop
 						#path::zeroize(self);
+						#zeroize_to
 					}
 				}
 			}
@@ -247,18 +308,31 @@ impl TraitImpl for ZeroizeOnDrop {
 			#[cfg(feature = "zeroize-on-drop")]
 			SimpleType::Struct(fields) | SimpleType::Tuple(fields) => {
 				let self_pattern = fields.self_pattern_mut();
-				let self_ident = data.iter_self_ident(**self);
 
 				if self.no_drop {
+					let self_ident = data.iter_self_ident(**self);
+
 					quote! {
 						#self_pattern => {
 							#(#self_ident.__derive_where_zeroize_on_drop();)*
 						}
 					}
 				} else {
+					let body = zeroize::ordered_fields(data, **self)
+						.into_iter()
+						.map(|field| {
+							let self_ident = &field.self_ident;
+
+							if let Some(with) = &field.attr.zeroize.with {
+								quote! { #with(#self_ident); }
+							} else {
+								quote! { #self_ident.__derive_where_zeroize_or_on_drop(); }
+							}
+						});
+
 					quote! {
 						#self_pattern => {
-							#(#self_ident.__derive_where_zeroize_or_on_drop();)*
+							#(#body)*
 						}
 					}
 				}
@@ -274,8 +348,9 @@ impl TraitImpl for ZeroizeOnDrop {
 					#path::zeroize(self);
 				}
 			}
-			SimpleType::Unit(_) => TokenStream::new(),
-			SimpleType::Union => unreachable!("unexpected trait for union"),
+			SimpleType::Unit(pattern) => quote! { #pattern => { } },
+			// Unions are zeroized in `build_signature`.
+			SimpleType::Union => TokenStream::new(),
 		}
 	}
 }
@@ -283,14 +358,56 @@ impl TraitImpl for ZeroizeOnDrop {
 impl ZeroizeOnDrop {
 	/// Returns the path to the root crate for this trait.
 	fn crate_(&self) -> Path {
-		if let Some(crate_) = &self.crate_ {
-			crate_.clone()
-		} else {
-			util::path_from_strs(&["zeroize"])
-		}
+		crate_root(self.crate_.as_ref(), "zeroize")
 	}
 }
 
+/// Builds a `match` overwriting `self` with the variant marked with
+/// `zeroize_to` after it was zeroized. Fields are moved out before and dropped
+/// after, so they are dropped exactly once without dropping `self` again.
+fn zeroize_to(item: &Item) -> Option<TokenStream> {
+	let target = item.zeroize_to()?.self_pattern();
+
+	let variants = match item {
+		Item::Enum { variants, .. } => variants,
+		Item::Item(_) => unreachable!("unexpected `zeroize_to` on item"),
+	};
+
+	let arms = variants.iter().map(|data| match data.fields() {
+		Either::Left(fields) if !fields.fields.is_empty() => {
+			let self_pattern = fields.self_pattern_mut();
+			let self_ident = fields.fields.iter().map(|field| &field.self_ident);
+
+			quote! {
+				#self_pattern => {
+					// SAFETY: every field is read exactly once and `self` is overwritten
+					// without being dropped, so the fields are only dropped through
+					// `__fields`.
+					let __fields = unsafe { (#(::core::ptr::read(#self_ident),)*) };
+					unsafe { ::core::ptr::write_volatile(self, #target) };
+					::core::mem::drop(__fields);
+				}
+			}
+		}
+		_ => {
+			let self_pattern = data.self_pattern();
+
+			quote! {
+				// SAFETY: this variant has no fields that could be leaked.
+				#self_pattern => unsafe { ::core::ptr::write_volatile(self, #target) },
+			}
+		}
+	});
+
+	Some(quote! {
+		match self {
+			#(#arms)*
+		}
+
+		::core::sync::atomic::compiler_fence(::core::sync::atomic::Ordering::SeqCst);
+	})
+}
+
 impl Deref for ZeroizeOnDrop {
 	type Target = Trait;
 
@@ -1,9 +1,15 @@
 //! Utility functions.
 
-use proc_macro2::Span;
+use proc_macro2::{Spacing, Span, TokenStream, TokenTree};
+#[cfg(any(feature = "schemars", feature = "serde"))]
+use syn::parse_quote;
+#[cfg(any(feature = "rkyv", feature = "schemars", feature = "serde"))]
+use syn::Attribute;
 use syn::{
-	punctuated::Punctuated, spanned::Spanned, Ident, Meta, MetaList, Path, PathArguments,
-	PathSegment, Result, Token,
+	parse::{Parse, ParseStream},
+	punctuated::Punctuated,
+	spanned::Spanned,
+	Ident, Lifetime, Meta, MetaList, Path, PathArguments, PathSegment, Result, Token,
 };
 
 use crate::error::Error;
@@ -55,6 +61,91 @@ pub fn path_from_root_and_strs(root: Path, segments: &[&str]) -> Path {
 	}
 }
 
+/// Returns `true` if `attr` is a `name` attribute containing `option`.
+#[cfg(any(feature = "rkyv", feature = "schemars", feature = "serde"))]
+pub fn has_option(attr: &Attribute, name: &str, option: &str) -> bool {
+	attr.path().is_ident(name)
+		&& attr
+			.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+			.map(|nested| nested.iter().any(|meta| meta.path().is_ident(option)))
+			.unwrap_or(false)
+}
+
+/// Removes `option` from all `name` attributes in `attrs`. Attributes left
+/// without any options are removed entirely.
+#[cfg(any(feature = "schemars", feature = "serde"))]
+pub fn remove_option(attrs: &mut Vec<Attribute>, name: &str, option: &str) {
+	*attrs = attrs
+		.drain(..)
+		.filter_map(|attr| {
+			if !has_option(&attr, name, option) {
+				return Some(attr);
+			}
+
+			let nested: Punctuated<Meta, Token![,]> = attr
+				.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+				.expect("already parsed")
+				.into_iter()
+				.filter(|meta| !meta.path().is_ident(option))
+				.collect();
+
+			if nested.is_empty() {
+				None
+			} else {
+				let name = Ident::new(name, Span::call_site());
+				Some(parse_quote! { #[#name(#nested)] })
+			}
+		})
+		.collect();
+}
+
+/// Returns all [`Lifetime`](struct@Lifetime)s used in `tokens`, except
+/// `'static`, `'_` and lifetimes introduced by `for<..>`.
+pub fn free_lifetimes(tokens: TokenStream) -> Vec<Lifetime> {
+	/// Collect used and `for<..>` lifetimes.
+	fn collect(tokens: TokenStream, used: &mut Vec<Lifetime>, bound: &mut Vec<Lifetime>) {
+		let tokens: Vec<_> = tokens.into_iter().collect();
+		// Tracks if we are inside `for<..>`.
+		let mut in_for = false;
+
+		for (index, token) in tokens.iter().enumerate() {
+			match token {
+				TokenTree::Group(group) => collect(group.stream(), used, bound),
+				TokenTree::Ident(ident) if ident == "for" => {
+					in_for = matches!(tokens.get(index + 1), Some(TokenTree::Punct(punct)) if punct.as_char() == '<');
+				}
+				TokenTree::Punct(punct) if punct.as_char() == '>' => in_for = false,
+				TokenTree::Punct(punct)
+					if punct.as_char() == '\'' && punct.spacing() == Spacing::Joint =>
+				{
+					if let Some(TokenTree::Ident(ident)) = tokens.get(index + 1) {
+						let lifetime = Lifetime {
+							apostrophe: punct.span(),
+							ident: ident.clone(),
+						};
+
+						if in_for {
+							bound.push(lifetime);
+						} else {
+							used.push(lifetime);
+						}
+					}
+				}
+				_ => (),
+			}
+		}
+	}
+
+	let mut used = Vec::new();
+	let mut bound = Vec::new();
+	collect(tokens, &mut used, &mut bound);
+
+	used.retain(|lifetime| {
+		lifetime.ident != "static" && lifetime.ident != "_" && !bound.contains(lifetime)
+	});
+	used
+}
+
 /// Extension for [`MetaList`].
 pub trait MetaListExt {
 	/// Shorthand for parsing a [`MetaList`] into a list of [`Meta`]s.
@@ -63,7 +154,9 @@ pub trait MetaListExt {
 
 impl MetaListExt for MetaList {
 	fn parse_non_empty_nested_metas(&self) -> Result<Punctuated<Meta, Token![,]>> {
-		let list = self.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+		let list = self.parse_args_with(|input: ParseStream| {
+			Punctuated::<Meta, Token![,]>::parse_terminated_with(input, parse_nested_meta)
+		})?;
 
 		if list.is_empty() {
 			return Err(Error::option_empty(self.span()));
@@ -72,3 +165,20 @@ impl MetaListExt for MetaList {
 		Ok(list)
 	}
 }
+
+/// Parse a nested [`Meta`]. Unlike [`Meta::parse()`], this also supports the
+/// `const` keyword as an option.
+fn parse_nested_meta(input: ParseStream) -> Result<Meta> {
+	if input.peek(Token![const]) {
+		let const_ = <Token![const]>::parse(input)?;
+
+		// Only `const` without a list or value is supported.
+		if input.is_empty() || input.peek(Token![,]) {
+			Ok(Meta::Path(Path::from(Ident::new("const", const_.span))))
+		} else {
+			Err(Error::option_syntax(input.span()))
+		}
+	} else {
+		Meta::parse(input)
+	}
+}
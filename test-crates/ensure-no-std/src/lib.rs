@@ -1,5 +1,7 @@
 #![no_std]
 
+#[cfg(feature = "defmt")]
+extern crate defmt_ as defmt;
 #[cfg(feature = "zeroize")]
 extern crate zeroize_ as zeroize;
 
@@ -8,6 +10,7 @@ use core::marker::PhantomData;
 use derive_where::derive_where;
 
 #[derive_where(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive_where(Format))]
 #[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize))]
 #[cfg_attr(feature = "serde", serde(crate = "serde_"))]
 #[cfg_attr(feature = "zeroize", derive_where(Zeroize))]
@@ -0,0 +1,126 @@
+#![cfg(feature = "arbitrary")]
+
+extern crate arbitrary_ as arbitrary;
+
+use std::marker::PhantomData;
+
+use arbitrary::{Arbitrary, Unstructured};
+use derive_where::derive_where;
+
+struct NotArbitrary;
+
+#[test]
+fn struct_() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[derive_where(Arbitrary; T)]
+	struct Test<T, U> {
+		a: T,
+		b: PhantomData<U>,
+		c: u8,
+	}
+
+	let mut u = Unstructured::new(&[1, 0, 2]);
+
+	assert_eq!(
+		Test::<u16, NotArbitrary>::arbitrary(&mut u).unwrap(),
+		Test {
+			a: 1,
+			b: PhantomData,
+			c: 2,
+		}
+	);
+	assert_eq!(
+		Test::<u16, NotArbitrary>::arbitrary_take_rest(Unstructured::new(&[1, 0, 2])).unwrap(),
+		Test {
+			a: 1,
+			b: PhantomData,
+			c: 2,
+		}
+	);
+	assert_eq!(Test::<u16, NotArbitrary>::size_hint(0), (3, Some(3)));
+}
+
+#[test]
+fn skip() {
+	#[derive_where(Debug, PartialEq)]
+	#[derive_where(Arbitrary)]
+	struct Test<T> {
+		a: u8,
+		#[derive_where(skip(Arbitrary))]
+		b: u8,
+		marker: PhantomData<T>,
+	}
+
+	let mut u = Unstructured::new(&[1, 2]);
+
+	assert_eq!(
+		Test::<NotArbitrary>::arbitrary(&mut u).unwrap(),
+		Test {
+			a: 1,
+			b: 0,
+			marker: PhantomData,
+		}
+	);
+	assert_eq!(Test::<NotArbitrary>::size_hint(0), (1, Some(1)));
+}
+
+#[test]
+fn skip_all() {
+	#[derive_where(Debug)]
+	#[derive_where(Arbitrary)]
+	struct Test<T> {
+		a: u8,
+		#[derive_where(skip)]
+		b: u8,
+		marker: PhantomData<T>,
+	}
+
+	let mut u = Unstructured::new(&[1, 2]);
+	let test = Test::<NotArbitrary>::arbitrary(&mut u).unwrap();
+
+	assert_eq!(test.a, 1);
+	assert_eq!(test.b, 2);
+	assert_eq!(Test::<NotArbitrary>::size_hint(0), (2, Some(2)));
+}
+
+#[test]
+fn enum_() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[derive_where(Arbitrary; T)]
+	enum Test<T, U> {
+		A(T),
+		B { marker: PhantomData<U> },
+		C,
+	}
+
+	let mut u = Unstructured::new(&[0, 0, 0, 0, 42]);
+	assert_eq!(
+		Test::<u8, NotArbitrary>::arbitrary(&mut u).unwrap(),
+		Test::A(42)
+	);
+
+	let mut u = Unstructured::new(&[0, 0, 0, 0x80]);
+	assert_eq!(
+		Test::<u8, NotArbitrary>::arbitrary(&mut u).unwrap(),
+		Test::B {
+			marker: PhantomData
+		}
+	);
+
+	let mut u = Unstructured::new(&[0xff, 0xff, 0xff, 0xff]);
+	assert_eq!(
+		Test::<u8, NotArbitrary>::arbitrary(&mut u).unwrap(),
+		Test::C
+	);
+
+	assert_eq!(Test::<u8, NotArbitrary>::size_hint(0), (4, Some(5)));
+}
+
+#[test]
+fn lifetime() {
+	#[derive_where(Arbitrary)]
+	struct Test<'a, T>(PhantomData<T>, &'a str);
+
+	let test = Test::<NotArbitrary>::arbitrary_take_rest(Unstructured::new(b"test")).unwrap();
+	assert_eq!(test.1, "test");
+}
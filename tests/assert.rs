@@ -0,0 +1,39 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[test]
+fn struct_() {
+	#[derive_where(Clone, Debug)]
+	#[derive_where(assert(Send, Sync, 'static); T: Send + Sync + 'static)]
+	struct Test<T>(PhantomData<T>);
+
+	let _ = format!("{:?}", Test::<u8>(PhantomData).clone());
+}
+
+#[test]
+fn enum_() {
+	#[derive_where(assert(Send, Unpin); T)]
+	enum Test<T> {
+		A(T),
+		B,
+	}
+
+	assert!(matches!(Test::A(42), Test::A(42)));
+	assert!(matches!(Test::<u8>::B, Test::B));
+}
+
+#[test]
+fn union_() {
+	#[derive_where(Clone, Copy)]
+	#[derive_where(assert(Copy); T)]
+	union Test<T: Copy> {
+		a: T,
+		b: u8,
+	}
+
+	let test = Test { a: 42_u8 };
+	let copy = test;
+	assert_eq!(unsafe { test.b }, 42);
+	assert_eq!(unsafe { copy.a }, 42);
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "borsh")]
+
+extern crate borsh_ as borsh;
+
+use std::marker::PhantomData;
+
+use borsh::{from_slice, to_vec};
+use derive_where::derive_where;
+
+struct NotBorsh;
+
+#[test]
+fn struct_() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[derive_where(BorshDeserialize, BorshSerialize; T)]
+	struct Test<T, U> {
+		a: T,
+		b: PhantomData<U>,
+		c: u8,
+	}
+
+	let test = Test::<u16, NotBorsh> {
+		a: 1,
+		b: PhantomData,
+		c: 2,
+	};
+	let bytes = to_vec(&test).unwrap();
+
+	assert_eq!(bytes, [1, 0, 2]);
+	assert_eq!(from_slice::<Test<u16, NotBorsh>>(&bytes).unwrap(), test);
+}
+
+#[test]
+fn tuple() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[derive_where(BorshDeserialize, BorshSerialize; T)]
+	struct Test<T, U>(T, PhantomData<U>, u8);
+
+	let test = Test::<u16, NotBorsh>(1, PhantomData, 2);
+	let bytes = to_vec(&test).unwrap();
+
+	assert_eq!(bytes, [1, 0, 2]);
+	assert_eq!(from_slice::<Test<u16, NotBorsh>>(&bytes).unwrap(), test);
+}
+
+#[test]
+fn enum_() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[derive_where(BorshDeserialize, BorshSerialize; T)]
+	enum Test<T, U> {
+		A { a: T },
+		B(u8, PhantomData<U>),
+		C,
+	}
+
+	for (test, expected) in [
+		(Test::<u16, NotBorsh>::A { a: 1 }, &[0, 1, 0][..]),
+		(Test::B(2, PhantomData), &[1, 2]),
+		(Test::C, &[2]),
+	] {
+		let bytes = to_vec(&test).unwrap();
+
+		assert_eq!(bytes, expected);
+		assert_eq!(from_slice::<Test<u16, NotBorsh>>(&bytes).unwrap(), test);
+	}
+
+	assert!(from_slice::<Test<u16, NotBorsh>>(&[3]).is_err());
+}
+
+#[test]
+fn discriminant() {
+	#[derive_where(Debug, PartialEq)]
+	#[derive_where(BorshDeserialize, BorshSerialize)]
+	#[repr(u8)]
+	enum Test<T> {
+		A(PhantomData<T>) = 5,
+		B,
+		C = 10,
+	}
+
+	for (test, expected) in [
+		(Test::<NotBorsh>::A(PhantomData), &[5][..]),
+		(Test::B, &[6]),
+		(Test::C, &[10]),
+	] {
+		let bytes = to_vec(&test).unwrap();
+
+		assert_eq!(bytes, expected);
+		assert_eq!(from_slice::<Test<NotBorsh>>(&bytes).unwrap(), test);
+	}
+
+	assert!(from_slice::<Test<NotBorsh>>(&[0]).is_err());
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "bytemuck")]
+
+extern crate bytemuck_ as bytemuck;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+/// Marker that doesn't implement any `bytemuck` trait.
+struct Marker;
+
+#[test]
+fn zeroable() {
+	#[derive_where(Zeroable; T)]
+	struct Test<T, E> {
+		a: T,
+		b: PhantomData<E>,
+	}
+
+	let test: Test<u32, Marker> = bytemuck::Zeroable::zeroed();
+	assert_eq!(test.a, 0);
+}
+
+#[test]
+fn pod() {
+	#[derive_where(Zeroable, Pod)]
+	#[derive(Clone, Copy)]
+	#[repr(C)]
+	struct Test {
+		a: u16,
+		b: [u8; 2],
+	}
+
+	let test = Test {
+		a: u16::from_ne_bytes([1, 2]),
+		b: [3, 4],
+	};
+
+	assert_eq!(bytemuck::bytes_of(&test), [1, 2, 3, 4]);
+	assert_eq!(bytemuck::cast::<_, [u8; 4]>(test), [1, 2, 3, 4]);
+}
+
+#[test]
+fn transparent() {
+	#[derive_where(Clone, Copy, Zeroable, Pod; T: bytemuck::Pod)]
+	#[repr(transparent)]
+	struct Test<T>(T);
+
+	let test: Test<u32> = bytemuck::cast(42_u32);
+	assert_eq!(test.0, 42);
+}
+
+#[test]
+fn packed() {
+	#[derive_where(Clone, Copy, Zeroable, Pod; T: bytemuck::Pod)]
+	#[repr(C, packed)]
+	struct Test<T> {
+		a: u8,
+		b: T,
+	}
+
+	let test = Test {
+		a: 1,
+		b: u16::from_ne_bytes([2, 3]),
+	};
+
+	assert_eq!(bytemuck::bytes_of(&test), [1, 2, 3]);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "nightly")]
+#![feature(const_trait_impl, const_cmp, const_default, const_clone)]
+
+// Has to be in a separate file so it isn't parsed on stable.
+#[path = "const_/marker.rs"]
+mod marker;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+use self::marker::Marker;
+
+#[test]
+fn struct_() {
+	#[derive_where(Clone(const), Default(const), PartialEq(const); T)]
+	struct Test<T, U> {
+		a: T,
+		b: Marker<U>,
+	}
+
+	const DEFAULT: Test<u8, ()> = Test::default();
+	const CLONE: Test<u8, ()> = DEFAULT.clone();
+	const EQ: bool = CLONE.eq(&DEFAULT);
+	const NE: bool = CLONE.eq(&Test {
+		a: 42,
+		b: Marker(PhantomData),
+	});
+
+	const _: () = assert!(EQ);
+	const _: () = assert!(!NE);
+}
+
+#[test]
+fn tuple() {
+	#[derive_where(Clone(const), Default(const), PartialEq(const); T)]
+	struct Test<T, U>(T, Marker<U>);
+
+	const DEFAULT: Test<u8, ()> = Test::default();
+	const CLONE: Test<u8, ()> = DEFAULT.clone();
+	const EQ: bool = CLONE.eq(&DEFAULT);
+	const NE: bool = CLONE.eq(&Test(42, Marker(PhantomData)));
+
+	const _: () = assert!(EQ);
+	const _: () = assert!(!NE);
+}
+
+#[test]
+fn enum_() {
+	#[derive_where(Clone(const), Default(const), PartialEq(const); T)]
+	enum Test<T, U> {
+		A(T),
+		#[derive_where(default)]
+		B,
+		C(Marker<U>),
+	}
+
+	const DEFAULT: Test<u8, ()> = Test::default();
+	const CLONE: Test<u8, ()> = Test::A(42).clone();
+	const EQ: bool = CLONE.eq(&Test::A(42));
+	const NE_FIELD: bool = CLONE.eq(&Test::A(0));
+	const NE_VARIANT: bool = DEFAULT.eq(&Test::C(Marker(PhantomData)));
+
+	assert!(matches!(DEFAULT, Test::B));
+	const _: () = assert!(EQ);
+	const _: () = assert!(!NE_FIELD);
+	const _: () = assert!(!NE_VARIANT);
+}
+
+#[test]
+fn copy() {
+	#[derive_where(Clone(const), Copy)]
+	struct Test<T>(u8, PhantomData<T>);
+
+	const CLONE: Test<()> = Clone::clone(&Test(42, PhantomData));
+
+	assert_eq!(CLONE.0, 42);
+}
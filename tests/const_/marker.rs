@@ -0,0 +1,22 @@
+use std::marker::PhantomData;
+
+/// [`PhantomData`] doesn't implement `const` traits yet.
+pub struct Marker<T>(pub PhantomData<T>);
+
+impl<T> const Clone for Marker<T> {
+	fn clone(&self) -> Self {
+		Marker(PhantomData)
+	}
+}
+
+impl<T> const Default for Marker<T> {
+	fn default() -> Self {
+		Marker(PhantomData)
+	}
+}
+
+impl<T> const PartialEq for Marker<T> {
+	fn eq(&self, _: &Self) -> bool {
+		true
+	}
+}
@@ -0,0 +1,90 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+trait Constant {
+	const DEFAULT: Self;
+}
+
+impl Constant for u8 {
+	const DEFAULT: Self = 42;
+}
+
+struct Nested(u8);
+
+impl Nested {
+	const DEFAULT: Self = Nested(1);
+}
+
+#[test]
+fn struct_() {
+	#[derive_where(ConstDefault; T: Constant)]
+	struct Test<T, U> {
+		a: T,
+		b: Nested,
+		#[derive_where(ConstDefault(value = PhantomData))]
+		c: PhantomData<U>,
+		#[derive_where(ConstDefault(value = 2 + 3))]
+		d: u8,
+	}
+
+	const TEST: Test<u8, ()> = Test::DEFAULT;
+
+	assert_eq!(TEST.a, 42);
+	assert_eq!(TEST.b.0, 1);
+	assert_eq!(TEST.d, 5);
+}
+
+#[test]
+fn tuple() {
+	#[derive_where(ConstDefault; T: Constant)]
+	struct Test<T, U>(
+		T,
+		Nested,
+		#[derive_where(ConstDefault(value = PhantomData))] PhantomData<U>,
+	);
+
+	const TEST: Test<u8, ()> = Test::DEFAULT;
+
+	assert_eq!(TEST.0, 42);
+	assert_eq!(TEST.1 .0, 1);
+}
+
+#[test]
+fn enum_() {
+	#[derive_where(ConstDefault)]
+	enum Test<T> {
+		A(T),
+		#[derive_where(default)]
+		B {
+			#[derive_where(ConstDefault(value = PhantomData))]
+			marker: PhantomData<T>,
+			nested: Nested,
+		},
+	}
+
+	const TEST: Test<()> = Test::DEFAULT;
+
+	assert!(matches!(
+		TEST,
+		Test::B {
+			nested: Nested(1),
+			..
+		}
+	));
+	assert!(matches!(Test::A(()), Test::A(())));
+}
+
+#[test]
+fn default() {
+	#[derive_where(Default, ConstDefault; T: Constant)]
+	enum Test<T> {
+		A(T),
+		#[derive_where(default)]
+		B(u8),
+	}
+
+	assert!(matches!(Test::<u8>::default(), Test::B(0)));
+	assert!(matches!(Test::<u8>::DEFAULT, Test::B(42)));
+	assert!(matches!(Test::A(1), Test::A(1)));
+}
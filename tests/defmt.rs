@@ -0,0 +1,109 @@
+#![cfg(feature = "defmt")]
+
+extern crate defmt_ as defmt;
+
+use std::marker::PhantomData;
+
+use defmt::{export, Format};
+use derive_where::derive_where;
+
+struct NotFormat;
+
+/// Formats `value` and returns the written bytes.
+fn format(value: &impl Format) -> Vec<u8> {
+	export::fetch_bytes();
+	value.format(export::make_formatter());
+	export::fetch_bytes()
+}
+
+/// Returns the encoded index of the string interned `offset` strings from now.
+fn istr(offset: u16) -> Vec<u8> {
+	(export::fetch_string_index() + offset)
+		.to_le_bytes()
+		.to_vec()
+}
+
+#[test]
+fn struct_() {
+	#[derive_where(Format; T)]
+	struct Test<T, U> {
+		a: T,
+		b: PhantomData<U>,
+	}
+
+	let expected = [istr(0), istr(1), vec![1], istr(2)].concat();
+	assert_eq!(
+		format(&Test::<u8, NotFormat> {
+			a: 1,
+			b: PhantomData,
+		}),
+		expected
+	);
+}
+
+#[test]
+fn tuple() {
+	#[derive_where(Format; T)]
+	struct Test<T, U>(T, PhantomData<U>);
+
+	let expected = [istr(0), istr(1), vec![1], istr(2)].concat();
+	assert_eq!(format(&Test::<u8, NotFormat>(1, PhantomData)), expected);
+}
+
+#[test]
+fn skip() {
+	#[derive_where(Format)]
+	struct Test<T> {
+		a: u8,
+		#[derive_where(skip(Format))]
+		b: PhantomData<T>,
+	}
+
+	let expected = [istr(0), istr(1), vec![1]].concat();
+	assert_eq!(
+		format(&Test::<NotFormat> {
+			a: 1,
+			b: PhantomData,
+		}),
+		expected
+	);
+}
+
+#[test]
+fn enum_() {
+	#[derive_where(Format; T)]
+	enum Test<T, U> {
+		A { a: T },
+		B(PhantomData<U>),
+		C,
+	}
+
+	let expected = [istr(0), istr(1), vec![1]].concat();
+	assert_eq!(format(&Test::<u8, NotFormat>::A { a: 1 }), expected);
+
+	let expected = [istr(0), istr(1)].concat();
+	assert_eq!(format(&Test::<u8, NotFormat>::B(PhantomData)), expected);
+
+	let expected = istr(0);
+	assert_eq!(format(&Test::<u8, NotFormat>::C), expected);
+}
+
+#[test]
+fn secret() {
+	#[derive_where(Format)]
+	#[derive_where(secret)]
+	struct Test<T> {
+		a: u8,
+		b: PhantomData<T>,
+	}
+
+	// Only the format string is written, not the fields.
+	let expected = istr(0);
+	assert_eq!(
+		format(&Test::<NotFormat> {
+			a: 1,
+			b: PhantomData,
+		}),
+		expected
+	);
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "schemars")]
+
+extern crate schemars_ as schemars;
+#[cfg(feature = "serde")]
+extern crate serde_ as serde;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+use serde_json::{json, Value};
+
+struct NotJsonSchema;
+
+/// Returns the `properties` of the schema of `T`.
+fn properties<T: schemars::JsonSchema>() -> Value {
+	serde_json::to_value(schemars::schema_for!(T)).unwrap()["properties"].clone()
+}
+
+#[test]
+fn struct_() {
+	#[derive_where(JsonSchema; T)]
+	struct Test<T, U> {
+		#[allow(dead_code)]
+		a: T,
+		b: PhantomData<U>,
+	}
+
+	let properties = properties::<Test<u8, NotJsonSchema>>();
+	assert_eq!(properties["a"]["type"], json!("integer"));
+	assert!(properties.get("b").is_some());
+}
+
+#[test]
+fn skip() {
+	#[derive_where(JsonSchema)]
+	struct Test<T> {
+		#[allow(dead_code)]
+		a: u8,
+		#[derive_where(skip(JsonSchema))]
+		b: PhantomData<T>,
+	}
+
+	let properties = properties::<Test<NotJsonSchema>>();
+	assert!(properties.get("a").is_some());
+	assert!(properties.get("b").is_none());
+}
+
+#[test]
+fn bound() {
+	#[derive_where(JsonSchema)]
+	#[schemars(bound = "T: schemars::JsonSchema")]
+	struct Test<T, U> {
+		#[allow(dead_code)]
+		a: T,
+		#[schemars(skip)]
+		b: PhantomData<U>,
+	}
+
+	let properties = properties::<Test<u8, NotJsonSchema>>();
+	assert!(properties.get("a").is_some());
+	assert!(properties.get("b").is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+	#[derive_where(Deserialize, Serialize, JsonSchema)]
+	#[serde(rename_all = "UPPERCASE")]
+	struct Test<T> {
+		a: u8,
+		#[derive_where(skip(Serde))]
+		b: PhantomData<T>,
+		#[derive_where(skip(Serialize))]
+		c: u8,
+	}
+
+	let properties = properties::<Test<NotJsonSchema>>();
+	assert!(properties.get("A").is_some());
+	assert!(properties.get("B").is_none());
+	assert_eq!(properties["C"]["writeOnly"], json!(true));
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "proptest")]
+
+extern crate proptest_ as proptest;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+use proptest::{
+	arbitrary::any,
+	strategy::{Strategy, ValueTree},
+	test_runner::TestRunner,
+};
+
+#[derive(Debug)]
+struct NotArbitrary;
+
+/// Generate `count` values with the
+/// [`Arbitrary`](proptest::arbitrary::Arbitrary) implementation of `T`.
+fn generate<T: proptest::arbitrary::Arbitrary>(count: usize) -> Vec<T> {
+	let mut runner = TestRunner::deterministic();
+	let strategy = any::<T>();
+
+	(0..count)
+		.map(|_| strategy.new_tree(&mut runner).unwrap().current())
+		.collect()
+}
+
+#[test]
+fn struct_() {
+	#[derive_where(Debug; T)]
+	#[derive_where(ProptestArbitrary; T)]
+	struct Test<T, U> {
+		a: T,
+		b: PhantomData<U>,
+		#[derive_where(ProptestArbitrary(strategy = 10..20_u8))]
+		c: u8,
+		#[derive_where(skip(ProptestArbitrary))]
+		d: u8,
+	}
+
+	let values = generate::<Test<bool, NotArbitrary>>(100);
+	assert!(values.iter().any(|test| test.a));
+	assert!(values.iter().any(|test| !test.a));
+
+	for test in values {
+		assert!((10..20).contains(&test.c));
+		assert_eq!(test.d, 0);
+	}
+}
+
+#[test]
+fn tuple() {
+	#[derive_where(Debug; T)]
+	#[derive_where(ProptestArbitrary; T)]
+	struct Test<T, U>(T, PhantomData<U>);
+
+	let values = generate::<Test<bool, NotArbitrary>>(100);
+	assert!(values.iter().any(|test| test.0));
+	assert!(values.iter().any(|test| !test.0));
+}
+
+#[test]
+fn skip_inner() {
+	#[derive_where(Debug, ProptestArbitrary)]
+	#[derive_where(skip_inner(ProptestArbitrary))]
+	struct Test<T>(u8, PhantomData<T>);
+
+	for test in generate::<Test<NotArbitrary>>(10) {
+		assert_eq!(test.0, 0);
+	}
+}
+
+#[test]
+fn skip_all() {
+	#[derive_where(Debug, ProptestArbitrary)]
+	struct Test<T>(#[derive_where(skip)] u8, PhantomData<T>);
+
+	assert!(generate::<Test<NotArbitrary>>(100)
+		.iter()
+		.any(|test| test.0 != 0));
+}
+
+#[test]
+fn enum_() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[derive_where(ProptestArbitrary; T)]
+	enum Test<T, U> {
+		A(T),
+		#[derive_where(ProptestArbitrary(weight = 10))]
+		B {
+			marker: PhantomData<U>,
+		},
+		C,
+	}
+
+	let values = generate::<Test<bool, NotArbitrary>>(1000);
+	let b = values
+		.iter()
+		.filter(|test| {
+			**test
+				== Test::B {
+					marker: PhantomData,
+				}
+		})
+		.count();
+
+	assert!(b > 700);
+	assert!(values.iter().any(|test| matches!(test, Test::A(_))));
+}
+
+#[test]
+fn enum_many() {
+	#[derive_where(Clone, Copy, Debug, PartialEq)]
+	#[derive_where(ProptestArbitrary)]
+	enum Test<T> {
+		A,
+		B,
+		C,
+		D,
+		E,
+		F,
+		G,
+		H,
+		I,
+		J,
+		K,
+		L(PhantomData<T>),
+	}
+
+	let values = generate::<Test<NotArbitrary>>(1000);
+
+	for variant in [Test::A, Test::J, Test::K, Test::L(PhantomData)] {
+		assert!(values.contains(&variant));
+	}
+}
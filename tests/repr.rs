@@ -1,7 +1,7 @@
 #[macro_use]
 mod util;
 
-use std::cmp::Ordering;
+use std::{cmp::Ordering, marker::PhantomData};
 
 use derive_where::derive_where;
 
@@ -178,3 +178,20 @@ fn repr_with_repr_c_with_values() {
 	assert!(test_c_1 > test_a_1);
 	assert!(test_c_1 > test_b_1);
 }
+
+#[test]
+fn repr_with_align() {
+	#[derive_where(PartialEq, PartialOrd; T)]
+	#[repr(u8, align(4))]
+	enum Test<T, U> {
+		A(T, PhantomData<U>),
+		B,
+	}
+
+	let test_a = Test::<_, ()>::A(42, PhantomData);
+	let test_b = Test::B;
+
+	assert!(test_a == Test::A(42, PhantomData));
+	assert!(test_a != test_b);
+	assert!(test_a < test_b);
+}
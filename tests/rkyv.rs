@@ -0,0 +1,125 @@
+#![cfg(feature = "rkyv")]
+
+extern crate rkyv_ as rkyv;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+use rkyv::{from_bytes, rancor::Error, to_bytes};
+
+struct NotArchive;
+
+#[test]
+fn struct_() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[derive_where(Archive, RkyvDeserialize, RkyvSerialize; T)]
+	struct Test<T, U> {
+		a: T,
+		b: PhantomData<U>,
+		c: u8,
+	}
+
+	let test = Test::<u16, NotArchive> {
+		a: 1,
+		b: PhantomData,
+		c: 2,
+	};
+	let bytes = to_bytes::<Error>(&test).unwrap();
+	let archived = rkyv::access::<ArchivedTest<u16, NotArchive>, Error>(&bytes).unwrap();
+
+	assert_eq!(archived.a, 1);
+	assert_eq!(archived.c, 2);
+	assert_eq!(
+		from_bytes::<Test<u16, NotArchive>, Error>(&bytes).unwrap(),
+		test
+	);
+}
+
+#[test]
+fn enum_() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[derive_where(Archive, RkyvDeserialize, RkyvSerialize; T)]
+	enum Test<T, U> {
+		A { a: T },
+		B(u8, PhantomData<U>),
+		C,
+	}
+
+	for test in [
+		Test::<u16, NotArchive>::A { a: 1 },
+		Test::B(2, PhantomData),
+		Test::C,
+	] {
+		let bytes = to_bytes::<Error>(&test).unwrap();
+		assert_eq!(
+			from_bytes::<Test<u16, NotArchive>, Error>(&bytes).unwrap(),
+			test
+		);
+	}
+}
+
+#[test]
+fn skip() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[derive_where(Archive, RkyvDeserialize, RkyvSerialize; T)]
+	struct Test<T, U> {
+		a: T,
+		#[derive_where(skip(Archive))]
+		b: Vec<u8>,
+		c: PhantomData<U>,
+	}
+
+	let test = Test::<u16, NotArchive> {
+		a: 1,
+		b: vec![1, 2, 3],
+		c: PhantomData,
+	};
+	let bytes = to_bytes::<Error>(&test).unwrap();
+
+	assert_eq!(
+		from_bytes::<Test<u16, NotArchive>, Error>(&bytes).unwrap(),
+		Test {
+			a: 1,
+			b: Vec::new(),
+			c: PhantomData,
+		}
+	);
+}
+
+#[test]
+fn skip_all() {
+	#[derive_where(Debug; T)]
+	#[derive_where(Archive, RkyvDeserialize, RkyvSerialize; T)]
+	struct Test<T, U> {
+		a: T,
+		#[derive_where(skip)]
+		b: u32,
+		c: PhantomData<U>,
+	}
+
+	let test = Test::<u16, NotArchive> {
+		a: 1,
+		b: 2,
+		c: PhantomData,
+	};
+	let bytes = to_bytes::<Error>(&test).unwrap();
+	let test = from_bytes::<Test<u16, NotArchive>, Error>(&bytes).unwrap();
+
+	assert_eq!(test.a, 1);
+	assert_eq!(test.b, 2);
+}
+
+#[test]
+fn crate_() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[derive_where(Archive(crate = rkyv), RkyvDeserialize(crate = rkyv), RkyvSerialize(crate = rkyv); T)]
+	struct Test<T, U>(T, PhantomData<U>);
+
+	let test = Test::<u16, NotArchive>(1, PhantomData);
+	let bytes = to_bytes::<Error>(&test).unwrap();
+
+	assert_eq!(
+		from_bytes::<Test<u16, NotArchive>, Error>(&bytes).unwrap(),
+		test
+	);
+}
@@ -0,0 +1,79 @@
+#![allow(unused)]
+
+#[cfg(feature = "serde")]
+extern crate serde_ as serde;
+#[cfg(feature = "subtle")]
+extern crate subtle_ as subtle;
+
+use derive_where::derive_where;
+
+#[test]
+fn debug() {
+	#[derive_where(Debug)]
+	#[derive_where(secret)]
+	struct Test {
+		a: u8,
+		#[derive_where(skip(Debug))]
+		b: u8,
+	}
+
+	assert_eq!(
+		format!("{:?}", Test { a: 42, b: 42 }),
+		"Test { a: [REDACTED], .. }"
+	);
+}
+
+#[test]
+fn debug_enum() {
+	#[derive_where(Debug)]
+	#[derive_where(secret)]
+	enum Test {
+		A(u8),
+		B { a: u8 },
+		C,
+	}
+
+	assert_eq!(format!("{:?}", Test::A(42)), "A([REDACTED])");
+	assert_eq!(format!("{:?}", Test::B { a: 42 }), "B { a: [REDACTED] }");
+	assert_eq!(format!("{:?}", Test::C), "C");
+}
+
+#[test]
+#[cfg(feature = "subtle")]
+fn partial_eq() {
+	#[derive_where(PartialEq; T)]
+	#[derive_where(secret)]
+	struct Test<T>(T, u8);
+
+	assert!(Test(1_u32, 2) == Test(1, 2));
+	assert!(Test(1_u32, 2) != Test(0, 2));
+	assert!(Test(1_u32, 2) != Test(1, 0));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serialize() {
+	use serde_test::Token;
+
+	#[derive_where(Serialize)]
+	#[derive_where(secret)]
+	struct Test {
+		#[derive_where(Serialize(expose))]
+		a: u8,
+		#[derive_where(skip(Serialize))]
+		b: u8,
+	}
+
+	serde_test::assert_ser_tokens(
+		&Test { a: 42, b: 42 },
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 1,
+			},
+			Token::Str("a"),
+			Token::U8(42),
+			Token::StructEnd,
+		],
+	);
+}
@@ -70,3 +70,728 @@ fn attribute() {
 		],
 	);
 }
+
+#[test]
+fn bound() {
+	#[derive(Debug, PartialEq)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize; T))]
+	#[cfg_attr(feature = "serde", serde(bound(deserialize = "U: Default")))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(
+		not(feature = "serde"),
+		serde(bound(
+			deserialize = "T: Deserialize<'de>, U: Default",
+			serialize = "T: Serialize"
+		))
+	)]
+	#[serde(crate = "serde_")]
+	struct Test<T, U>(T, #[serde(skip)] U);
+
+	let test = Test(42, 0);
+
+	serde_test::assert_tokens(
+		&test,
+		&[
+			Token::TupleStruct {
+				name: "Test",
+				len: 1,
+			},
+			Token::I32(42),
+			Token::TupleStructEnd,
+		],
+	);
+}
+
+#[test]
+fn bound_only() {
+	#[derive(Debug, PartialEq)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[serde(bound(
+		deserialize = "T: serde_::Deserialize<'de>",
+		serialize = "T: serde_::Serialize"
+	))]
+	#[serde(crate = "serde_", rename_all = "UPPERCASE")]
+	struct Test<T> {
+		field: T,
+	}
+
+	let test = Test { field: 42 };
+
+	serde_test::assert_tokens(
+		&test,
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 1,
+			},
+			Token::Str("FIELD"),
+			Token::I32(42),
+			Token::StructEnd,
+		],
+	);
+}
+
+#[test]
+fn rename() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize; T))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(
+		not(feature = "serde"),
+		serde(bound(deserialize = "T: Deserialize<'de>", serialize = "T: Serialize"))
+	)]
+	#[serde(crate = "serde_")]
+	#[serde(rename = "Renamed", rename_all = "camelCase")]
+	struct Test<T, U> {
+		field_a: T,
+		#[serde(rename = "b")]
+		field_b: i32,
+		#[serde(skip)]
+		marker: PhantomData<U>,
+	}
+
+	let test = Test {
+		field_a: 42,
+		field_b: 1,
+		marker: PhantomData::<i32>,
+	};
+
+	serde_test::assert_tokens(
+		&test,
+		&[
+			Token::Struct {
+				name: "Renamed",
+				len: 2,
+			},
+			Token::Str("fieldA"),
+			Token::I32(42),
+			Token::Str("b"),
+			Token::I32(1),
+			Token::StructEnd,
+		],
+	);
+}
+
+#[test]
+fn enum_() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize; T))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(
+		not(feature = "serde"),
+		serde(bound(deserialize = "T: Deserialize<'de>", serialize = "T: Serialize"))
+	)]
+	#[serde(crate = "serde_")]
+	#[serde(rename_all = "snake_case")]
+	enum Test<T, U> {
+		UnitVariant,
+		Newtype(T),
+		Tuple(T, i32),
+		#[serde(rename = "custom", rename_all = "UPPERCASE")]
+		Struct {
+			field: T,
+			#[serde(skip)]
+			marker: PhantomData<U>,
+		},
+	}
+
+	serde_test::assert_tokens(
+		&Test::<i32, i32>::UnitVariant,
+		&[Token::UnitVariant {
+			name: "Test",
+			variant: "unit_variant",
+		}],
+	);
+	serde_test::assert_tokens(
+		&Test::<i32, i32>::Newtype(42),
+		&[
+			Token::NewtypeVariant {
+				name: "Test",
+				variant: "newtype",
+			},
+			Token::I32(42),
+		],
+	);
+	serde_test::assert_tokens(
+		&Test::<i32, i32>::Tuple(42, 1),
+		&[
+			Token::TupleVariant {
+				name: "Test",
+				variant: "tuple",
+				len: 2,
+			},
+			Token::I32(42),
+			Token::I32(1),
+			Token::TupleVariantEnd,
+		],
+	);
+	serde_test::assert_tokens(
+		&Test::<i32, i32>::Struct {
+			field: 42,
+			marker: PhantomData,
+		},
+		&[
+			Token::StructVariant {
+				name: "Test",
+				variant: "custom",
+				len: 1,
+			},
+			Token::Str("FIELD"),
+			Token::I32(42),
+			Token::StructVariantEnd,
+		],
+	);
+}
+
+#[test]
+fn skip_variant() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize; T))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(
+		not(feature = "serde"),
+		serde(bound(deserialize = "T: Deserialize<'de>", serialize = "T: Serialize"))
+	)]
+	#[serde(crate = "serde_")]
+	enum Test<T, U> {
+		A(T),
+		#[serde(skip)]
+		B(PhantomData<U>),
+	}
+
+	serde_test::assert_ser_tokens_error(
+		&Test::<i32, i32>::B(PhantomData),
+		&[],
+		"the enum variant Test::B cannot be serialized",
+	);
+	serde_test::assert_de_tokens_error::<Test<i32, i32>>(
+		&[Token::UnitVariant {
+			name: "Test",
+			variant: "B",
+		}],
+		"unknown variant `B`, expected `A`",
+	);
+}
+
+#[test]
+fn default() {
+	fn forty_two() -> i32 {
+		42
+	}
+
+	#[derive_where(Debug, PartialEq; T)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize; T))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(
+		not(feature = "serde"),
+		serde(bound(deserialize = "T: Deserialize<'de>", serialize = "T: Serialize"))
+	)]
+	#[serde(crate = "serde_")]
+	struct Test<T, U> {
+		a: T,
+		#[serde(default = "forty_two")]
+		b: i32,
+		#[serde(default)]
+		c: i32,
+		d: Option<i32>,
+		#[serde(skip)]
+		marker: PhantomData<U>,
+	}
+
+	serde_test::assert_de_tokens(
+		&Test {
+			a: 1,
+			b: 42,
+			c: 0,
+			d: None,
+			marker: PhantomData::<i32>,
+		},
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 1,
+			},
+			Token::Str("a"),
+			Token::I32(1),
+			Token::StructEnd,
+		],
+	);
+	serde_test::assert_de_tokens_error::<Test<i32, i32>>(
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 0,
+			},
+			Token::StructEnd,
+		],
+		"missing field `a`",
+	);
+}
+
+#[test]
+fn default_container() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize; T))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(
+		not(feature = "serde"),
+		serde(bound(deserialize = "T: Deserialize<'de>", serialize = "T: Serialize"))
+	)]
+	#[serde(crate = "serde_")]
+	#[serde(default, deny_unknown_fields)]
+	struct Test<T, U> {
+		a: Option<T>,
+		b: i32,
+		#[serde(skip)]
+		marker: PhantomData<U>,
+	}
+
+	impl<T, U> Default for Test<T, U> {
+		fn default() -> Self {
+			Self {
+				a: None,
+				b: 42,
+				marker: PhantomData,
+			}
+		}
+	}
+
+	serde_test::assert_de_tokens(
+		&Test {
+			a: Some(1),
+			b: 42,
+			marker: PhantomData::<i32>,
+		},
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 1,
+			},
+			Token::Str("a"),
+			Token::Some,
+			Token::I32(1),
+			Token::StructEnd,
+		],
+	);
+	serde_test::assert_de_tokens_error::<Test<i32, i32>>(
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 1,
+			},
+			Token::Str("c"),
+		],
+		"unknown field `c`, expected `a` or `b`",
+	);
+}
+
+#[test]
+fn tag() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize; T))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(
+		not(feature = "serde"),
+		serde(bound(deserialize = "T: Deserialize<'de>", serialize = "T: Serialize"))
+	)]
+	#[serde(crate = "serde_")]
+	#[serde(tag = "type")]
+	enum Test<T, U> {
+		A,
+		B {
+			field: T,
+			#[serde(skip)]
+			marker: PhantomData<U>,
+		},
+	}
+
+	serde_test::assert_tokens(
+		&Test::<i32, i32>::A,
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 1,
+			},
+			Token::Str("type"),
+			Token::Str("A"),
+			Token::StructEnd,
+		],
+	);
+	serde_test::assert_tokens(
+		&Test::<i32, i32>::B {
+			field: 42,
+			marker: PhantomData,
+		},
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 2,
+			},
+			Token::Str("type"),
+			Token::Str("B"),
+			Token::Str("field"),
+			Token::I32(42),
+			Token::StructEnd,
+		],
+	);
+}
+
+#[test]
+fn untagged() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize; T))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(
+		not(feature = "serde"),
+		serde(bound(deserialize = "T: Deserialize<'de>", serialize = "T: Serialize"))
+	)]
+	#[serde(crate = "serde_")]
+	#[serde(untagged)]
+	enum Test<T, U> {
+		A,
+		B(T),
+		C(T, T),
+		D {
+			field: T,
+			#[serde(skip)]
+			marker: PhantomData<U>,
+		},
+	}
+
+	serde_test::assert_ser_tokens(&Test::<i32, i32>::A, &[Token::Unit]);
+	serde_test::assert_ser_tokens(&Test::<i32, i32>::B(42), &[Token::I32(42)]);
+	serde_test::assert_ser_tokens(
+		&Test::<i32, i32>::C(42, 1),
+		&[
+			Token::Tuple { len: 2 },
+			Token::I32(42),
+			Token::I32(1),
+			Token::TupleEnd,
+		],
+	);
+	serde_test::assert_ser_tokens(
+		&Test::<i32, i32>::D {
+			field: 42,
+			marker: PhantomData,
+		},
+		&[
+			Token::Struct {
+				// `serde` uses the name of the type instead.
+				name: if cfg!(feature = "serde") { "D" } else { "Test" },
+				len: 1,
+			},
+			Token::Str("field"),
+			Token::I32(42),
+			Token::StructEnd,
+		],
+	);
+}
+
+#[test]
+fn skip() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize; T))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(
+		not(feature = "serde"),
+		serde(bound(deserialize = "T: Deserialize<'de>", serialize = "T: Serialize"))
+	)]
+	#[serde(crate = "serde_")]
+	struct Test<T, U> {
+		a: T,
+		#[cfg_attr(feature = "serde", derive_where(skip(Serialize)))]
+		#[cfg_attr(not(feature = "serde"), serde(skip_serializing))]
+		b: i32,
+		#[cfg_attr(feature = "serde", derive_where(skip(Deserialize)))]
+		#[cfg_attr(not(feature = "serde"), serde(skip_deserializing))]
+		c: i32,
+		#[cfg_attr(feature = "serde", derive_where(skip(Serde)))]
+		#[cfg_attr(not(feature = "serde"), serde(skip))]
+		d: i32,
+		#[cfg_attr(feature = "serde", derive_where(skip(Serde)))]
+		#[cfg_attr(not(feature = "serde"), serde(skip))]
+		marker: PhantomData<U>,
+	}
+
+	serde_test::assert_ser_tokens(
+		&Test {
+			a: 1,
+			b: 2,
+			c: 3,
+			d: 4,
+			marker: PhantomData::<i32>,
+		},
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 2,
+			},
+			Token::Str("a"),
+			Token::I32(1),
+			Token::Str("c"),
+			Token::I32(3),
+			Token::StructEnd,
+		],
+	);
+	serde_test::assert_de_tokens(
+		&Test {
+			a: 1,
+			b: 2,
+			c: 0,
+			d: 0,
+			marker: PhantomData::<i32>,
+		},
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 2,
+			},
+			Token::Str("a"),
+			Token::I32(1),
+			Token::Str("b"),
+			Token::I32(2),
+			Token::StructEnd,
+		],
+	);
+}
+
+#[test]
+fn skip_all() {
+	#[derive_where(Debug, PartialEq)]
+	#[cfg_attr(feature = "serde", derive_where(Serialize))]
+	#[cfg_attr(not(feature = "serde"), derive(Serialize))]
+	#[serde(crate = "serde_")]
+	struct Test<T> {
+		// Unconstrained `skip` doesn't skip `Serde`.
+		#[derive_where(skip)]
+		a: i32,
+		#[serde(skip)]
+		marker: PhantomData<T>,
+	}
+
+	assert_eq!(
+		Test {
+			a: 1,
+			marker: PhantomData::<i32>,
+		},
+		Test {
+			a: 2,
+			marker: PhantomData,
+		}
+	);
+	serde_test::assert_ser_tokens(
+		&Test {
+			a: 1,
+			marker: PhantomData::<i32>,
+		},
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 1,
+			},
+			Token::Str("a"),
+			Token::I32(1),
+			Token::StructEnd,
+		],
+	);
+}
+
+#[test]
+fn skip_inner() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize; T))]
+	#[cfg_attr(feature = "serde", derive_where(skip_inner(Serialize)))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(
+		not(feature = "serde"),
+		serde(bound(deserialize = "T: Deserialize<'de>", serialize = ""))
+	)]
+	#[serde(crate = "serde_")]
+	struct Test<T, U> {
+		#[cfg_attr(not(feature = "serde"), serde(skip_serializing))]
+		a: T,
+		#[cfg_attr(feature = "serde", derive_where(skip(Deserialize)))]
+		#[cfg_attr(not(feature = "serde"), serde(skip))]
+		marker: PhantomData<U>,
+	}
+
+	serde_test::assert_ser_tokens(
+		&Test {
+			a: 1,
+			marker: PhantomData::<i32>,
+		},
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 0,
+			},
+			Token::StructEnd,
+		],
+	);
+}
+
+#[test]
+fn skip_inner_all() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[derive_where(skip_inner)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize, Serialize; T))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(
+		not(feature = "serde"),
+		serde(bound(deserialize = "T: Deserialize<'de>", serialize = "T: Serialize"))
+	)]
+	#[serde(crate = "serde_")]
+	struct Test<T, U> {
+		a: T,
+		#[cfg_attr(feature = "serde", derive_where(skip(Serde)))]
+		#[cfg_attr(not(feature = "serde"), serde(skip))]
+		marker: PhantomData<U>,
+	}
+
+	serde_test::assert_tokens(
+		&Test {
+			a: 1,
+			marker: PhantomData::<i32>,
+		},
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 1,
+			},
+			Token::Str("a"),
+			Token::I32(1),
+			Token::StructEnd,
+		],
+	);
+}
+
+#[test]
+fn crate_option() {
+	#[derive_where(Debug, PartialEq)]
+	#[cfg_attr(
+		feature = "serde",
+		derive_where(Deserialize(crate = serde_), Serialize(crate = "serde_"))
+	)]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(not(feature = "serde"), serde(crate = "serde_", bound = ""))]
+	struct Test<T> {
+		marker: PhantomData<T>,
+	}
+
+	serde_test::assert_tokens(
+		&Test {
+			marker: PhantomData::<i32>,
+		},
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 1,
+			},
+			Token::Str("marker"),
+			Token::UnitStruct {
+				name: "PhantomData",
+			},
+			Token::StructEnd,
+		],
+	);
+}
+
+#[test]
+fn crate_option_forward() {
+	#[derive_where(Debug, PartialEq)]
+	#[cfg_attr(
+		feature = "serde",
+		derive_where(Deserialize(crate = serde_), Serialize(crate = serde_))
+	)]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize, Serialize))]
+	#[cfg_attr(not(feature = "serde"), serde(crate = "serde_", bound = ""))]
+	#[serde(transparent)]
+	struct Test<T>(Wrapper<T>);
+
+	serde_test::assert_tokens(
+		&Test(42.into()),
+		&[
+			Token::Struct {
+				name: "Wrapper",
+				len: 2,
+			},
+			Token::Str("data"),
+			Token::I32(42),
+			Token::Str("hack"),
+			Token::UnitStruct {
+				name: "PhantomData",
+			},
+			Token::StructEnd,
+		],
+	);
+}
+
+#[test]
+fn de_lifetime() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[cfg_attr(
+		feature = "serde",
+		derive_where(Deserialize; T: serde_::Deserialize<'de>)
+	)]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize))]
+	#[cfg_attr(
+		not(feature = "serde"),
+		serde(bound(deserialize = "T: Deserialize<'de>"))
+	)]
+	#[serde(crate = "serde_")]
+	struct Test<T, U> {
+		a: T,
+		#[serde(skip)]
+		marker: PhantomData<U>,
+	}
+
+	serde_test::assert_de_tokens(
+		&Test {
+			a: 42,
+			marker: PhantomData::<i32>,
+		},
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 1,
+			},
+			Token::Str("a"),
+			Token::I32(42),
+			Token::StructEnd,
+		],
+	);
+}
+
+#[test]
+fn borrow() {
+	#[derive_where(Debug, PartialEq; T)]
+	#[cfg_attr(feature = "serde", derive_where(Deserialize; T))]
+	#[cfg_attr(not(feature = "serde"), derive(Deserialize))]
+	#[serde(crate = "serde_")]
+	struct Test<'a, T, U> {
+		a: &'a str,
+		b: T,
+		#[serde(skip)]
+		marker: PhantomData<U>,
+	}
+
+	serde_test::assert_de_tokens(
+		&Test {
+			a: "test",
+			b: 42,
+			marker: PhantomData::<i32>,
+		},
+		&[
+			Token::Struct {
+				name: "Test",
+				len: 2,
+			},
+			Token::Str("a"),
+			Token::BorrowedStr("test"),
+			Token::Str("b"),
+			Token::I32(42),
+			Token::StructEnd,
+		],
+	);
+}
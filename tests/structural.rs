@@ -0,0 +1,36 @@
+#![cfg(feature = "nightly")]
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[test]
+fn struct_() {
+	#[derive_where(Debug, Eq, PartialEq; T)]
+	struct Test<T, U>(T, PhantomData<U>);
+
+	const TEST: Test<u8, ()> = Test(42, PhantomData);
+
+	match Test(42, PhantomData) {
+		TEST => (),
+		test => panic!("unexpected value: {:?}", test),
+	}
+}
+
+#[test]
+fn enum_() {
+	#[derive_where(Debug, Eq, PartialEq; T)]
+	enum Test<T, U> {
+		A(T),
+		B(PhantomData<U>),
+	}
+
+	const A: Test<u8, ()> = Test::A(42);
+	const B: Test<u8, ()> = Test::B(PhantomData);
+
+	match Test::B(PhantomData) {
+		A => panic!("unexpected value: {:?}", A),
+		B => (),
+		test => panic!("unexpected value: {:?}", test),
+	}
+}
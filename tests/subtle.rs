@@ -0,0 +1,138 @@
+#![cfg(feature = "subtle")]
+
+extern crate subtle_ as subtle;
+
+use std::{marker::PhantomData, ops::Neg};
+
+use derive_where::derive_where;
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+
+struct NotConstantTimeEq;
+
+#[test]
+fn struct_() {
+	#[derive_where(ConstantTimeEq; T)]
+	struct Test<T, U> {
+		a: T,
+		#[derive_where(skip(ConstantTimeEq))]
+		b: PhantomData<U>,
+		c: u8,
+	}
+
+	let test = Test::<u16, NotConstantTimeEq> {
+		a: 1,
+		b: PhantomData,
+		c: 2,
+	};
+
+	assert!(bool::from(test.ct_eq(&Test {
+		a: 1,
+		b: PhantomData,
+		c: 2,
+	})));
+	assert!(!bool::from(test.ct_eq(&Test {
+		a: 0,
+		b: PhantomData,
+		c: 2,
+	})));
+	assert!(!bool::from(test.ct_eq(&Test {
+		a: 1,
+		b: PhantomData,
+		c: 0,
+	})));
+}
+
+#[test]
+fn skip() {
+	#[derive_where(ConstantTimeEq)]
+	struct Test {
+		a: u8,
+		#[derive_where(skip(ConstantTimeEq))]
+		b: u8,
+	}
+
+	assert!(bool::from(Test { a: 1, b: 2 }.ct_eq(&Test { a: 1, b: 3 })));
+	assert!(!bool::from(Test { a: 1, b: 2 }.ct_eq(&Test { a: 0, b: 2 })));
+}
+
+#[test]
+fn enum_() {
+	#[derive_where(ConstantTimeEq; T)]
+	enum Test<T, U> {
+		A { a: T },
+		B(u8, #[derive_where(skip(ConstantTimeEq))] PhantomData<U>),
+		C,
+	}
+
+	let a = Test::<u16, NotConstantTimeEq>::A { a: 1 };
+	let b = Test::<u16, NotConstantTimeEq>::B(1, PhantomData);
+	let c = Test::<u16, NotConstantTimeEq>::C;
+
+	assert!(bool::from(a.ct_eq(&Test::A { a: 1 })));
+	assert!(!bool::from(a.ct_eq(&Test::A { a: 0 })));
+	assert!(bool::from(b.ct_eq(&Test::B(1, PhantomData))));
+	assert!(!bool::from(b.ct_eq(&Test::B(0, PhantomData))));
+	assert!(bool::from(c.ct_eq(&Test::C)));
+	assert!(!bool::from(a.ct_eq(&b)));
+	assert!(!bool::from(b.ct_eq(&c)));
+}
+
+#[test]
+fn crate_() {
+	#[derive_where(ConstantTimeEq(crate = subtle_))]
+	struct Test(u8);
+
+	#[derive_where(ConstantTimeEq(crate = "subtle_"))]
+	struct TestString(u8);
+
+	assert!(bool::from(Test(1).ct_eq(&Test(1))));
+	assert!(bool::from(TestString(1).ct_eq(&TestString(1))));
+}
+
+#[test]
+fn conditionally_selectable() {
+	#[derive_where(ConditionallySelectable; T)]
+	#[derive(Clone, Copy, Debug, PartialEq)]
+	struct Test<T> {
+		a: T,
+		b: u8,
+	}
+
+	let a = Test { a: 1_u16, b: 2 };
+	let b = Test { a: 3, b: 4 };
+
+	assert_eq!(Test::conditional_select(&a, &b, Choice::from(0)), a);
+	assert_eq!(Test::conditional_select(&a, &b, Choice::from(1)), b);
+}
+
+#[test]
+fn conditionally_selectable_tuple() {
+	#[derive_where(ConditionallySelectable(crate = subtle_))]
+	#[derive(Clone, Copy, Debug, PartialEq)]
+	struct Test(u8, u32);
+
+	let mut test = Test(1, 2);
+	test.conditional_assign(&Test(3, 4), Choice::from(1));
+	assert_eq!(test, Test(3, 4));
+}
+
+#[test]
+fn conditionally_negatable() {
+	#[derive_where(ConditionallySelectable)]
+	#[derive(Clone, Copy, Debug, PartialEq)]
+	struct Test(i32);
+
+	impl Neg for &Test {
+		type Output = Test;
+
+		fn neg(self) -> Self::Output {
+			Test(-self.0)
+		}
+	}
+
+	let mut test = Test(1);
+	test.conditional_negate(Choice::from(0));
+	assert_eq!(test, Test(1));
+	test.conditional_negate(Choice::from(1));
+	assert_eq!(test, Test(-1));
+}
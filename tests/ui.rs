@@ -6,10 +6,46 @@ fn ui() {
 	use trybuild::TestCases;
 
 	TestCases::new().compile_fail("tests/ui/*.rs");
+	#[cfg(not(feature = "arbitrary"))]
+	TestCases::new().compile_fail("tests/ui/not-arbitrary/*.rs");
+	#[cfg(not(feature = "borsh"))]
+	TestCases::new().compile_fail("tests/ui/not-borsh/*.rs");
+	#[cfg(feature = "bytemuck")]
+	TestCases::new().compile_fail("tests/ui/bytemuck/*.rs");
+	#[cfg(not(feature = "bytemuck"))]
+	TestCases::new().compile_fail("tests/ui/not-bytemuck/*.rs");
+	#[cfg(not(feature = "defmt"))]
+	TestCases::new().compile_fail("tests/ui/not-defmt/*.rs");
+	#[cfg(feature = "proptest")]
+	TestCases::new().compile_fail("tests/ui/proptest/*.rs");
+	#[cfg(not(feature = "proptest"))]
+	TestCases::new().compile_fail("tests/ui/not-proptest/*.rs");
+	#[cfg(feature = "rkyv")]
+	TestCases::new().compile_fail("tests/ui/rkyv/*.rs");
+	#[cfg(not(feature = "rkyv"))]
+	TestCases::new().compile_fail("tests/ui/not-rkyv/*.rs");
+	#[cfg(feature = "safe")]
+	TestCases::new().compile_fail("tests/ui/safe/*.rs");
+	#[cfg(all(feature = "safe", feature = "zeroize"))]
+	TestCases::new().compile_fail("tests/ui/safe-zeroize/*.rs");
+	#[cfg(not(feature = "safe"))]
+	TestCases::new().compile_fail("tests/ui/not-safe/*.rs");
+	#[cfg(feature = "schemars")]
+	TestCases::new().compile_fail("tests/ui/schemars/*.rs");
+	#[cfg(not(feature = "schemars"))]
+	TestCases::new().compile_fail("tests/ui/not-schemars/*.rs");
 	#[cfg(feature = "serde")]
 	TestCases::new().compile_fail("tests/ui/serde/*.rs");
 	#[cfg(not(feature = "serde"))]
 	TestCases::new().compile_fail("tests/ui/not-serde/*.rs");
+	#[cfg(feature = "subtle")]
+	TestCases::new().compile_fail("tests/ui/subtle/*.rs");
+	#[cfg(not(feature = "subtle"))]
+	TestCases::new().compile_fail("tests/ui/not-subtle/*.rs");
+	#[cfg(feature = "zerocopy")]
+	TestCases::new().compile_fail("tests/ui/zerocopy/*.rs");
+	#[cfg(not(feature = "zerocopy"))]
+	TestCases::new().compile_fail("tests/ui/not-zerocopy/*.rs");
 	#[cfg(not(feature = "zeroize"))]
 	TestCases::new().compile_fail("tests/ui/not-zeroize/*.rs");
 	#[cfg(feature = "zeroize")]
@@ -0,0 +1,26 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use derive_where::derive_where;
+
+#[derive_where(assert(Send))]
+struct NotSend<T>(Rc<T>);
+
+#[derive_where(assert(Sync); T: Send)]
+struct NotSync<T>(PhantomData<T>);
+
+#[derive_where(assert('static))]
+struct NotStatic<'a, T>(&'a T);
+
+#[derive_where(assert)]
+struct MissingBounds<T>(PhantomData<T>);
+
+#[derive_where(assert())]
+struct EmptyBounds<T>(PhantomData<T>);
+
+#[derive_where(assert(Send Sync))]
+struct InvalidBounds<T>(PhantomData<T>);
+
+#[derive_where(assert = Send)]
+struct WrongSyntax<T>(PhantomData<T>);
+
+fn main() {}
@@ -0,0 +1,32 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Zeroable(crate = "bytemuck_"))]
+struct Options<T>(PhantomData<T>);
+
+#[derive_where(Pod)]
+#[repr(u8)]
+enum Enum<T> {
+	A(PhantomData<T>),
+}
+
+#[derive_where(Pod)]
+struct NoRepr<T>(PhantomData<T>);
+
+#[derive_where(Pod; T)]
+#[repr(C)]
+struct Generic<T>(T);
+
+#[derive_where(Pod)]
+#[repr(C, packed(2))]
+struct Packed2<T> {
+	a: u8,
+	b: u16,
+	c: PhantomData<T>,
+}
+
+#[derive_where(Zeroable)]
+struct Skip<T>(#[derive_where(skip)] PhantomData<T>);
+
+fn main() {}
@@ -0,0 +1,15 @@
+extern crate bytemuck_ as bytemuck;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+struct NotZeroable;
+
+#[derive_where(Zeroable)]
+struct Test<T> {
+	a: NotZeroable,
+	b: PhantomData<T>,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+extern crate bytemuck_ as bytemuck;
+
+use derive_where::derive_where;
+
+#[derive_where(Zeroable, Pod)]
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct Padding {
+	a: u8,
+	b: u16,
+}
+
+#[derive_where(Zeroable, Pod)]
+#[derive(Clone, Copy)]
+#[repr(C, packed(2))]
+struct Packed2 {
+	a: u8,
+	b: u16,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Clone(const, const))]
+struct DuplicateConst<T>(PhantomData<T>);
+
+#[derive_where(Clone, Clone(const))]
+struct DuplicateConstTrait<T>(PhantomData<T>);
+
+#[derive_where(Default(test))]
+struct UnknownOption<T>(PhantomData<T>);
+
+#[derive_where(PartialEq(const = true))]
+struct WrongSyntax<T>(PhantomData<T>);
+
+fn main() {}
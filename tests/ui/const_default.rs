@@ -0,0 +1,30 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Clone)]
+struct NotConstDefault<T>(#[derive_where(ConstDefault(value = PhantomData))] PhantomData<T>);
+
+#[derive_where(ConstDefault)]
+struct MissingValue<T>(#[derive_where(ConstDefault)] PhantomData<T>);
+
+#[derive_where(ConstDefault)]
+struct DuplicateValue<T>(
+	#[derive_where(ConstDefault(value = PhantomData, value = PhantomData))] PhantomData<T>,
+);
+
+#[derive_where(ConstDefault)]
+struct UnknownOption<T>(#[derive_where(ConstDefault(test = PhantomData))] PhantomData<T>);
+
+#[derive_where(ConstDefault)]
+struct WrongSyntax<T>(#[derive_where(ConstDefault(value))] PhantomData<T>);
+
+#[derive_where(ConstDefault)]
+enum MissingDefault<T> {
+	A(PhantomData<T>),
+}
+
+#[derive_where(ConstDefault; T)]
+struct NoBound<T>(#[derive_where(ConstDefault(value = PhantomData))] PhantomData<T>);
+
+fn main() {}
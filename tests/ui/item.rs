@@ -65,6 +65,9 @@ struct DoubleColonAtTheEnd<T, U>(T, PhantomData<U>);
 #[derive_where(Clone; where)]
 struct InvalidGeneric<T>(PhantomData<T>);
 
+#[derive_where(Clone; T: Trait<'a>)]
+struct UndeclaredLifetime<T>(PhantomData<T>);
+
 #[derive_where(Clone Debug)]
 struct MissingCommaBetweenTraits<T>(PhantomData<T>);
 
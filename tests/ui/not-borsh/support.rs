@@ -0,0 +1,11 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(BorshDeserialize)]
+struct UnsupportedDeserialize<T>(PhantomData<T>);
+
+#[derive_where(BorshSerialize)]
+struct UnsupportedSerialize<T>(PhantomData<T>);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Pod)]
+struct Pod<T>(PhantomData<T>);
+
+#[derive_where(Zeroable)]
+struct Zeroable<T>(PhantomData<T>);
+
+fn main() {}
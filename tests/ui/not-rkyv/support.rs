@@ -0,0 +1,14 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Archive)]
+struct Archive<T>(PhantomData<T>);
+
+#[derive_where(RkyvDeserialize)]
+struct RkyvDeserialize<T>(PhantomData<T>);
+
+#[derive_where(RkyvSerialize)]
+struct RkyvSerialize<T>(PhantomData<T>);
+
+fn main() {}
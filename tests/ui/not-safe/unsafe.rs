@@ -0,0 +1,23 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Send)]
+struct MissingUnsafe<T>(PhantomData<T>);
+
+#[derive_where(unsafe(Clone))]
+struct SafeTrait<T>(PhantomData<T>);
+
+#[derive_where(unsafe)]
+struct NoList<T>(PhantomData<T>);
+
+#[derive_where(unsafe = Send)]
+struct WrongSyntax<T>(PhantomData<T>);
+
+#[derive_where(unsafe())]
+struct EmptyList<T>(PhantomData<T>);
+
+#[derive_where(unsafe(Send, Send))]
+struct DuplicateTrait<T>(PhantomData<T>);
+
+fn main() {}
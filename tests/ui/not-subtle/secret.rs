@@ -0,0 +1,9 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(PartialEq)]
+#[derive_where(secret)]
+struct SecretPartialEq<T>(PhantomData<T>);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(ConditionallySelectable)]
+struct UnsupportedSelectable<T>(PhantomData<T>);
+
+#[derive_where(ConstantTimeEq)]
+struct Unsupported<T>(PhantomData<T>);
+
+fn main() {}
@@ -0,0 +1,20 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(FromBytes)]
+struct FromBytes<T>(PhantomData<T>);
+
+#[derive_where(FromZeros)]
+struct FromZeros<T>(PhantomData<T>);
+
+#[derive_where(Immutable)]
+struct Immutable<T>(PhantomData<T>);
+
+#[derive_where(IntoBytes)]
+struct IntoBytes<T>(PhantomData<T>);
+
+#[derive_where(KnownLayout)]
+struct KnownLayout<T>(PhantomData<T>);
+
+fn main() {}
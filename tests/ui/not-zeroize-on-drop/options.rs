@@ -0,0 +1,15 @@
+extern crate zeroize_ as zeroize;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+fn wipe<T>(_: &mut T) {}
+
+#[derive_where(ZeroizeOnDrop)]
+struct Order<T>(#[derive_where(Zeroize(order = 1))] PhantomData<T>);
+
+#[derive_where(ZeroizeOnDrop)]
+struct With<T>(#[derive_where(Zeroize(with = wipe))] PhantomData<T>);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Debug)]
+union UnsupportedTrait<T> {
+	a: Option<PhantomData<T>>,
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+extern crate proptest_ as proptest;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Clone)]
+struct NotImplemented<T>(#[derive_where(ProptestArbitrary(strategy = 0..1))] PhantomData<T>);
+
+#[derive_where(ProptestArbitrary)]
+struct MissingOption<T>(#[derive_where(ProptestArbitrary)] PhantomData<T>);
+
+#[derive_where(ProptestArbitrary)]
+struct InvalidOption<T>(#[derive_where(ProptestArbitrary(test))] PhantomData<T>);
+
+#[derive_where(ProptestArbitrary)]
+struct DuplicateStrategy<T>(
+	#[derive_where(ProptestArbitrary(strategy = 0..1, strategy = 0..1))] PhantomData<T>,
+);
+
+#[derive_where(ProptestArbitrary)]
+enum WeightZero<T> {
+	#[derive_where(ProptestArbitrary(weight = 0))]
+	A(PhantomData<T>),
+}
+
+#[derive_where(ProptestArbitrary)]
+enum WeightNoInteger<T> {
+	#[derive_where(ProptestArbitrary(weight = "1"))]
+	A(PhantomData<T>),
+}
+
+#[derive_where(ProptestArbitrary)]
+enum DuplicateWeight<T> {
+	#[derive_where(ProptestArbitrary(weight = 1, weight = 1))]
+	A(PhantomData<T>),
+}
+
+#[derive_where(ProptestArbitrary)]
+enum WeightOverflow<T> {
+	#[derive_where(ProptestArbitrary(weight = 4294967295))]
+	A(PhantomData<T>),
+	B(PhantomData<T>),
+}
+
+fn main() {}
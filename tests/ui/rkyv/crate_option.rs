@@ -0,0 +1,21 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Archive(crate = rkyv_, crate = rkyv_))]
+struct Duplicate<T>(PhantomData<T>);
+
+#[derive_where(Archive(crate = ::rkyv))]
+struct Unnecessary<T>(PhantomData<T>);
+
+#[derive_where(RkyvSerialize(bound = ""))]
+struct UnsupportedOption<T>(PhantomData<T>);
+
+#[derive_where(RkyvDeserialize(crate))]
+struct WrongSyntax<T>(PhantomData<T>);
+
+#[derive_where(Archive(crate = rkyv_))]
+#[rkyv(crate = rkyv_)]
+struct Conflict<T>(PhantomData<T>);
+
+fn main() {}
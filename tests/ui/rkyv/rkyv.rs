@@ -0,0 +1,8 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Archive, RkyvSerialize)]
+struct SkipGroup<T>(#[derive_where(skip(RkyvSerialize))] PhantomData<T>);
+
+fn main() {}
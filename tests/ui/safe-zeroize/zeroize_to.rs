@@ -0,0 +1,14 @@
+extern crate zeroize_ as zeroize;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(ZeroizeOnDrop)]
+enum ZeroizeTo<T> {
+	A(PhantomData<T>),
+	#[derive_where(zeroize_to)]
+	B,
+}
+
+fn main() {}
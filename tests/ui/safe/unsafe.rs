@@ -0,0 +1,8 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(unsafe(Send))]
+struct Unsafe<T>(PhantomData<T>);
+
+fn main() {}
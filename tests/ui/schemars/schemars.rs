@@ -0,0 +1,18 @@
+extern crate schemars_ as schemars;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(JsonSchema(crate = schemars_))]
+struct Options<T>(PhantomData<T>);
+
+#[derive_where(JsonSchema)]
+#[schemars(bound = "T")]
+struct InvalidBound<T>(PhantomData<T>);
+
+#[derive_where(JsonSchema)]
+#[schemars(bound = 1)]
+struct WrongBoundSyntax<T>(PhantomData<T>);
+
+fn main() {}
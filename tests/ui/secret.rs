@@ -0,0 +1,22 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Debug)]
+#[derive_where(secret)]
+#[derive_where(secret)]
+struct DuplicateSecret<T>(PhantomData<T>);
+
+#[derive_where(Debug)]
+#[derive_where(secret = true)]
+struct WrongSecretSyntax<T>(PhantomData<T>);
+
+#[derive_where(Clone)]
+#[derive_where(secret)]
+struct SecretWithoutTrait<T>(PhantomData<T>);
+
+#[derive_where(PartialEq(const))]
+#[derive_where(secret)]
+struct SecretConst<T>(PhantomData<T>);
+
+fn main() {}
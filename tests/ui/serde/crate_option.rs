@@ -0,0 +1,21 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Serialize(crate = serde_, crate = serde_))]
+struct Duplicate<T>(PhantomData<T>);
+
+#[derive_where(Serialize(crate = ::serde))]
+struct Unnecessary<T>(PhantomData<T>);
+
+#[derive_where(Serialize(bound = ""))]
+struct UnsupportedOption<T>(PhantomData<T>);
+
+#[derive_where(Serialize(crate))]
+struct WrongSyntax<T>(PhantomData<T>);
+
+#[derive_where(Serialize(crate = serde_))]
+#[serde(crate = "serde_")]
+struct Conflict<T>(PhantomData<T>);
+
+fn main() {}
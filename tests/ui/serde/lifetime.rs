@@ -0,0 +1,8 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Clone, Deserialize; T: serde_::Deserialize<'de>)]
+struct TraitWithoutLifetime<T>(PhantomData<T>);
+
+fn main() {}
@@ -0,0 +1,26 @@
+extern crate serde_ as serde;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Serialize)]
+#[derive_where(secret)]
+struct NotExposed<T>(PhantomData<T>);
+
+#[derive_where(Serialize)]
+struct ExposeWithoutSecret<T>(#[derive_where(Serialize(expose))] PhantomData<T>);
+
+#[derive_where(Debug)]
+#[derive_where(secret)]
+struct ExposeWithoutSerialize<T>(#[derive_where(Serialize(expose))] PhantomData<T>);
+
+#[derive_where(Serialize)]
+#[derive_where(secret)]
+struct DuplicateExpose<T>(#[derive_where(Serialize(expose, expose))] PhantomData<T>);
+
+#[derive_where(Serialize)]
+#[derive_where(secret)]
+struct MissingExpose<T>(#[derive_where(Serialize)] PhantomData<T>);
+
+fn main() {}
@@ -7,7 +7,7 @@ use derive_where::derive_where;
 struct ValidSerde<T>(PhantomData<T>);
 
 #[derive_where(Deserialize)]
-#[serde(bound = "")]
-struct ConflictingBound<T>(PhantomData<T>);
+#[serde(bound = "T")]
+struct InvalidBound<T>(PhantomData<T>);
 
 fn main() {}
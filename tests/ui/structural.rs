@@ -0,0 +1,25 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Eq, PartialEq; T)]
+struct Skip<T, U>(T, #[derive_where(skip)] PhantomData<U>);
+
+const SKIP: Skip<u8, ()> = Skip(42, PhantomData);
+
+#[derive_where(PartialEq; T)]
+struct NoEq<T, U>(T, PhantomData<U>);
+
+const NO_EQ: NoEq<u8, ()> = NoEq(42, PhantomData);
+
+fn main() {
+	match Skip(42, PhantomData) {
+		SKIP => (),
+		_ => (),
+	}
+
+	match NoEq(42, PhantomData) {
+		NO_EQ => (),
+		_ => (),
+	}
+}
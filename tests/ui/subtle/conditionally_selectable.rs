@@ -0,0 +1,24 @@
+extern crate subtle_ as subtle;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(ConditionallySelectable(test))]
+struct InvalidOption<T>(PhantomData<T>);
+
+#[derive_where(ConditionallySelectable(crate(subtle_)))]
+struct WrongCrateSyntax<T>(PhantomData<T>);
+
+#[derive_where(ConditionallySelectable(crate = subtle_, crate = subtle_))]
+struct DuplicateCrate<T>(PhantomData<T>);
+
+#[derive_where(ConditionallySelectable(crate = ::subtle))]
+struct DefaultCrate<T>(PhantomData<T>);
+
+#[derive_where(Clone, Copy, ConditionallySelectable)]
+enum Enum<T> {
+	A(PhantomData<T>),
+}
+
+fn main() {}
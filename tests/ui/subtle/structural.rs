@@ -0,0 +1,16 @@
+extern crate subtle_ as subtle;
+
+use derive_where::derive_where;
+
+#[derive_where(Eq, PartialEq; T: Eq + subtle::ConstantTimeEq)]
+#[derive_where(secret)]
+struct Secret<T>(T);
+
+const SECRET: Secret<u8> = Secret(42);
+
+fn main() {
+	match Secret(42) {
+		SECRET => (),
+		_ => (),
+	}
+}
@@ -0,0 +1,28 @@
+extern crate subtle_ as subtle;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(ConstantTimeEq(test))]
+struct InvalidOption<T>(PhantomData<T>);
+
+#[derive_where(ConstantTimeEq(test = "test"))]
+struct WrongOptionSyntax1<T>(PhantomData<T>);
+
+#[derive_where(ConstantTimeEq("option"))]
+struct WrongOptionSyntax2<T>(PhantomData<T>);
+
+#[derive_where(ConstantTimeEq(crate(subtle_)))]
+struct WrongCrateSyntax<T>(PhantomData<T>);
+
+#[derive_where(ConstantTimeEq(crate = "struct Test"))]
+struct InvalidPath<T>(PhantomData<T>);
+
+#[derive_where(ConstantTimeEq(crate = subtle_, crate = subtle_))]
+struct DuplicateCrate<T>(PhantomData<T>);
+
+#[derive_where(ConstantTimeEq(crate = ::subtle))]
+struct DefaultCrate<T>(PhantomData<T>);
+
+fn main() {}
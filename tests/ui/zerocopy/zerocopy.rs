@@ -0,0 +1,34 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(FromBytes(crate = "zerocopy"))]
+#[repr(C)]
+struct Options<T>(PhantomData<T>);
+
+#[derive_where(IntoBytes)]
+struct NoRepr<T>(PhantomData<T>);
+
+#[derive_where(IntoBytes)]
+#[repr(align(4))]
+struct OnlyAlign<T>(PhantomData<T>);
+
+#[derive_where(FromZeros)]
+enum NoReprEnum<T> {
+	A(PhantomData<T>),
+	B,
+}
+
+#[derive_where(FromBytes, FromZeros)]
+#[repr(C)]
+struct FromBytesAndFromZeros<T>(PhantomData<T>);
+
+#[derive_where(FromBytes; T)]
+#[derive_where(FromZeros)]
+#[repr(C)]
+struct FromBytesAndFromZerosBounds<T>(PhantomData<T>);
+
+#[derive_where(KnownLayout)]
+struct Skip<T>(#[derive_where(skip)] PhantomData<T>);
+
+fn main() {}
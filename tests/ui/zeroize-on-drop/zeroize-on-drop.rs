@@ -10,4 +10,32 @@ struct DuplicateNoDrop<T>(PhantomData<T>);
 #[derive_where(ZeroizeOnDrop(no_drop))]
 struct NoDropNoZeroizeOnDrop<T>(T);
 
+#[derive_where(ZeroizeOnDrop(union, no_drop))]
+union UnionNoDrop {
+	a: u8,
+}
+
+fn hook<T>(_: &mut T) {}
+
+#[derive_where(ZeroizeOnDrop(on_drop = hook, no_drop))]
+struct OnDropNoDrop<T>(PhantomData<T>);
+
+#[derive_where(ZeroizeOnDrop(no_drop))]
+enum ZeroizeToNoDrop<T> {
+	A(PhantomData<T>),
+	#[derive_where(zeroize_to)]
+	B,
+}
+
+#[derive_where(ZeroizeOnDrop)]
+struct FqsOnDrop<T>(#[derive_where(Zeroize(fqs))] PhantomData<T>);
+
+fn wipe<T>(_: &mut T) {}
+
+#[derive_where(ZeroizeOnDrop(no_drop))]
+struct WithNoDrop<T>(#[derive_where(Zeroize(with = wipe))] PhantomData<T>);
+
+#[derive_where(ZeroizeOnDrop(no_drop))]
+struct OrderNoDrop<T>(#[derive_where(Zeroize(order = 1))] PhantomData<T>);
+
 fn main() {}
@@ -0,0 +1,18 @@
+extern crate zeroize_ as zeroize;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+fn hook<T>(_: &mut T) {}
+
+#[derive_where(ZeroizeOnDrop(on_drop = hook, on_drop = hook))]
+struct DuplicateOnDrop<T>(PhantomData<T>);
+
+#[derive_where(ZeroizeOnDrop(on_drop = "hook"))]
+struct WrongOnDropSyntax<T>(PhantomData<T>);
+
+#[derive_where(ZeroizeOnDrop(on_drop))]
+struct MissingOnDropValue<T>(PhantomData<T>);
+
+fn main() {}
@@ -0,0 +1,16 @@
+extern crate zeroize_ as zeroize;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(Zeroize)]
+struct DuplicateOrder<T>(#[derive_where(Zeroize(order = 1, order = 2))] PhantomData<T>);
+
+#[derive_where(Zeroize)]
+struct WrongOrderSyntax<T>(#[derive_where(Zeroize(order = "1"))] PhantomData<T>);
+
+#[derive_where(Zeroize)]
+struct WrongOrderType<T>(#[derive_where(Zeroize(order = 1.0))] PhantomData<T>);
+
+fn main() {}
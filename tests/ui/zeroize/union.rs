@@ -0,0 +1,35 @@
+extern crate zeroize_ as zeroize;
+
+use std::{marker::PhantomData, mem::ManuallyDrop};
+
+use derive_where::derive_where;
+
+#[derive_where(Debug)]
+union UnsupportedTrait<T> {
+	a: Option<PhantomData<T>>,
+}
+
+#[derive_where(Zeroize)]
+union MissingUnion {
+	a: u8,
+}
+
+#[derive_where(ZeroizeOnDrop)]
+union MissingUnionOnDrop {
+	a: u8,
+}
+
+#[derive_where(Zeroize(union))]
+struct NotUnion<T>(PhantomData<T>);
+
+#[derive_where(Zeroize(union, union))]
+union DuplicateUnion {
+	a: u8,
+}
+
+#[derive_where(Zeroize(union))]
+union NotCopy {
+	a: ManuallyDrop<Vec<u8>>,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+extern crate zeroize_ as zeroize;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+fn wipe<T>(_: &mut T) {}
+
+#[derive_where(Zeroize)]
+struct DuplicateWith<T>(#[derive_where(Zeroize(with = wipe, with = wipe))] PhantomData<T>);
+
+#[derive_where(Zeroize)]
+struct WithFqs<T>(#[derive_where(Zeroize(with = wipe, fqs))] PhantomData<T>);
+
+#[derive_where(Zeroize)]
+struct FqsWith<T>(#[derive_where(Zeroize(fqs, with = wipe))] PhantomData<T>);
+
+#[derive_where(Zeroize)]
+struct WrongWithSyntax<T>(#[derive_where(Zeroize(with = "wipe"))] PhantomData<T>);
+
+#[derive_where(Clone)]
+struct NotImplemented<T>(#[derive_where(Zeroize(with = wipe))] PhantomData<T>);
+
+fn main() {}
@@ -0,0 +1,43 @@
+extern crate zeroize_ as zeroize;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+
+#[derive_where(ZeroizeOnDrop)]
+enum DuplicateZeroizeTo<T> {
+	A(PhantomData<T>),
+	#[derive_where(zeroize_to, zeroize_to)]
+	B,
+}
+
+#[derive_where(ZeroizeOnDrop)]
+enum MultipleZeroizeTo<T> {
+	A(PhantomData<T>),
+	#[derive_where(zeroize_to)]
+	B,
+	#[derive_where(zeroize_to)]
+	C,
+}
+
+#[derive_where(ZeroizeOnDrop)]
+enum ZeroizeToFields<T> {
+	#[derive_where(zeroize_to)]
+	A(PhantomData<T>),
+}
+
+#[derive_where(ZeroizeOnDrop)]
+enum WrongZeroizeToSyntax<T> {
+	A(PhantomData<T>),
+	#[derive_where(zeroize_to = true)]
+	B,
+}
+
+#[derive_where(Clone)]
+enum NotImplemented<T> {
+	A(PhantomData<T>),
+	#[derive_where(zeroize_to)]
+	B,
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+#![cfg(not(feature = "safe"))]
+
+use std::ptr::{self, NonNull};
+
+use derive_where::derive_where;
+
+fn assert_send<T: Send>(_: &T) {}
+
+fn assert_sync<T: Sync>(_: &T) {}
+
+#[test]
+fn send_sync() {
+	#[derive_where(unsafe(Send, Sync); T: Send + Sync)]
+	struct Test<T>(NonNull<T>);
+
+	let mut value = 42;
+	let test = Test(NonNull::from(&mut value));
+	assert_send(&test);
+	assert_sync(&test);
+	assert_eq!(unsafe { *test.0.as_ptr() }, 42);
+}
+
+#[test]
+fn bound() {
+	#[derive_where(unsafe(Send); T)]
+	#[derive_where(unsafe(Sync); U)]
+	struct Test<T, U>(*const T, *const U);
+
+	let test = Test::<i32, i32>(ptr::null(), ptr::null());
+	assert_send(&test);
+	assert_sync(&test);
+	assert!(test.0.is_null());
+	assert!(test.1.is_null());
+}
+
+#[test]
+fn enum_() {
+	#[derive_where(unsafe(Send, Sync))]
+	enum Test<T> {
+		A(*mut T),
+		B,
+	}
+
+	let test = Test::<*const ()>::A(ptr::null_mut());
+	assert_send(&test);
+	assert_sync(&test);
+	assert!(matches!(test, Test::A(pointer) if pointer.is_null()));
+	assert!(matches!(Test::<()>::B, Test::B));
+}
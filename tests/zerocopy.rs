@@ -0,0 +1,56 @@
+#![cfg(feature = "zerocopy")]
+
+extern crate zerocopy_ as zerocopy;
+
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+use zerocopy::{FromBytes, FromZeros, IntoBytes};
+
+/// Endianness marker that doesn't implement any `zerocopy` trait.
+struct BigEndian;
+
+#[test]
+fn struct_() {
+	#[derive_where(FromBytes, IntoBytes, KnownLayout, Immutable)]
+	#[repr(C)]
+	struct Packet<E> {
+		kind: u8,
+		length: [u8; 2],
+		data: [u8; 4],
+		endian: PhantomData<E>,
+	}
+
+	let bytes = [1, 0, 4, 3, 4, 5, 6];
+	let packet = Packet::<BigEndian>::read_from_bytes(&bytes).unwrap();
+
+	assert_eq!(packet.kind, 1);
+	assert_eq!(packet.length, [0, 4]);
+	assert_eq!(packet.data, [3, 4, 5, 6]);
+	assert_eq!(packet.as_bytes(), bytes);
+}
+
+#[test]
+fn bound() {
+	#[derive_where(FromBytes, IntoBytes, Immutable; T)]
+	#[repr(transparent)]
+	struct Test<T, E>(T, PhantomData<E>);
+
+	let test = Test::<u32, BigEndian>::read_from_bytes(&42_u32.to_ne_bytes()).unwrap();
+
+	assert_eq!(test.0, 42);
+	assert_eq!(test.as_bytes(), 42_u32.to_ne_bytes());
+}
+
+#[test]
+fn from_zeros() {
+	#[derive_where(FromZeros)]
+	#[repr(u8)]
+	#[allow(dead_code)]
+	enum Test<E> {
+		A,
+		B(u8, PhantomData<E>),
+	}
+
+	assert!(matches!(Test::<BigEndian>::new_zeroed(), Test::A));
+}
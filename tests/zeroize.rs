@@ -103,6 +103,151 @@ fn fqs() {
 	util::test_drop(Test(Fqs(42.into())), |test| assert_eq!(test.0 .0, 0))
 }
 
+#[test]
+fn with() {
+	struct Foreign(i32);
+
+	fn wipe(foreign: &mut Foreign) {
+		foreign.0 = 0;
+	}
+
+	#[derive_where(Zeroize, ZeroizeOnDrop)]
+	struct Test<T>(Wrapper<T>, #[derive_where(Zeroize(with = wipe))] Foreign);
+
+	let mut test = Test(42.into(), Foreign(42));
+
+	let _ = AssertZeroize(&test);
+	#[cfg(feature = "zeroize-on-drop")]
+	let _ = AssertZeroizeOnDrop(&test);
+	assert!(mem::needs_drop::<Test<()>>());
+
+	test.zeroize();
+
+	assert_eq!(test.0, 0);
+	assert_eq!(test.1 .0, 0);
+
+	util::test_drop(Test(42.into(), Foreign(42)), |test| {
+		assert_eq!(test.0, 0);
+		assert_eq!(test.1 .0, 0);
+	})
+}
+
+#[test]
+#[cfg(feature = "zeroize-on-drop")]
+fn with_on_drop() {
+	struct Foreign(i32);
+
+	fn wipe(foreign: &mut Foreign) {
+		foreign.0 = 0;
+	}
+
+	#[derive_where(ZeroizeOnDrop)]
+	struct Test(#[derive_where(Zeroize(with = wipe))] Foreign);
+
+	let _ = AssertZeroizeOnDrop(&Test(Foreign(42)));
+
+	util::test_drop(Test(Foreign(42)), |test| assert_eq!(test.0 .0, 0))
+}
+
+#[test]
+fn on_drop() {
+	use std::cell::Cell;
+
+	thread_local! {
+		static RELEASED: Cell<Option<bool>> = Cell::new(None);
+	}
+
+	#[derive_where(Zeroize, ZeroizeOnDrop(on_drop = Test::release))]
+	struct Test<T>(Wrapper<T>);
+
+	impl<T> Test<T> {
+		fn release(&mut self) {
+			// Fields aren't zeroized yet.
+			RELEASED.with(|released| released.set(Some(self.0 == 42)));
+		}
+	}
+
+	assert!(mem::needs_drop::<Test<()>>());
+
+	mem::drop(Test(42.into()));
+
+	assert_eq!(RELEASED.with(Cell::get), Some(true));
+}
+
+#[test]
+fn order() {
+	use std::cell::RefCell;
+
+	thread_local! {
+		static ORDER: RefCell<Vec<u8>> = RefCell::new(Vec::new());
+	}
+
+	struct Record(u8);
+
+	impl Zeroize for Record {
+		fn zeroize(&mut self) {
+			ORDER.with(|order| order.borrow_mut().push(self.0));
+		}
+	}
+
+	#[derive_where(Zeroize)]
+	struct Test(
+		Record,
+		#[derive_where(Zeroize(order = 1))] Record,
+		#[derive_where(Zeroize(order = -1))] Record,
+		Record,
+	);
+
+	Test(Record(0), Record(1), Record(2), Record(3)).zeroize();
+
+	assert_eq!(ORDER.with(RefCell::take), [2, 0, 3, 1]);
+}
+
+#[test]
+#[cfg(not(feature = "safe"))]
+fn zeroize_to() {
+	use std::rc::Rc;
+
+	#[derive_where(Zeroize, ZeroizeOnDrop)]
+	enum Test<T> {
+		A(Wrapper<T>, #[derive_where(skip(Zeroize))] Rc<()>),
+		B {
+			a: Wrapper<T>,
+		},
+		#[derive_where(zeroize_to)]
+		Empty,
+	}
+
+	let rc = Rc::new(());
+
+	util::test_drop(Test::A(42.into(), rc.clone()), |test| {
+		assert!(matches!(test, Test::Empty))
+	});
+	util::test_drop(Test::B { a: 42.into() }, |test| {
+		assert!(matches!(test, Test::Empty))
+	});
+
+	// Fields are still dropped exactly once.
+	assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn unit_variant() {
+	#[derive_where(Zeroize, ZeroizeOnDrop)]
+	enum Test<T> {
+		A(Wrapper<T>),
+		B,
+	}
+
+	let mut test = Test::A(42.into());
+	test.zeroize();
+	assert!(matches!(test, Test::A(wrapper) if wrapper == 0));
+
+	let mut test = Test::<()>::B;
+	test.zeroize();
+	assert!(matches!(test, Test::B));
+}
+
 #[test]
 fn deref() {
 	struct ZeroizeDeref<T>(i32, PhantomData<T>);
@@ -160,6 +305,44 @@ fn no_drop() {
 	util::test_drop(Test(42.into()), |test| assert_eq!(*test.0, 0))
 }
 
+#[test]
+fn union_() {
+	#[derive_where(Zeroize(union))]
+	union Test {
+		a: u8,
+		b: [u32; 2],
+	}
+
+	let mut test = Test { b: [42, 42] };
+
+	let _ = AssertZeroize(&test);
+
+	test.zeroize();
+
+	assert_eq!(unsafe { test.b }, [0, 0]);
+
+	util::test_drop(Test { b: [42, 42] }, |test| {
+		assert_eq!(unsafe { test.b }, [42, 42])
+	})
+}
+
+#[test]
+fn union_drop() {
+	#[derive_where(ZeroizeOnDrop(union))]
+	union Test {
+		a: u8,
+		b: [u32; 2],
+	}
+
+	#[cfg(feature = "zeroize-on-drop")]
+	let _ = AssertZeroizeOnDrop(&Test { a: 42 });
+	assert!(mem::needs_drop::<Test>());
+
+	util::test_drop(Test { b: [42, 42] }, |test| {
+		assert_eq!(unsafe { test.b }, [0, 0])
+	})
+}
+
 mod hygiene {
 	use derive_where::derive_where;
 